@@ -9,7 +9,7 @@ use std::sync::Arc;
 
 use jsonrpsee::RpcModule;
 use sc_transaction_pool_api::TransactionPool;
-use fennel_node_runtime::{opaque::Block, AccountId, Balance, Nonce};
+use fennel_node_runtime::{opaque::Block, AccountId, Balance, BlockNumber, Nonce};
 use sp_api::ProvideRuntimeApi;
 use sp_block_builder::BlockBuilder;
 use sp_blockchain::{Error as BlockChainError, HeaderBackend, HeaderMetadata};
@@ -32,9 +32,17 @@ where
 	C: Send + Sync + 'static,
 	C::Api: substrate_frame_rpc_system::AccountNonceApi<Block, AccountId, Nonce>,
 	C::Api: pallet_transaction_payment_rpc::TransactionPaymentRuntimeApi<Block, Balance>,
+	C::Api: pallet_certificate_rpc::CertificateRuntimeApi<Block, AccountId, BlockNumber>,
+	C::Api: pallet_identity_rpc::IdentityRuntimeApi<Block, AccountId>,
+	C::Api: pallet_keystore_rpc::KeystoreRuntimeApi<Block, AccountId, BlockNumber>,
+	C::Api: pallet_signal_rpc::SignalRuntimeApi<Block, AccountId, BlockNumber>,
 	C::Api: BlockBuilder<Block>,
 	P: TransactionPool + 'static,
 {
+	use pallet_certificate_rpc::{CertificateApiServer, Certificates};
+	use pallet_identity_rpc::{Identity, IdentityApiServer};
+	use pallet_keystore_rpc::{Keystore, KeystoreApiServer};
+	use pallet_signal_rpc::{SignalApiServer, Signals};
 	use pallet_transaction_payment_rpc::{TransactionPayment, TransactionPaymentApiServer};
 	use substrate_frame_rpc_system::{System, SystemApiServer};
 
@@ -42,7 +50,11 @@ where
 	let FullDeps { client, pool } = deps;
 
 	module.merge(System::new(client.clone(), pool).into_rpc())?;
-	module.merge(TransactionPayment::new(client).into_rpc())?;
+	module.merge(TransactionPayment::new(client.clone()).into_rpc())?;
+	module.merge(Identity::new(client.clone()).into_rpc())?;
+	module.merge(Keystore::new(client.clone()).into_rpc())?;
+	module.merge(Certificates::new(client.clone()).into_rpc())?;
+	module.merge(Signals::new(client).into_rpc())?;
 
 	// Extend this RPC with a custom API by using the following syntax.
 	// `YourRpcStruct` should have a reference to a client, which is needed
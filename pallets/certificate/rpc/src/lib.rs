@@ -0,0 +1,132 @@
+//! RPC interface for the Fennel certificate pallet.
+
+use std::sync::Arc;
+
+use codec::Codec;
+use jsonrpsee::{
+    core::RpcResult,
+    proc_macros::rpc,
+    types::{error::ErrorObject, ErrorObjectOwned},
+};
+use pallet_certificate_runtime_api::Certificate;
+use serde::{Deserialize, Serialize};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_core::Bytes;
+use sp_runtime::traits::Block as BlockT;
+
+pub use pallet_certificate_runtime_api::CertificateApi as CertificateRuntimeApi;
+
+/// A live certificate as returned over RPC.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CertificateInfo<AccountId, BlockNumber> {
+    /// The account that issued the certificate.
+    pub sender: AccountId,
+    /// The account that holds the certificate.
+    pub recipient: AccountId,
+    /// What the certificate attests to, typically a content hash or a URI.
+    pub payload: Bytes,
+    /// The block in which the certificate was issued.
+    pub issued_at: BlockNumber,
+    /// The block in which the certificate expires, if it ever does.
+    pub expires_at: Option<BlockNumber>,
+}
+
+impl<AccountId, BlockNumber> CertificateInfo<AccountId, BlockNumber> {
+    fn new(sender: AccountId, recipient: AccountId, certificate: Certificate<Vec<u8>, BlockNumber>) -> Self {
+        Self {
+            sender,
+            recipient,
+            payload: certificate.payload.into(),
+            issued_at: certificate.issued_at,
+            expires_at: certificate.expires_at,
+        }
+    }
+}
+
+/// Certificate RPC methods.
+#[rpc(client, server)]
+pub trait CertificateApi<BlockHash, AccountId, BlockNumber> {
+    /// Returns the live certificates an account has issued.
+    #[method(name = "certificate_issuedBy")]
+    fn issued_by(
+        &self,
+        who: AccountId,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<CertificateInfo<AccountId, BlockNumber>>>;
+
+    /// Returns the live certificates an account holds.
+    #[method(name = "certificate_heldBy")]
+    fn held_by(
+        &self,
+        who: AccountId,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<CertificateInfo<AccountId, BlockNumber>>>;
+
+    /// Returns whether a sender has issued a recipient a certificate that is currently valid.
+    #[method(name = "certificate_isValid")]
+    fn is_valid(&self, sender: AccountId, recipient: AccountId, at: Option<BlockHash>) -> RpcResult<bool>;
+}
+
+/// Provides RPC methods to query certificates.
+pub struct Certificates<C, B> {
+    /// Shared reference to the client.
+    client: Arc<C>,
+    _marker: std::marker::PhantomData<B>,
+}
+
+impl<C, B> Certificates<C, B> {
+    /// Creates a new instance of the Certificates RPC helper.
+    pub fn new(client: Arc<C>) -> Self {
+        Self { client, _marker: Default::default() }
+    }
+}
+
+/// Error code returned when the runtime API call fails.
+const RUNTIME_ERROR: i32 = 1;
+
+fn runtime_error(error: impl ToString) -> ErrorObjectOwned {
+    ErrorObject::owned(RUNTIME_ERROR, "Unable to query certificates.", Some(error.to_string()))
+}
+
+impl<C, Block, AccountId, BlockNumber> CertificateApiServer<<Block as BlockT>::Hash, AccountId, BlockNumber>
+    for Certificates<C, Block>
+where
+    Block: BlockT,
+    C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+    C::Api: CertificateRuntimeApi<Block, AccountId, BlockNumber>,
+    AccountId: Codec + Clone + Serialize + Send + Sync + 'static,
+    BlockNumber: Codec + Serialize + Send + Sync + 'static,
+{
+    fn issued_by(
+        &self,
+        who: AccountId,
+        at: Option<Block::Hash>,
+    ) -> RpcResult<Vec<CertificateInfo<AccountId, BlockNumber>>> {
+        let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+        let issued = self.client.runtime_api().issued_by(at_hash, who.clone()).map_err(runtime_error)?;
+        Ok(issued
+            .into_iter()
+            .map(|(recipient, certificate)| CertificateInfo::new(who.clone(), recipient, certificate))
+            .collect())
+    }
+
+    fn held_by(
+        &self,
+        who: AccountId,
+        at: Option<Block::Hash>,
+    ) -> RpcResult<Vec<CertificateInfo<AccountId, BlockNumber>>> {
+        let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+        let held = self.client.runtime_api().held_by(at_hash, who.clone()).map_err(runtime_error)?;
+        Ok(held
+            .into_iter()
+            .map(|(sender, certificate)| CertificateInfo::new(sender, who.clone(), certificate))
+            .collect())
+    }
+
+    fn is_valid(&self, sender: AccountId, recipient: AccountId, at: Option<Block::Hash>) -> RpcResult<bool> {
+        let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+        self.client.runtime_api().is_valid(at_hash, sender, recipient).map_err(runtime_error)
+    }
+}
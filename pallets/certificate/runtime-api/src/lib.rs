@@ -0,0 +1,29 @@
+//! Runtime API definition for the Fennel certificate pallet.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+use codec::Codec;
+
+pub use pallet_certificate::Certificate;
+
+sp_api::decl_runtime_apis! {
+    /// Read access to live certificates without building raw storage keys.
+    pub trait CertificateApi<AccountId, BlockNumber>
+    where
+        AccountId: Codec,
+        BlockNumber: Codec,
+    {
+        /// Returns the recipients and certificates of the certificates `who` has issued that are
+        /// valid in the current block.
+        fn issued_by(who: AccountId) -> Vec<(AccountId, Certificate<Vec<u8>, BlockNumber>)>;
+        /// Returns the issuers and certificates of the certificates `who` holds that are valid in
+        /// the current block.
+        fn held_by(who: AccountId) -> Vec<(AccountId, Certificate<Vec<u8>, BlockNumber>)>;
+        /// Whether `sender` has issued `recipient` a certificate that is valid in the current
+        /// block.
+        fn is_valid(sender: AccountId, recipient: AccountId) -> bool;
+    }
+}
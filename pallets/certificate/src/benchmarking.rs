@@ -4,13 +4,47 @@
 use super::*;
 use crate::Pallet as Certificate;
 use frame_benchmarking::v2::*;
-use frame_support::{traits::Currency, sp_runtime::traits::Bounded};
-use frame_system::RawOrigin;
+use frame_support::{traits::{Currency, Get}, sp_runtime::traits::Bounded, BoundedVec};
+use frame_system::{pallet_prelude::BlockNumberFor, RawOrigin};
+use alloc::vec;
 
 pub fn assert_last_event<T: Config>(generic_event: <T as Config>::RuntimeEvent) {
     frame_system::Pallet::<T>::assert_last_event(generic_event.into());
 }
 
+/// A payload of the maximum size and an expiry, the most expensive certificate to issue.
+fn certificate_details<T: Config>() -> (BoundedVec<u8, T::MaxPayloadSize>, Option<BlockNumberFor<T>>) {
+    let payload = vec![7u8; <T::MaxPayloadSize as Get<u32>>::get() as usize].try_into().unwrap();
+    let expires_at = frame_system::Pallet::<T>::block_number() + 10u32.into();
+    (payload, Some(expires_at))
+}
+
+/// Stores `count` accepted certificates from `sender` and sizes their lock to match.
+fn generate_certificates<T: Config>(sender: &T::AccountId, count: u32) {
+    let issued_at = frame_system::Pallet::<T>::block_number();
+    for i in 0..count {
+        let target: T::AccountId = account("target", i, 0);
+        let certificate = crate::Certificate { payload: BoundedVec::new(), issued_at, expires_at: None };
+        CertificateList::<T>::insert(sender, &target, certificate);
+        CertificatesHeld::<T>::insert(&target, sender, ());
+    }
+    CertificateCount::<T>::insert(sender, count);
+    Pallet::<T>::set_certificate_lock(sender, count);
+}
+
+/// Fills every block a certificate expiring at `at` could be scheduled in but the last, so
+/// scheduling it has to look at all of them.
+fn fill_expiries<T: Config>(at: BlockNumberFor<T>) {
+    let mut block = at;
+    for delay in 1..fennel_expiry::MAX_EXPIRY_DELAY {
+        let full: vec::Vec<_> = (0..T::MaxExpiriesPerBlock::get())
+            .map(|i| (account("filler", i, delay), account("filler", i, delay)))
+            .collect();
+        CertificateExpiries::<T>::insert(block, BoundedVec::try_from(full).unwrap());
+        block += 1u32.into();
+    }
+}
+
 #[benchmarks]
 mod benchmarks {
 	use super::*;
@@ -24,10 +58,14 @@ mod benchmarks {
 
 		T::Currency::make_free_balance_be(&sender, BalanceOf::<T>::max_value());
 
+		let (payload, expires_at) = certificate_details::<T>();
+		let expiry = expires_at.ok_or(BenchmarkError::Stop("Certificate has no expiry"))?;
+		fill_expiries::<T>(expiry);
+
 		#[extrinsic_call]
-		send_certificate(RawOrigin::Signed(sender.clone()), recipient.clone());
+		send_certificate(RawOrigin::Signed(sender.clone()), recipient.clone(), payload, expires_at);
 
-		assert!(CertificateList::<T>::contains_key(sender.clone(), recipient.clone()));
+		assert!(PendingCertificates::<T>::contains_key(sender.clone(), recipient.clone()));
 		assert_last_event::<T>(Event::CertificateSent { sender, recipient }.into());
 
 		Ok(())
@@ -40,13 +78,14 @@ mod benchmarks {
 
 		T::Currency::make_free_balance_be(&sender, BalanceOf::<T>::max_value());
 		// Use direct call for setup
-		Certificate::<T>::send_certificate(RawOrigin::Signed(sender.clone()).into(), recipient.clone())?;
+		let (payload, expires_at) = certificate_details::<T>();
+		Certificate::<T>::send_certificate(RawOrigin::Signed(sender.clone()).into(), recipient.clone(), payload, expires_at)?;
 
 		#[extrinsic_call]
-		revoke_certificate(RawOrigin::Signed(sender.clone()), recipient.clone());
+		revoke_certificate(RawOrigin::Signed(sender.clone()), recipient.clone(), RevocationReason::Withdrawn);
 
-		assert!(CertificateList::<T>::contains_key(sender.clone(), recipient.clone()));
-		assert!(!CertificateList::<T>::get(sender.clone(), recipient.clone()));
+		assert!(!PendingCertificates::<T>::contains_key(sender.clone(), recipient.clone()));
+		assert!(RevokedCertificates::<T>::contains_key(sender.clone(), recipient.clone()));
 		assert_last_event::<T>(Event::CertificateRevoked { sender, recipient }.into());
 		Ok(())
 	}
@@ -57,18 +96,19 @@ mod benchmarks {
 
 		T::Currency::make_free_balance_be(&sender, BalanceOf::<T>::max_value());
 
-		// Generate a larger set of certificates.
-		for i in 0..10000 {
-			let target: T::AccountId = account("target", i, 0);
-			Certificate::<T>::send_certificate(RawOrigin::Signed(sender.clone()).into(), target.clone())?;
-		}
+		// Generate a larger set of accepted certificates.
+		generate_certificates::<T>(&sender, 10000);
 
 		let recipient: T::AccountId = account("Montgomery", 0, 0);
 		
+		let (payload, expires_at) = certificate_details::<T>();
+		let expiry = expires_at.ok_or(BenchmarkError::Stop("Certificate has no expiry"))?;
+		fill_expiries::<T>(expiry);
+
 		#[extrinsic_call]
-		send_certificate(RawOrigin::Signed(sender.clone()), recipient.clone());
+		send_certificate(RawOrigin::Signed(sender.clone()), recipient.clone(), payload, expires_at);
 
-		assert!(CertificateList::<T>::contains_key(sender.clone(), recipient.clone()));
+		assert!(PendingCertificates::<T>::contains_key(sender.clone(), recipient.clone()));
 		assert_last_event::<T>(Event::CertificateSent { sender, recipient }.into());
 		
 		Ok(())
@@ -80,24 +120,80 @@ mod benchmarks {
 
 		T::Currency::make_free_balance_be(&sender, BalanceOf::<T>::max_value());
 
-		// Generate a larger set of certificates.
-		for i in 0..10000 {
-			let target: T::AccountId = account("target", i, 0);
-			Certificate::<T>::send_certificate(RawOrigin::Signed(sender.clone()).into(), target.clone())?;
-		}
+		// Generate a larger set of accepted certificates.
+		generate_certificates::<T>(&sender, 10000);
 
 		let recipient: T::AccountId = account("Montgomery", 0, 0);
-		Certificate::<T>::send_certificate(RawOrigin::Signed(sender.clone()).into(), recipient.clone())?;
+		let (payload, expires_at) = certificate_details::<T>();
+		Certificate::<T>::send_certificate(RawOrigin::Signed(sender.clone()).into(), recipient.clone(), payload, expires_at)?;
 		
 		#[extrinsic_call]
-		revoke_certificate(RawOrigin::Signed(sender.clone()), recipient.clone());
+		revoke_certificate(RawOrigin::Signed(sender.clone()), recipient.clone(), RevocationReason::Withdrawn);
 
-		assert!(CertificateList::<T>::contains_key(sender.clone(), recipient.clone()));
-		assert!(!CertificateList::<T>::get(sender.clone(), recipient.clone()));
+		assert!(!PendingCertificates::<T>::contains_key(sender.clone(), recipient.clone()));
+		assert!(RevokedCertificates::<T>::contains_key(sender.clone(), recipient.clone()));
 		assert_last_event::<T>(Event::CertificateRevoked { sender, recipient }.into());
 		
 		Ok(())
 	}
 
+	#[benchmark]
+	fn expire_certificate() -> Result<(), BenchmarkError> {
+		let recipient: T::AccountId = account("Montgomery", 0, 0);
+		let sender: T::AccountId = whitelisted_caller();
+
+		T::Currency::make_free_balance_be(&sender, BalanceOf::<T>::max_value());
+		let (payload, expires_at) = certificate_details::<T>();
+		Certificate::<T>::send_certificate(RawOrigin::Signed(sender.clone()).into(), recipient.clone(), payload, expires_at)?;
+		let expiry = expires_at.ok_or(BenchmarkError::Stop("Certificate has no expiry"))?;
+
+		#[block]
+		{
+			Certificate::<T>::expire_certificate(&sender, &recipient, expiry);
+		}
+
+		assert!(!PendingCertificates::<T>::contains_key(sender.clone(), recipient.clone()));
+		assert_last_event::<T>(Event::CertificateExpired { sender, recipient }.into());
+		Ok(())
+	}
+
+	#[benchmark]
+	fn accept_certificate() -> Result<(), BenchmarkError> {
+		let recipient: T::AccountId = account("Montgomery", 0, 0);
+		let sender: T::AccountId = whitelisted_caller();
+
+		T::Currency::make_free_balance_be(&sender, BalanceOf::<T>::max_value());
+		let (payload, expires_at) = certificate_details::<T>();
+		let expiry = expires_at.ok_or(BenchmarkError::Stop("Certificate has no expiry"))?;
+		// The certificate lands in the last block it can, and has to search for it again.
+		fill_expiries::<T>(expiry);
+		Certificate::<T>::send_certificate(RawOrigin::Signed(sender.clone()).into(), recipient.clone(), payload, expires_at)?;
+
+		#[extrinsic_call]
+		accept_certificate(RawOrigin::Signed(recipient.clone()), sender.clone());
+
+		assert!(CertificateList::<T>::contains_key(sender.clone(), recipient.clone()));
+		assert!(CertificatesHeld::<T>::contains_key(recipient.clone(), sender.clone()));
+		assert_last_event::<T>(Event::CertificateAccepted { sender, recipient }.into());
+		Ok(())
+	}
+
+	#[benchmark]
+	fn reject_certificate() -> Result<(), BenchmarkError> {
+		let recipient: T::AccountId = account("Montgomery", 0, 0);
+		let sender: T::AccountId = whitelisted_caller();
+
+		T::Currency::make_free_balance_be(&sender, BalanceOf::<T>::max_value());
+		let (payload, expires_at) = certificate_details::<T>();
+		Certificate::<T>::send_certificate(RawOrigin::Signed(sender.clone()).into(), recipient.clone(), payload, expires_at)?;
+
+		#[extrinsic_call]
+		reject_certificate(RawOrigin::Signed(recipient.clone()), sender.clone());
+
+		assert!(!PendingCertificates::<T>::contains_key(sender.clone(), recipient.clone()));
+		assert_last_event::<T>(Event::CertificateRejected { sender, recipient }.into());
+		Ok(())
+	}
+
 	impl_benchmark_test_suite!(Certificate, crate::mock::new_test_ext(), crate::mock::Test);
 }
@@ -11,21 +11,25 @@ mod tests;
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
 
+pub mod migrations;
 pub mod weights;
-pub use weights::*;
 
-const CERTIFICATE_EXISTS: bool = true;
+extern crate alloc;
+pub use weights::*;
 
 #[frame_support::pallet]
 pub mod pallet {
 	use frame_support::{
-        dispatch::{DispatchResultWithPostInfo, DispatchResult},
+        dispatch::DispatchResultWithPostInfo,
 		pallet_prelude::*,
+		sp_runtime::traits::{Saturating, Zero},
 		traits::{Currency, LockIdentifier, LockableCurrency, WithdrawReasons},
 	};
 	use frame_system::pallet_prelude::*;
+	use fennel_expiry::ExpiryQueue;
 
-	use crate::{weights::WeightInfo, CERTIFICATE_EXISTS};
+	use crate::weights::WeightInfo;
+	use alloc::vec::Vec;
 
 	type BalanceOf<T> =
 		<<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
@@ -42,26 +46,181 @@ pub mod pallet {
 		>;
 		/// The identifier for the lock used to store certificate deposits.
 		type LockId: Get<LockIdentifier>;
-		/// The price of a certificate lock.
+		/// The amount locked for each live certificate an account has issued.
 		type LockPrice: Get<BalanceOf<Self>>;
+		/// The maximum length of a certificate payload, such as a content hash or URI.
+		#[pallet::constant]
+		type MaxPayloadSize: Get<u32>;
+		/// The maximum number of certificates the expiry sweep looks at in the same block.
+		/// Certificates due in a full block are scheduled in the next block with room.
+		#[pallet::constant]
+		type MaxExpiriesPerBlock: Get<u32>;
+		/// The number of blocks a recipient has to accept a certificate before it is dropped.
+		#[pallet::constant]
+		type AcceptanceTimeout: Get<BlockNumberFor<Self>>;
+	}
+
+	/// A certificate issued by one account to another.
+	#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub struct Certificate<Payload, BlockNumber> {
+		/// What the certificate attests to, typically a content hash or a URI.
+		pub payload: Payload,
+		/// The block in which the certificate was issued.
+		pub issued_at: BlockNumber,
+		/// The block in which the certificate expires, if it ever does.
+		pub expires_at: Option<BlockNumber>,
+	}
+
+	/// A certificate waiting for its recipient to accept it.
+	#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub struct PendingCertificate<Payload, BlockNumber> {
+		/// The certificate as it will be stored once accepted.
+		pub certificate: Certificate<Payload, BlockNumber>,
+		/// The block from which the certificate can no longer be accepted.
+		pub accept_by: BlockNumber,
+	}
+
+	/// Why an issuer revoked a certificate.
+	#[derive(
+		Encode, Decode, DecodeWithMemTracking, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen,
+	)]
+	pub enum RevocationReason {
+		/// The certificate should never have been issued.
+		IssuedInError,
+		/// The certificate was replaced by a newer one.
+		Superseded,
+		/// The issuer no longer stands behind the certificate.
+		Withdrawn,
+	}
+
+	/// What is kept about a certificate after it has been revoked.
+	#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub struct Revocation<Payload, BlockNumber> {
+		/// Why the certificate was revoked.
+		pub reason: RevocationReason,
+		/// The payload of the revoked certificate.
+		pub payload: Payload,
+		/// The block in which the revoked certificate was issued.
+		pub issued_at: BlockNumber,
+		/// The block in which the certificate was revoked.
+		pub revoked_at: BlockNumber,
 	}
 
+	/// The revocation record of runtime `T`.
+	pub type RevocationOf<T> = Revocation<BoundedVec<u8, <T as Config>::MaxPayloadSize>, BlockNumberFor<T>>;
+
+	/// The certificate record of runtime `T`.
+	pub type CertificateOf<T> = Certificate<BoundedVec<u8, <T as Config>::MaxPayloadSize>, BlockNumberFor<T>>;
+
+	/// A certificate of runtime `T` with its payload unbounded, as returned by queries.
+	pub type CertificateInfoOf<T> = Certificate<Vec<u8>, BlockNumberFor<T>>;
+
+	/// The pending certificate record of runtime `T`.
+	pub type PendingCertificateOf<T> =
+		PendingCertificate<BoundedVec<u8, <T as Config>::MaxPayloadSize>, BlockNumberFor<T>>;
+
+	/// A certificate scheduled to expire, identified by its issuer and recipient.
+	pub type CertificateKeyOf<T> = (<T as frame_system::Config>::AccountId, <T as frame_system::Config>::AccountId);
+
+	/// The `CertificateExpiries` queue swept in idle time.
+	pub struct CertificateExpiryQueue<T>(PhantomData<T>);
+
+	impl<T: Config> ExpiryQueue for CertificateExpiryQueue<T> {
+		type BlockNumber = BlockNumberFor<T>;
+		type Item = CertificateKeyOf<T>;
+		type MaxPerBlock = T::MaxExpiriesPerBlock;
+		type Buckets = CertificateExpiries<T>;
+		type Pending = PendingExpiries<T>;
+		type Cursor = ExpiryCursor<T>;
+		type DbWeight = T::DbWeight;
+	}
+
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(3);
+
 	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
 	#[pallet::storage]
 	#[pallet::getter(fn certificate_list)]
-	/// Maps accounts to the array of identities it owns.
+	/// Maps issuers and recipients to the certificate the issuer sent the recipient, once the
+	/// recipient has accepted it.
 	pub type CertificateList<T: Config> = StorageDoubleMap<
 		_,
 		Blake2_128Concat,
 		T::AccountId,
 		Blake2_128Concat,
 		T::AccountId,
-		bool,
+		CertificateOf<T>,
+	>;
+
+	#[pallet::storage]
+	/// Maps recipients and issuers to `()` for every certificate in `CertificateList`, so the
+	/// certificates an account holds can be listed without scanning every issuer.
+	pub type CertificatesHeld<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, T::AccountId, Blake2_128Concat, T::AccountId, ()>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn pending_certificate)]
+	/// Maps issuers and recipients to certificates the recipient has not accepted or rejected yet.
+	pub type PendingCertificates<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		Blake2_128Concat,
+		T::AccountId,
+		PendingCertificateOf<T>,
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn revoked_certificate)]
+	/// Maps issuers and recipients to the last certificate the issuer revoked for the recipient.
+	/// The record stays when a new certificate is issued to the same recipient, and is replaced
+	/// if that one is revoked in turn.
+	pub type RevokedCertificates<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		Blake2_128Concat,
+		T::AccountId,
+		RevocationOf<T>,
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn certificate_count)]
+	/// The number of live and pending certificates each account has issued. The account's
+	/// certificate lock is `LockPrice` times this number.
+	pub type CertificateCount<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, u32, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn certificate_expiries)]
+	/// Maps blocks to the certificates that expire, or stop waiting for acceptance, in them.
+	pub type CertificateExpiries<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		BlockNumberFor<T>,
+		BoundedVec<CertificateKeyOf<T>, T::MaxExpiriesPerBlock>,
 		ValueQuery,
 	>;
 
+	#[pallet::storage]
+	#[pallet::getter(fn scheduled_expiry)]
+	/// Maps issuers and recipients to the block whose `CertificateExpiries` entry holds their
+	/// certificate. Each pending or expiring certificate is scheduled exactly once.
+	pub type ScheduledExpiries<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, T::AccountId, Blake2_128Concat, T::AccountId, BlockNumberFor<T>>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn pending_expiries)]
+	/// The number of certificates held in `CertificateExpiries` that have not been swept yet.
+	pub type PendingExpiries<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn expiry_cursor)]
+	/// The next block whose expiring certificates have not been swept yet, while any are
+	/// pending.
+	pub type ExpiryCursor<T: Config> = StorageValue<_, BlockNumberFor<T>>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
@@ -69,8 +228,18 @@ pub mod pallet {
 		CertificateSent { sender: T::AccountId, recipient: T::AccountId },
         /// A `certificate` was revoked.
 		CertificateRevoked { sender: T::AccountId, recipient: T::AccountId },
+        /// `amount` was added to the certificate lock of `account`.
         CertificateLock { account: T::AccountId, amount: BalanceOf<T> },
+        /// `amount` was released from the certificate lock of `account`.
         CertificateUnlock { account: T::AccountId, amount: BalanceOf<T> },
+        /// A `certificate` reached its expiry block and was removed.
+		CertificateExpired { sender: T::AccountId, recipient: T::AccountId },
+        /// The recipient accepted a `certificate`.
+		CertificateAccepted { sender: T::AccountId, recipient: T::AccountId },
+        /// The recipient rejected a `certificate`.
+		CertificateRejected { sender: T::AccountId, recipient: T::AccountId },
+        /// A `certificate` was not accepted in time and was dropped.
+		CertificateTimedOut { sender: T::AccountId, recipient: T::AccountId },
 	}
 
 	#[pallet::error]
@@ -79,64 +248,301 @@ pub mod pallet {
 		CertificateNotOwned,
 		/// The certificate already exists.
 		CertificateExists,
+		/// The account cannot cover the lock for another certificate.
 		InsufficientBalance,
+		/// The expiry block has already been reached.
+		InvalidExpiry,
+		/// Too many certificates are already scheduled to expire in the requested block and the
+		/// blocks after it.
+		TooManyExpiries,
+		/// There is no certificate from the sender waiting for the recipient.
+		NoPendingCertificate,
+		/// The certificate was not accepted in time.
+		AcceptanceTimedOut,
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_idle(n: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+			Self::sweep_expired_certificates(n, remaining_weight)
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// Whether `sender` has issued `recipient` a certificate that is still valid in block `at`.
+		pub fn is_certificate_valid(
+			sender: &T::AccountId,
+			recipient: &T::AccountId,
+			at: BlockNumberFor<T>,
+		) -> bool {
+			<CertificateList<T>>::get(sender, recipient).is_some_and(|certificate| Self::is_live(&certificate, at))
+		}
+
+		/// Whether `certificate` has not expired by block `at`.
+		fn is_live(certificate: &CertificateOf<T>, at: BlockNumberFor<T>) -> bool {
+			certificate.expires_at.is_none_or(|expiry| at < expiry)
+		}
+
+		/// Returns the recipients and certificates of the certificates `sender` has issued that
+		/// are valid in the current block. Expired certificates are left out even before the
+		/// sweep removes them.
+		pub fn certificates_issued_by(sender: &T::AccountId) -> Vec<(T::AccountId, CertificateInfoOf<T>)> {
+			let now = frame_system::Pallet::<T>::block_number();
+			<CertificateList<T>>::iter_prefix(sender)
+				.filter(|(_, certificate)| Self::is_live(certificate, now))
+				.map(|(recipient, certificate)| (recipient, Self::unbounded(certificate)))
+				.collect()
+		}
+
+		/// Returns the issuers and certificates of the certificates `recipient` holds that are
+		/// valid in the current block. Expired certificates are left out even before the sweep
+		/// removes them.
+		pub fn certificates_held_by(recipient: &T::AccountId) -> Vec<(T::AccountId, CertificateInfoOf<T>)> {
+			let now = frame_system::Pallet::<T>::block_number();
+			<CertificatesHeld<T>>::iter_key_prefix(recipient)
+				.filter_map(|sender| {
+					let certificate = <CertificateList<T>>::get(&sender, recipient)?;
+					Self::is_live(&certificate, now).then(|| (sender, Self::unbounded(certificate)))
+				})
+				.collect()
+		}
+
+		fn unbounded(certificate: CertificateOf<T>) -> CertificateInfoOf<T> {
+			Certificate {
+				payload: certificate.payload.into_inner(),
+				issued_at: certificate.issued_at,
+				expires_at: certificate.expires_at,
+			}
+		}
+
+		/// The amount locked for `who` while they have `count` live certificates.
+		pub fn lock_amount(count: u32) -> BalanceOf<T> {
+			T::LockPrice::get().saturating_mul(count.into())
+		}
+
+		/// Sets the certificate lock of `who` to cover `count` live certificates.
+		pub(crate) fn set_certificate_lock(who: &T::AccountId, count: u32) {
+			if count == 0 {
+				T::Currency::remove_lock(T::LockId::get(), who);
+			} else {
+				T::Currency::set_lock(T::LockId::get(), who, Self::lock_amount(count), WithdrawReasons::all());
+			}
+		}
+
+		/// Releases the share of the lock of `sender` held for one of their certificates, emitting
+		/// `CertificateUnlock` with the amount released, if any.
+		fn release_certificate_lock(sender: &T::AccountId) {
+			let (previous, count) = <CertificateCount<T>>::mutate_exists(sender, |count| {
+				let previous = count.unwrap_or_default();
+				let remaining = previous.saturating_sub(1);
+				*count = (remaining > 0).then_some(remaining);
+				(previous, remaining)
+			});
+			Self::set_certificate_lock(sender, count);
+			let released = Self::lock_amount(previous).saturating_sub(Self::lock_amount(count));
+			if !released.is_zero() {
+				Self::deposit_event(Event::CertificateUnlock { account: sender.clone(), amount: released });
+			}
+		}
+
+		/// Removes the certificate `sender` issued to `recipient` and releases its share of the
+		/// issuer's lock.
+		fn remove_certificate(sender: &T::AccountId, recipient: &T::AccountId) {
+			Self::unschedule_expiry(sender, recipient);
+			Self::release_certificate_lock(sender);
+			<CertificateList<T>>::remove(sender, recipient);
+			<CertificatesHeld<T>>::remove(recipient, sender);
+		}
+
+		/// Drops the certificate `sender` sent `recipient` that is still waiting for acceptance and
+		/// releases its share of the issuer's lock.
+		fn remove_pending_certificate(sender: &T::AccountId, recipient: &T::AccountId) {
+			Self::unschedule_expiry(sender, recipient);
+			Self::release_certificate_lock(sender);
+			<PendingCertificates<T>>::remove(sender, recipient);
+		}
+
+		/// The block from which a pending certificate can be dropped, either because it expired
+		/// or because its acceptance window closed.
+		pub(crate) fn pending_deadline(pending: &PendingCertificateOf<T>) -> BlockNumberFor<T> {
+			pending.certificate.expires_at.map_or(pending.accept_by, |expiry| expiry.min(pending.accept_by))
+		}
+
+		/// Schedules the certificate `sender` sent `recipient` to be looked at by the sweep in
+		/// block `at`, or in the first of the `MAX_EXPIRY_DELAY` blocks after it with room.
+		pub(crate) fn schedule_expiry(
+			sender: &T::AccountId,
+			recipient: &T::AccountId,
+			at: BlockNumberFor<T>,
+			now: BlockNumberFor<T>,
+		) -> DispatchResult {
+			let block = CertificateExpiryQueue::<T>::schedule((sender.clone(), recipient.clone()), at, now)
+				.ok_or(Error::<T>::TooManyExpiries)?;
+			<ScheduledExpiries<T>>::insert(sender, recipient, block);
+			Ok(())
+		}
+
+		/// Removes the certificate `sender` sent `recipient` from the block it is scheduled in,
+		/// freeing its place there.
+		fn unschedule_expiry(sender: &T::AccountId, recipient: &T::AccountId) {
+			let Some(block) = <ScheduledExpiries<T>>::take(sender, recipient) else { return };
+			CertificateExpiryQueue::<T>::unschedule(&(sender.clone(), recipient.clone()), block);
+		}
+
+		/// Removes certificates that expired in or before block `now`, oldest first, until either
+		/// none remain or `remaining_weight` is used up. Returns the weight used.
+		pub(crate) fn sweep_expired_certificates(now: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+			let certificate_weight = T::WeightInfo::expire_certificate();
+			CertificateExpiryQueue::<T>::sweep(now, remaining_weight, certificate_weight, |(sender, recipient), at| {
+				Self::expire_certificate(&sender, &recipient, at)
+			})
+		}
+
+		/// Removes the certificate `sender` issued to `recipient` if it is scheduled in block `at`,
+		/// or drops it if it is still waiting for acceptance, emitting `CertificateExpired` or
+		/// `CertificateTimedOut`. Entries left behind by certificates rescheduled since are
+		/// ignored.
+		pub(crate) fn expire_certificate(sender: &T::AccountId, recipient: &T::AccountId, at: BlockNumberFor<T>) {
+			if <ScheduledExpiries<T>>::get(sender, recipient) != Some(at) {
+				return;
+			}
+			// The sweep has already taken the entry out of `CertificateExpiries`.
+			<ScheduledExpiries<T>>::remove(sender, recipient);
+			if <CertificateList<T>>::contains_key(sender, recipient) {
+				Self::remove_certificate(sender, recipient);
+				Self::deposit_event(Event::CertificateExpired { sender: sender.clone(), recipient: recipient.clone() });
+			} else if let Some(pending) = <PendingCertificates<T>>::get(sender, recipient) {
+				Self::remove_pending_certificate(sender, recipient);
+				if pending.certificate.expires_at.is_some_and(|expiry| expiry <= pending.accept_by) {
+					Self::deposit_event(Event::CertificateExpired { sender: sender.clone(), recipient: recipient.clone() });
+				} else {
+					Self::deposit_event(Event::CertificateTimedOut { sender: sender.clone(), recipient: recipient.clone() });
+				}
+			}
+		}
 	}
 
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
 		/// Creates an on-chain event with a Certificate payload defined as part of the transaction
-		/// and commits the details to storage.
+		/// and commits the details to storage. The payload is typically a content hash or URI. If
+		/// `expires_at` is given, the certificate is removed once that block is reached and the
+		/// chain has spare block weight, emitting `CertificateExpired`.
+		///
+		/// The certificate waits in `PendingCertificates` until the recipient accepts or rejects
+		/// it. If neither happens within `AcceptanceTimeout` blocks it is dropped, emitting
+		/// `CertificateTimedOut`. The issuer's lock covers pending certificates too.
 		#[pallet::weight(T::WeightInfo::send_certificate())]
 		#[pallet::call_index(0)]
-        pub fn send_certificate(origin: OriginFor<T>, recipient: T::AccountId) -> DispatchResultWithPostInfo {
+        pub fn send_certificate(
+			origin: OriginFor<T>,
+			recipient: T::AccountId,
+			payload: BoundedVec<u8, T::MaxPayloadSize>,
+			expires_at: Option<BlockNumberFor<T>>,
+		) -> DispatchResultWithPostInfo {
 			let who = ensure_signed(origin)?;
 			if T::Currency::total_balance(&who) < T::Currency::minimum_balance() {
 				return Err(Error::<T>::InsufficientBalance.into());
 			}
 			ensure!(
-				!CertificateList::<T>::contains_key(&who, &recipient),
+				!CertificateList::<T>::contains_key(&who, &recipient) &&
+					!PendingCertificates::<T>::contains_key(&who, &recipient),
 				Error::<T>::CertificateExists
 			);
-			// Insert a placeholder value into storage - if the pair (who, recipient) exists, we
-			// know there's a certificate present for the pair, regardless of value.
-			T::Currency::set_lock(T::LockId::get(), &who, T::LockPrice::get(), WithdrawReasons::all());
+			let count = <CertificateCount<T>>::get(&who).saturating_add(1);
+			ensure!(
+				T::Currency::free_balance(&who) >= Self::lock_amount(count),
+				Error::<T>::InsufficientBalance
+			);
+			let now = frame_system::Pallet::<T>::block_number();
+			if let Some(expiry) = expires_at {
+				ensure!(expiry > now, Error::<T>::InvalidExpiry);
+			}
+			let pending = PendingCertificate {
+				certificate: Certificate { payload, issued_at: now, expires_at },
+				accept_by: now.saturating_add(T::AcceptanceTimeout::get()),
+			};
+			Self::schedule_expiry(&who, &recipient, Self::pending_deadline(&pending), now)?;
+			<CertificateCount<T>>::insert(&who, count);
+			Self::set_certificate_lock(&who, count);
             Self::deposit_event(Event::CertificateLock { account: who.clone(), amount: T::LockPrice::get() });
-			<CertificateList<T>>::try_mutate(
-				&who,
-				recipient.clone(),
-				|certificate| -> DispatchResult {
-					*certificate = CERTIFICATE_EXISTS;
-					Ok(())
-				},
-			)?;
+			<PendingCertificates<T>>::insert(&who, &recipient, pending);
             Self::deposit_event(Event::CertificateSent { sender: who.clone(), recipient: recipient.clone() });
             Ok(().into())
 		}
 		#[pallet::weight(T::WeightInfo::revoke_certificate())]
 		#[pallet::call_index(1)]
-		/// Revokes the identity with ID number identity_id, as long as the identity is owned by
-		/// origin.
-		pub fn revoke_certificate(origin: OriginFor<T>, recipient: T::AccountId) -> DispatchResultWithPostInfo {
+		/// Revokes the certificate origin issued to `recipient`, whether or not it has been
+		/// accepted yet. The certificate is removed, so a new one can be issued, and a record of it
+		/// is kept in `RevokedCertificates` along with `reason`.
+		pub fn revoke_certificate(
+			origin: OriginFor<T>,
+			recipient: T::AccountId,
+			reason: RevocationReason,
+		) -> DispatchResultWithPostInfo {
 			let who = ensure_signed(origin)?;
 			if T::Currency::total_balance(&who) < T::Currency::minimum_balance() {
 				return Err(Error::<T>::InsufficientBalance.into());
 			}
-			ensure!(
-				CertificateList::<T>::contains_key(&who, &recipient),
-				Error::<T>::CertificateNotOwned
-			);
-			T::Currency::remove_lock(T::LockId::get(), &who);
-            Self::deposit_event(Event::CertificateUnlock { account: who.clone(), amount: T::Currency::free_balance(&who) });
-			<CertificateList<T>>::try_mutate(
+			let certificate = if let Some(certificate) = CertificateList::<T>::get(&who, &recipient) {
+				Self::remove_certificate(&who, &recipient);
+				certificate
+			} else {
+				let pending =
+					PendingCertificates::<T>::get(&who, &recipient).ok_or(Error::<T>::CertificateNotOwned)?;
+				Self::remove_pending_certificate(&who, &recipient);
+				pending.certificate
+			};
+			<RevokedCertificates<T>>::insert(
 				&who,
-				recipient.clone(),
-				|certificate| -> DispatchResult {
-					*certificate = !CERTIFICATE_EXISTS;
-					Ok(())
+				&recipient,
+				Revocation {
+					reason,
+					payload: certificate.payload,
+					issued_at: certificate.issued_at,
+					revoked_at: frame_system::Pallet::<T>::block_number(),
 				},
-			)?;
+			);
             Self::deposit_event(Event::CertificateRevoked { sender: who.clone(), recipient: recipient.clone() });
             Ok(().into())
 		}
+
+		#[pallet::weight(T::WeightInfo::accept_certificate())]
+		#[pallet::call_index(2)]
+		/// Accepts the certificate `sender` sent origin, making it live. The certificate must be
+		/// accepted before its acceptance window closes and before it expires.
+		pub fn accept_certificate(origin: OriginFor<T>, sender: T::AccountId) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			let pending =
+				PendingCertificates::<T>::get(&sender, &who).ok_or(Error::<T>::NoPendingCertificate)?;
+			let now = frame_system::Pallet::<T>::block_number();
+			ensure!(now < pending.accept_by, Error::<T>::AcceptanceTimedOut);
+			ensure!(pending.certificate.expires_at.is_none_or(|expiry| now < expiry), Error::<T>::InvalidExpiry);
+			// The certificate no longer waits for acceptance, so only its expiry stays scheduled.
+			Self::unschedule_expiry(&sender, &who);
+			if let Some(expiry) = pending.certificate.expires_at {
+				Self::schedule_expiry(&sender, &who, expiry, now)?;
+			}
+			<PendingCertificates<T>>::remove(&sender, &who);
+			<CertificateList<T>>::insert(&sender, &who, pending.certificate);
+			<CertificatesHeld<T>>::insert(&who, &sender, ());
+            Self::deposit_event(Event::CertificateAccepted { sender, recipient: who });
+            Ok(().into())
+		}
+
+		#[pallet::weight(T::WeightInfo::reject_certificate())]
+		#[pallet::call_index(3)]
+		/// Rejects the certificate `sender` sent origin. The certificate is dropped and the
+		/// sender's lock for it is released.
+		pub fn reject_certificate(origin: OriginFor<T>, sender: T::AccountId) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			ensure!(
+				PendingCertificates::<T>::contains_key(&sender, &who),
+				Error::<T>::NoPendingCertificate
+			);
+			Self::remove_pending_certificate(&sender, &who);
+            Self::deposit_event(Event::CertificateRejected { sender, recipient: who });
+            Ok(().into())
+		}
 	}
 }
@@ -0,0 +1,131 @@
+//! Storage migrations for pallet-certificate.
+
+/// Migrates the pallet from storage version 0 to 1.
+///
+/// Version 1 replaces the `bool` stored in `CertificateList` with a [`Certificate`] record
+/// carrying a payload, the issuing block and an optional expiry. Certificates issued before the
+/// upgrade get an empty payload, no expiry, and the block the migration runs in as their issuing
+/// block. Entries left at `false` by earlier revocations are removed.
+///
+/// [`Certificate`]: crate::Certificate
+pub mod v1 {
+    use crate::{Certificate, CertificateList, Config, Pallet};
+    use core::marker::PhantomData;
+    use frame_support::{
+        migrations::VersionedMigration,
+        traits::{Get, UncheckedOnRuntimeUpgrade},
+        weights::Weight,
+        BoundedVec,
+    };
+
+    /// Converts `CertificateList` to certificate records. Use [`MigrateV0ToV1`] instead, which
+    /// only runs this once and bumps the storage version.
+    pub struct InnerMigrateV0ToV1<T>(PhantomData<T>);
+
+    impl<T: Config> UncheckedOnRuntimeUpgrade for InnerMigrateV0ToV1<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let issued_at = frame_system::Pallet::<T>::block_number();
+            let mut translated: u64 = 0;
+            CertificateList::<T>::translate::<bool, _>(|_, _, exists| {
+                translated = translated.saturating_add(1);
+                exists.then(|| Certificate { payload: BoundedVec::new(), issued_at, expires_at: None })
+            });
+            T::DbWeight::get().reads_writes(translated.saturating_add(1), translated)
+        }
+    }
+
+    /// Migrates pallet-certificate from storage version 0 to 1.
+    pub type MigrateV0ToV1<T> = VersionedMigration<
+        0,
+        1,
+        InnerMigrateV0ToV1<T>,
+        Pallet<T>,
+        <T as frame_system::Config>::DbWeight,
+    >;
+}
+
+/// Migrates the pallet from storage version 1 to 2.
+///
+/// Version 2 locks `LockPrice` for every live certificate instead of once per issuer, tracked by
+/// the new `CertificateCount` map. This migration counts each issuer's certificates and resizes
+/// their lock to match.
+pub mod v2 {
+    use crate::{CertificateCount, CertificateList, Config, Pallet};
+    use alloc::collections::btree_map::BTreeMap;
+    use core::marker::PhantomData;
+    use frame_support::{
+        migrations::VersionedMigration,
+        traits::{Get, UncheckedOnRuntimeUpgrade},
+        weights::Weight,
+    };
+
+    /// Fills in `CertificateCount` and resizes issuer locks. Use [`MigrateV1ToV2`] instead,
+    /// which only runs this once and bumps the storage version.
+    pub struct InnerMigrateV1ToV2<T>(PhantomData<T>);
+
+    impl<T: Config> UncheckedOnRuntimeUpgrade for InnerMigrateV1ToV2<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let mut counts = BTreeMap::<T::AccountId, u32>::new();
+            let mut reads: u64 = 0;
+            for (sender, _) in CertificateList::<T>::iter_keys() {
+                reads = reads.saturating_add(1);
+                let count = counts.entry(sender).or_default();
+                *count = count.saturating_add(1);
+            }
+            let issuers = counts.len() as u64;
+            for (sender, count) in counts {
+                CertificateCount::<T>::insert(&sender, count);
+                Pallet::<T>::set_certificate_lock(&sender, count);
+            }
+            // Each issuer's lock update reads and writes their balance locks.
+            T::DbWeight::get().reads_writes(reads.saturating_add(issuers), issuers.saturating_mul(2))
+        }
+    }
+
+    /// Migrates pallet-certificate from storage version 1 to 2.
+    pub type MigrateV1ToV2<T> = VersionedMigration<
+        1,
+        2,
+        InnerMigrateV1ToV2<T>,
+        Pallet<T>,
+        <T as frame_system::Config>::DbWeight,
+    >;
+}
+
+/// Migrates the pallet from storage version 2 to 3.
+///
+/// Version 3 adds the recipient-first `CertificatesHeld` index, which this migration fills in
+/// from `CertificateList`.
+pub mod v3 {
+    use crate::{CertificateList, CertificatesHeld, Config, Pallet};
+    use core::marker::PhantomData;
+    use frame_support::{
+        migrations::VersionedMigration,
+        traits::{Get, UncheckedOnRuntimeUpgrade},
+        weights::Weight,
+    };
+
+    /// Backfills `CertificatesHeld` from `CertificateList`. Use [`MigrateV2ToV3`] instead,
+    /// which only runs this once and bumps the storage version.
+    pub struct InnerMigrateV2ToV3<T>(PhantomData<T>);
+
+    impl<T: Config> UncheckedOnRuntimeUpgrade for InnerMigrateV2ToV3<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let mut indexed: u64 = 0;
+            for (sender, recipient) in CertificateList::<T>::iter_keys() {
+                CertificatesHeld::<T>::insert(recipient, sender, ());
+                indexed = indexed.saturating_add(1);
+            }
+            T::DbWeight::get().reads_writes(indexed, indexed)
+        }
+    }
+
+    /// Migrates pallet-certificate from storage version 2 to 3.
+    pub type MigrateV2ToV3<T> = VersionedMigration<
+        2,
+        3,
+        InnerMigrateV2ToV3<T>,
+        Pallet<T>,
+        <T as frame_system::Config>::DbWeight,
+    >;
+}
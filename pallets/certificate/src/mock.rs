@@ -82,6 +82,9 @@ impl pallet_certificate::Config for Test {
 	type Currency = Balances;
 	type LockId = MockLockIdentifier;
 	type LockPrice = MockLockPrice;
+	type MaxPayloadSize = frame_support::traits::ConstU32<64>;
+	type MaxExpiriesPerBlock = frame_support::traits::ConstU32<3>;
+	type AcceptanceTimeout = frame_support::traits::ConstU64<20>;
 }
 
 // Build genesis storage according to the mock runtime.
@@ -1,14 +1,34 @@
-use crate::{mock::*, Error};
-use frame_support::{assert_noop, assert_ok, traits::Currency};
+use crate::{
+	mock::*, Certificate, CertificateExpiries, CertificateList, CertificatesHeld, Error, ExpiryCursor,
+	PendingCertificate,
+	PendingCertificates, Revocation, RevocationReason, RevokedCertificates, ScheduledExpiries,
+};
+use frame_support::{
+	assert_noop, assert_ok,
+	traits::{ConstU32, Currency, GetStorageVersion, Hooks, OnRuntimeUpgrade, StorageVersion},
+	weights::Weight,
+	BoundedVec,
+};
+
+fn payload() -> BoundedVec<u8, ConstU32<64>> {
+	BoundedVec::try_from(b"ipfs://certificate".to_vec()).unwrap()
+}
+
+/// Sends a certificate from `sender` to `recipient` and has the recipient accept it.
+fn issue(sender: u64, recipient: u64, expires_at: Option<u64>) {
+	assert_ok!(CertificateModule::send_certificate(RuntimeOrigin::signed(sender), recipient, payload(), expires_at));
+	assert_ok!(CertificateModule::accept_certificate(RuntimeOrigin::signed(recipient), sender));
+}
 
 #[test]
 fn test_send_certificate() {
 	new_test_ext().execute_with(|| {
 		System::set_block_number(1);
 		let _ = Balances::deposit_creating(&1, 100);
-		assert_ok!(CertificateModule::send_certificate(RuntimeOrigin::signed(1), 1));
+		assert_ok!(CertificateModule::send_certificate(RuntimeOrigin::signed(1), 1, payload(), None));
 		System::assert_last_event(crate::Event::CertificateSent { sender: 1, recipient: 1 }.into());
-		assert_eq!(CertificateModule::certificate_list(1, 1), true);
+		assert!(CertificateModule::pending_certificate(1, 1).is_some());
+		assert!(CertificateModule::certificate_list(1, 1).is_none());
 	});
 }
 
@@ -17,13 +37,18 @@ fn test_send_existing_certificate() {
 	new_test_ext().execute_with(|| {
 		System::set_block_number(1);
 		let _ = Balances::deposit_creating(&1, 100);
-		assert_ok!(CertificateModule::send_certificate(RuntimeOrigin::signed(1), 1));
+		assert_ok!(CertificateModule::send_certificate(RuntimeOrigin::signed(1), 1, payload(), None));
 		System::assert_last_event(crate::Event::CertificateSent { sender: 1, recipient: 1 }.into());
 		assert_noop!(
-			CertificateModule::send_certificate(RuntimeOrigin::signed(1), 1),
+			CertificateModule::send_certificate(RuntimeOrigin::signed(1), 1, payload(), None),
 			Error::<Test>::CertificateExists
 		);
-		assert_eq!(CertificateModule::certificate_list(1, 1), true);
+		assert_ok!(CertificateModule::accept_certificate(RuntimeOrigin::signed(1), 1));
+		assert_noop!(
+			CertificateModule::send_certificate(RuntimeOrigin::signed(1), 1, payload(), None),
+			Error::<Test>::CertificateExists
+		);
+		assert!(CertificateModule::certificate_list(1, 1).is_some());
 	});
 }
 
@@ -32,11 +57,10 @@ fn test_revoke_certificate() {
 	new_test_ext().execute_with(|| {
 		System::set_block_number(1);
 		let _ = Balances::deposit_creating(&1, 100);
-		assert_ok!(CertificateModule::send_certificate(RuntimeOrigin::signed(1), 1));
-		System::assert_last_event(crate::Event::CertificateSent { sender: 1, recipient: 1 }.into());
-		assert_ok!(CertificateModule::revoke_certificate(RuntimeOrigin::signed(1), 1));
+		issue(1, 1, None);
+		assert_ok!(CertificateModule::revoke_certificate(RuntimeOrigin::signed(1), 1, RevocationReason::Withdrawn));
 		System::assert_last_event(crate::Event::CertificateRevoked { sender: 1, recipient: 1 }.into());
-		assert_eq!(CertificateModule::certificate_list(1, 1), false);
+		assert!(CertificateModule::certificate_list(1, 1).is_none());
 	});
 }
 
@@ -46,12 +70,527 @@ fn test_try_revoke_unowned_certificate() {
 		System::set_block_number(1);
 		let _ = Balances::deposit_creating(&1, 100);
 		let _ = Balances::deposit_creating(&2, 100);
-		assert_ok!(CertificateModule::send_certificate(RuntimeOrigin::signed(1), 1));
-		System::assert_last_event(crate::Event::CertificateSent { sender: 1, recipient: 1 }.into());
+		issue(1, 1, None);
 		assert_noop!(
-			CertificateModule::revoke_certificate(RuntimeOrigin::signed(2), 1),
+			CertificateModule::revoke_certificate(RuntimeOrigin::signed(2), 1, RevocationReason::Withdrawn),
 			Error::<Test>::CertificateNotOwned
 		);
-		assert_eq!(CertificateModule::certificate_list(1, 1), true);
+		assert!(CertificateModule::certificate_list(1, 1).is_some());
+	});
+}
+
+#[test]
+fn test_send_certificate_records_details() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(3);
+		let _ = Balances::deposit_creating(&1, 100);
+		assert_ok!(CertificateModule::send_certificate(RuntimeOrigin::signed(1), 2, payload(), Some(10)));
+		let certificate = Certificate { payload: payload(), issued_at: 3, expires_at: Some(10) };
+		assert_eq!(
+			CertificateModule::pending_certificate(1, 2),
+			Some(PendingCertificate { certificate: certificate.clone(), accept_by: 23 })
+		);
+		// Only the earlier of the expiry and the acceptance timeout is scheduled.
+		assert_eq!(CertificateExpiries::<Test>::get(10).into_inner(), vec![(1, 2)]);
+		assert!(CertificateExpiries::<Test>::get(23).is_empty());
+		assert_eq!(ScheduledExpiries::<Test>::get(1, 2), Some(10));
+		assert!(!CertificateModule::is_certificate_valid(&1, &2, 3));
+
+		assert_ok!(CertificateModule::accept_certificate(RuntimeOrigin::signed(2), 1));
+		assert_eq!(CertificateModule::certificate_list(1, 2), Some(certificate));
+		assert!(CertificateModule::is_certificate_valid(&1, &2, 9));
+		assert!(!CertificateModule::is_certificate_valid(&1, &2, 10));
+	});
+}
+
+#[test]
+fn test_send_certificate_rejects_past_expiry() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(5);
+		let _ = Balances::deposit_creating(&1, 100);
+		assert_noop!(
+			CertificateModule::send_certificate(RuntimeOrigin::signed(1), 2, payload(), Some(5)),
+			Error::<Test>::InvalidExpiry
+		);
+	});
+}
+
+#[test]
+fn test_sends_beyond_the_expiry_cap_overflow_into_later_blocks() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let _ = Balances::deposit_creating(&1, 100);
+		// Every certificate sent in block 1 times out in block 21, which holds three of them.
+		for recipient in 2..9 {
+			assert_ok!(CertificateModule::send_certificate(RuntimeOrigin::signed(1), recipient, payload(), None));
+		}
+		assert_eq!(CertificateExpiries::<Test>::get(21).len(), 3);
+		assert_eq!(CertificateExpiries::<Test>::get(22).len(), 3);
+		assert_eq!(CertificateExpiries::<Test>::get(23).into_inner(), vec![(1, 8)]);
+		assert_eq!(ScheduledExpiries::<Test>::get(1, 8), Some(23));
+
+		// Overflowing certificates can no longer be accepted once their window closes.
+		System::set_block_number(21);
+		assert_noop!(
+			CertificateModule::accept_certificate(RuntimeOrigin::signed(8), 1),
+			Error::<Test>::AcceptanceTimedOut
+		);
+		System::set_block_number(23);
+		CertificateModule::on_idle(23, Weight::MAX);
+		assert_eq!(PendingCertificates::<Test>::iter().count(), 0);
+		assert_eq!(CertificateModule::certificate_count(1), 0);
+		System::assert_has_event(crate::Event::CertificateTimedOut { sender: 1, recipient: 8 }.into());
+	});
+}
+
+#[test]
+fn test_certificates_leaving_storage_free_their_expiry_slot() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let _ = Balances::deposit_creating(&1, 100);
+		for recipient in 2..5 {
+			assert_ok!(CertificateModule::send_certificate(RuntimeOrigin::signed(1), recipient, payload(), Some(10)));
+		}
+		assert_eq!(CertificateExpiries::<Test>::get(10).len(), 3);
+
+		assert_ok!(CertificateModule::revoke_certificate(RuntimeOrigin::signed(1), 2, RevocationReason::Withdrawn));
+		assert_ok!(CertificateModule::reject_certificate(RuntimeOrigin::signed(3), 1));
+		assert_eq!(CertificateExpiries::<Test>::get(10).into_inner(), vec![(1, 4)]);
+		assert!(!ScheduledExpiries::<Test>::contains_key(1, 2));
+		assert!(!ScheduledExpiries::<Test>::contains_key(1, 3));
+
+		// Accepting keeps the expiry scheduled; without one nothing stays scheduled.
+		assert_ok!(CertificateModule::accept_certificate(RuntimeOrigin::signed(4), 1));
+		assert_eq!(CertificateExpiries::<Test>::get(10).into_inner(), vec![(1, 4)]);
+		assert_ok!(CertificateModule::send_certificate(RuntimeOrigin::signed(1), 5, payload(), None));
+		assert_ok!(CertificateModule::accept_certificate(RuntimeOrigin::signed(5), 1));
+		assert!(CertificateExpiries::<Test>::get(21).is_empty());
+		assert!(!ScheduledExpiries::<Test>::contains_key(1, 5));
+	});
+}
+
+#[test]
+fn test_send_certificate_fails_when_every_nearby_block_is_full() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let _ = Balances::deposit_creating(&1, 100);
+		for block in 10..10 + fennel_expiry::MAX_EXPIRY_DELAY as u64 {
+			let full: Vec<(u64, u64)> = (0..3).map(|i| (100 + block, i)).collect();
+			CertificateExpiries::<Test>::insert(block, BoundedVec::try_from(full).unwrap());
+		}
+		assert_noop!(
+			CertificateModule::send_certificate(RuntimeOrigin::signed(1), 2, payload(), Some(10)),
+			Error::<Test>::TooManyExpiries
+		);
+	});
+}
+
+#[test]
+fn test_expired_certificates_are_swept() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let _ = Balances::deposit_creating(&1, 100);
+		issue(1, 2, Some(3));
+		issue(1, 3, None);
+
+		CertificateModule::on_idle(2, Weight::MAX);
+		assert!(CertificateList::<Test>::contains_key(1, 2));
+
+		System::set_block_number(4);
+		CertificateModule::on_idle(4, Weight::MAX);
+		assert!(!CertificateList::<Test>::contains_key(1, 2));
+		assert!(CertificateList::<Test>::contains_key(1, 3));
+		assert!(CertificateExpiries::<Test>::get(3).is_empty());
+		// Nothing else is scheduled, so the sweep stops until another expiry is.
+		assert_eq!(ExpiryCursor::<Test>::get(), None);
+		System::assert_last_event(crate::Event::CertificateExpired { sender: 1, recipient: 2 }.into());
+	});
+}
+
+#[test]
+fn test_sweep_stops_when_out_of_weight() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let _ = Balances::deposit_creating(&1, 100);
+		issue(1, 2, Some(2));
+		issue(1, 3, Some(2));
+
+		// Enough weight to reach block 2 and expire a single certificate.
+		let budget = <() as crate::WeightInfo>::expire_certificate();
+		System::set_block_number(2);
+		assert_eq!(CertificateModule::on_idle(2, budget), budget);
+		assert!(!CertificateList::<Test>::contains_key(1, 3));
+		assert!(CertificateList::<Test>::contains_key(1, 2));
+		assert_eq!(ExpiryCursor::<Test>::get(), Some(2));
+
+		CertificateModule::on_idle(2, Weight::MAX);
+		assert!(!CertificateList::<Test>::contains_key(1, 2));
+		assert_eq!(ExpiryCursor::<Test>::get(), None);
+	});
+}
+
+#[test]
+fn test_revoking_the_last_expiring_certificate_clears_the_cursor() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let _ = Balances::deposit_creating(&1, 100);
+		issue(1, 2, Some(10));
+		assert!(ExpiryCursor::<Test>::get().is_some());
+
+		assert_ok!(CertificateModule::revoke_certificate(RuntimeOrigin::signed(1), 2, RevocationReason::Superseded));
+		assert_eq!(CertificateModule::pending_expiries(), 0);
+		assert_eq!(ExpiryCursor::<Test>::get(), None);
+	});
+}
+
+#[test]
+fn test_revoked_certificate_is_not_expired() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let _ = Balances::deposit_creating(&1, 100);
+		issue(1, 2, Some(3));
+		assert_ok!(CertificateModule::revoke_certificate(RuntimeOrigin::signed(1), 2, RevocationReason::Withdrawn));
+		issue(1, 2, None);
+
+		System::set_block_number(3);
+		CertificateModule::on_idle(3, Weight::MAX);
+		assert!(CertificateList::<Test>::contains_key(1, 2));
+	});
+}
+
+#[test]
+fn test_migration_v1_converts_certificates() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(6);
+		StorageVersion::new(0).put::<CertificateModule>();
+		frame_support::storage::unhashed::put(&CertificateList::<Test>::hashed_key_for(1, 2), &true);
+		frame_support::storage::unhashed::put(&CertificateList::<Test>::hashed_key_for(1, 3), &false);
+
+		crate::migrations::v1::MigrateV0ToV1::<Test>::on_runtime_upgrade();
+
+		assert_eq!(
+			CertificateList::<Test>::get(1, 2),
+			Some(Certificate { payload: BoundedVec::new(), issued_at: 6, expires_at: None })
+		);
+		assert!(!CertificateList::<Test>::contains_key(1, 3));
+		assert_eq!(CertificateModule::on_chain_storage_version(), StorageVersion::new(1));
+	});
+}
+
+fn locked(who: u64) -> u128 {
+	pallet_balances::Locks::<Test>::get(who).iter().map(|lock| lock.amount).sum()
+}
+
+#[test]
+fn test_lock_scales_with_live_certificates() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let _ = Balances::deposit_creating(&1, 100);
+		for recipient in 2..5 {
+			assert_ok!(CertificateModule::send_certificate(RuntimeOrigin::signed(1), recipient, payload(), None));
+			System::assert_has_event(crate::Event::CertificateLock { account: 1, amount: 10 }.into());
+		}
+		assert_eq!(CertificateModule::certificate_count(1), 3);
+		assert_eq!(locked(1), 30);
+
+		assert_ok!(CertificateModule::revoke_certificate(RuntimeOrigin::signed(1), 2, RevocationReason::Withdrawn));
+		System::assert_has_event(crate::Event::CertificateUnlock { account: 1, amount: 10 }.into());
+		assert_eq!(CertificateModule::certificate_count(1), 2);
+		assert_eq!(locked(1), 20);
+
+		assert_ok!(CertificateModule::revoke_certificate(RuntimeOrigin::signed(1), 3, RevocationReason::Withdrawn));
+		assert_ok!(CertificateModule::revoke_certificate(RuntimeOrigin::signed(1), 4, RevocationReason::Withdrawn));
+		assert!(!crate::CertificateCount::<Test>::contains_key(1));
+		assert_eq!(locked(1), 0);
+	});
+}
+
+#[test]
+fn test_expiry_releases_its_share_of_the_lock() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let _ = Balances::deposit_creating(&1, 100);
+		issue(1, 2, Some(2));
+		issue(1, 3, None);
+		assert_eq!(locked(1), 20);
+
+		System::set_block_number(2);
+		CertificateModule::on_idle(2, Weight::MAX);
+		assert_eq!(CertificateModule::certificate_count(1), 1);
+		assert_eq!(locked(1), 10);
+		System::assert_has_event(crate::Event::CertificateUnlock { account: 1, amount: 10 }.into());
+	});
+}
+
+#[test]
+fn test_unlock_is_not_reported_when_nothing_was_locked() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let _ = Balances::deposit_creating(&1, 100);
+		// A certificate the issuer's count does not cover, so no lock is held for it.
+		CertificateList::<Test>::insert(1, 2, Certificate { payload: payload(), issued_at: 1, expires_at: None });
+		assert_ok!(CertificateModule::revoke_certificate(RuntimeOrigin::signed(1), 2, RevocationReason::Withdrawn));
+		assert!(!System::events().iter().any(|record| matches!(
+			record.event,
+			RuntimeEvent::CertificateModule(crate::Event::CertificateUnlock { .. })
+		)));
+		assert_eq!(locked(1), 0);
+	});
+}
+
+#[test]
+fn test_send_certificate_requires_balance_for_the_lock() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let _ = Balances::deposit_creating(&1, 25);
+		assert_ok!(CertificateModule::send_certificate(RuntimeOrigin::signed(1), 2, payload(), None));
+		assert_ok!(CertificateModule::send_certificate(RuntimeOrigin::signed(1), 3, payload(), None));
+		assert_noop!(
+			CertificateModule::send_certificate(RuntimeOrigin::signed(1), 4, payload(), None),
+			Error::<Test>::InsufficientBalance
+		);
+	});
+}
+
+#[test]
+fn test_migration_v2_counts_certificates_and_resizes_locks() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let _ = Balances::deposit_creating(&1, 100);
+		StorageVersion::new(1).put::<CertificateModule>();
+		let certificate = Certificate { payload: payload(), issued_at: 1, expires_at: None };
+		CertificateList::<Test>::insert(1, 2, certificate.clone());
+		CertificateList::<Test>::insert(1, 3, certificate);
+
+		crate::migrations::v2::MigrateV1ToV2::<Test>::on_runtime_upgrade();
+
+		assert_eq!(CertificateModule::certificate_count(1), 2);
+		assert_eq!(locked(1), 20);
+		assert_eq!(CertificateModule::on_chain_storage_version(), StorageVersion::new(2));
+	});
+}
+
+#[test]
+fn test_revoke_certificate_keeps_revocation_record() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(2);
+		let _ = Balances::deposit_creating(&1, 100);
+		issue(1, 2, None);
+
+		System::set_block_number(5);
+		assert_ok!(CertificateModule::revoke_certificate(
+			RuntimeOrigin::signed(1),
+			2,
+			RevocationReason::IssuedInError
+		));
+		assert!(!CertificateList::<Test>::contains_key(1, 2));
+		assert_eq!(
+			RevokedCertificates::<Test>::get(1, 2),
+			Some(Revocation {
+				reason: RevocationReason::IssuedInError,
+				payload: payload(),
+				issued_at: 2,
+				revoked_at: 5,
+			})
+		);
+	});
+}
+
+#[test]
+fn test_resend_certificate_after_revoke() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let _ = Balances::deposit_creating(&1, 100);
+		issue(1, 2, None);
+		assert_ok!(CertificateModule::revoke_certificate(
+			RuntimeOrigin::signed(1),
+			2,
+			RevocationReason::Superseded
+		));
+		issue(1, 2, None);
+		System::assert_last_event(crate::Event::CertificateAccepted { sender: 1, recipient: 2 }.into());
+		assert!(CertificateModule::is_certificate_valid(&1, &2, 1));
+		assert_eq!(CertificateModule::certificate_count(1), 1);
+		// The earlier revocation stays on record.
+		assert!(RevokedCertificates::<Test>::contains_key(1, 2));
+	});
+}
+
+#[test]
+fn test_accept_certificate() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let _ = Balances::deposit_creating(&1, 100);
+		assert_noop!(
+			CertificateModule::accept_certificate(RuntimeOrigin::signed(2), 1),
+			Error::<Test>::NoPendingCertificate
+		);
+		assert_ok!(CertificateModule::send_certificate(RuntimeOrigin::signed(1), 2, payload(), None));
+		// Only the recipient can accept.
+		assert_noop!(
+			CertificateModule::accept_certificate(RuntimeOrigin::signed(3), 1),
+			Error::<Test>::NoPendingCertificate
+		);
+		assert_ok!(CertificateModule::accept_certificate(RuntimeOrigin::signed(2), 1));
+		System::assert_last_event(crate::Event::CertificateAccepted { sender: 1, recipient: 2 }.into());
+		assert!(!PendingCertificates::<Test>::contains_key(1, 2));
+		assert!(CertificateModule::is_certificate_valid(&1, &2, 1));
+		assert_eq!(locked(1), 10);
+	});
+}
+
+#[test]
+fn test_reject_certificate_releases_the_lock() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let _ = Balances::deposit_creating(&1, 100);
+		assert_ok!(CertificateModule::send_certificate(RuntimeOrigin::signed(1), 2, payload(), None));
+		assert_eq!(locked(1), 10);
+
+		assert_ok!(CertificateModule::reject_certificate(RuntimeOrigin::signed(2), 1));
+		System::assert_last_event(crate::Event::CertificateRejected { sender: 1, recipient: 2 }.into());
+		System::assert_has_event(crate::Event::CertificateUnlock { account: 1, amount: 10 }.into());
+		assert!(!PendingCertificates::<Test>::contains_key(1, 2));
+		assert!(!CertificateList::<Test>::contains_key(1, 2));
+		assert_eq!(locked(1), 0);
+		assert_noop!(
+			CertificateModule::reject_certificate(RuntimeOrigin::signed(2), 1),
+			Error::<Test>::NoPendingCertificate
+		);
+
+		// The sender may try again.
+		assert_ok!(CertificateModule::send_certificate(RuntimeOrigin::signed(1), 2, payload(), None));
+	});
+}
+
+#[test]
+fn test_pending_certificate_times_out() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let _ = Balances::deposit_creating(&1, 100);
+		assert_ok!(CertificateModule::send_certificate(RuntimeOrigin::signed(1), 2, payload(), None));
+		issue(1, 3, None);
+		assert_eq!(locked(1), 20);
+
+		System::set_block_number(21);
+		assert_noop!(
+			CertificateModule::accept_certificate(RuntimeOrigin::signed(2), 1),
+			Error::<Test>::AcceptanceTimedOut
+		);
+		CertificateModule::on_idle(21, Weight::MAX);
+		System::assert_has_event(crate::Event::CertificateTimedOut { sender: 1, recipient: 2 }.into());
+		assert!(!PendingCertificates::<Test>::contains_key(1, 2));
+		// The accepted certificate is unaffected.
+		assert!(CertificateList::<Test>::contains_key(1, 3));
+		assert_eq!(CertificateModule::certificate_count(1), 1);
+		assert_eq!(locked(1), 10);
+	});
+}
+
+#[test]
+fn test_pending_certificate_expires_before_acceptance() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let _ = Balances::deposit_creating(&1, 100);
+		assert_ok!(CertificateModule::send_certificate(RuntimeOrigin::signed(1), 2, payload(), Some(5)));
+
+		System::set_block_number(5);
+		assert_noop!(
+			CertificateModule::accept_certificate(RuntimeOrigin::signed(2), 1),
+			Error::<Test>::InvalidExpiry
+		);
+		CertificateModule::on_idle(5, Weight::MAX);
+		System::assert_has_event(crate::Event::CertificateExpired { sender: 1, recipient: 2 }.into());
+		assert!(!PendingCertificates::<Test>::contains_key(1, 2));
+		assert_eq!(locked(1), 0);
+	});
+}
+
+#[test]
+fn test_revoke_pending_certificate() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let _ = Balances::deposit_creating(&1, 100);
+		assert_ok!(CertificateModule::send_certificate(RuntimeOrigin::signed(1), 2, payload(), None));
+		assert_ok!(CertificateModule::revoke_certificate(RuntimeOrigin::signed(1), 2, RevocationReason::IssuedInError));
+		assert!(!PendingCertificates::<Test>::contains_key(1, 2));
+		assert!(RevokedCertificates::<Test>::contains_key(1, 2));
+		assert_eq!(locked(1), 0);
+		assert_noop!(
+			CertificateModule::accept_certificate(RuntimeOrigin::signed(2), 1),
+			Error::<Test>::NoPendingCertificate
+		);
+	});
+}
+
+#[test]
+fn test_certificates_held_index() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let _ = Balances::deposit_creating(&1, 100);
+		let _ = Balances::deposit_creating(&2, 100);
+		issue(1, 3, None);
+		issue(2, 3, Some(5));
+		issue(1, 4, None);
+		// Pending certificates are not held yet.
+		System::set_block_number(2);
+		assert_ok!(CertificateModule::send_certificate(RuntimeOrigin::signed(2), 4, payload(), None));
+
+		let certificate = |expires_at| Certificate { payload: payload().into_inner(), issued_at: 1, expires_at };
+		let mut held = CertificateModule::certificates_held_by(&3);
+		held.sort_by_key(|(sender, _)| *sender);
+		assert_eq!(held, vec![(1, certificate(None)), (2, certificate(Some(5)))]);
+		assert_eq!(CertificateModule::certificates_held_by(&4), vec![(1, certificate(None))]);
+		let mut issued = CertificateModule::certificates_issued_by(&1);
+		issued.sort_by_key(|(recipient, _)| *recipient);
+		assert_eq!(issued, vec![(3, certificate(None)), (4, certificate(None))]);
+
+		assert_ok!(CertificateModule::revoke_certificate(RuntimeOrigin::signed(1), 3, RevocationReason::Withdrawn));
+		assert!(!CertificatesHeld::<Test>::contains_key(3, 1));
+		System::set_block_number(5);
+		CertificateModule::on_idle(5, Weight::MAX);
+		assert!(CertificateModule::certificates_held_by(&3).is_empty());
+	});
+}
+
+#[test]
+fn test_expired_certificates_are_not_listed_before_the_sweep() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let _ = Balances::deposit_creating(&1, 100);
+		issue(1, 3, Some(5));
+		issue(1, 4, None);
+
+		System::set_block_number(4);
+		assert_eq!(CertificateModule::certificates_held_by(&3).len(), 1);
+		assert_eq!(CertificateModule::certificates_issued_by(&1).len(), 2);
+
+		// Expired but not swept yet: still stored, no longer listed.
+		System::set_block_number(5);
+		assert!(CertificateList::<Test>::contains_key(1, 3));
+		assert!(CertificateModule::certificates_held_by(&3).is_empty());
+		let certificate = Certificate { payload: payload().into_inner(), issued_at: 1, expires_at: None };
+		assert_eq!(CertificateModule::certificates_issued_by(&1), vec![(4, certificate)]);
+
+		CertificateModule::on_idle(5, Weight::MAX);
+		assert!(!CertificateList::<Test>::contains_key(1, 3));
+		assert!(CertificateModule::certificates_held_by(&3).is_empty());
+	});
+}
+
+#[test]
+fn test_migration_v3_indexes_held_certificates() {
+	new_test_ext().execute_with(|| {
+		StorageVersion::new(2).put::<CertificateModule>();
+		let certificate = Certificate { payload: payload(), issued_at: 1, expires_at: None };
+		CertificateList::<Test>::insert(1, 3, certificate.clone());
+		CertificateList::<Test>::insert(2, 3, certificate);
+
+		crate::migrations::v3::MigrateV2ToV3::<Test>::on_runtime_upgrade();
+
+		assert!(CertificatesHeld::<Test>::contains_key(3, 1));
+		assert!(CertificatesHeld::<Test>::contains_key(3, 2));
+		assert_eq!(CertificateModule::certificates_held_by(&3).len(), 2);
+		assert_eq!(CertificateModule::on_chain_storage_version(), StorageVersion::new(3));
 	});
 }
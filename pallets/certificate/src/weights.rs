@@ -1,27 +1,10 @@
 
-//! Autogenerated weights for `pallet_certificate`
+//! Estimated weights for `pallet_certificate`
 //!
-//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 47.0.0
-//! DATE: 2025-05-18, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
-//! WORST CASE MAP SIZE: `1000000`
-//! HOSTNAME: `DESKTOP-AH3IE72`, CPU: `AMD Ryzen 3 3100 4-Core Processor`
-//! WASM-EXECUTION: `Compiled`, CHAIN: `None`, DB CACHE: `1024`
-
-// Executed Command:
-// frame-omni-bencher
-// v1
-// benchmark
-// pallet
-// --runtime
-// /home/neurosx/WORKING_WORKSPACE/fennel-solonet-2503UPGRADE/target/release/wbuild/fennel-node-runtime/fennel_node_runtime.wasm
-// --pallet
-// pallet_certificate
-// --extrinsic
-// 
-// --template
-// /home/neurosx/WORKING_WORKSPACE/fennel-solonet-2503UPGRADE/scripts/benchmarking/frame-weight-template.hbs
-// --output
-// /home/neurosx/WORKING_WORKSPACE/fennel-solonet-2503UPGRADE/benchmarks_output/pallet_certificate_weights.rs
+//! These figures are NOT benchmark output. They start from the benchmark run of 2025-05-18
+//! and were adjusted by hand for the storage reads and writes of the calls added or
+//! changed since. The per-call execution times are estimates. Regenerate this file with
+//! `./scripts/benchmarking/frame-weight-template.hbs` before relying on these weights.
 
 #![cfg_attr(rustfmt, rustfmt_skip)]
 #![allow(unused_parens)]
@@ -37,133 +20,390 @@ pub trait WeightInfo {
 	fn revoke_certificate() -> Weight;
 	fn send_certificate_heavy_storage() -> Weight;
 	fn revoke_certificate_heavy_storage() -> Weight;
+	fn expire_certificate() -> Weight;
+	fn accept_certificate() -> Weight;
+	fn reject_certificate() -> Weight;
 }
 
 /// Weights for `pallet_certificate` using the Substrate node and recommended hardware.
 pub struct SubstrateWeight<T>(PhantomData<T>);
 impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
-	/// Storage: `Certificate::CertificateList` (r:1 w:1)
-	/// Proof: `Certificate::CertificateList` (`max_values`: None, `max_size`: Some(97), added: 2572, mode: `MaxEncodedLen`)
+	/// Storage: `Certificate::CertificateList` (r:1 w:0)
+	/// Proof: `Certificate::CertificateList` (`max_values`: None, `max_size`: Some(363), added: 2838, mode: `MaxEncodedLen`)
+	/// Storage: `Certificate::PendingCertificates` (r:1 w:1)
+	/// Proof: `Certificate::PendingCertificates` (`max_values`: None, `max_size`: Some(367), added: 2842, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Locks` (r:1 w:1)
 	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Freezes` (r:1 w:0)
 	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `Certificate::CertificateExpiries` (r:16 w:1)
+	/// Proof: `Certificate::CertificateExpiries` (`max_values`: None, `max_size`: Some(4110), added: 6585, mode: `MaxEncodedLen`)
+	/// Storage: `Certificate::ExpiryCursor` (r:1 w:1)
+	/// Proof: `Certificate::ExpiryCursor` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Certificate::CertificateCount` (r:1 w:1)
+	/// Proof: `Certificate::CertificateCount` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `Certificate::ScheduledExpiries` (r:0 w:1)
+	/// Proof: `Certificate::ScheduledExpiries` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+	/// Storage: `Certificate::PendingExpiries` (r:1 w:1)
+	/// Proof: `Certificate::PendingExpiries` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
 	fn send_certificate() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `6`
-		//  Estimated: `4764`
+		//  Estimated: `106350`
 		// Minimum execution time: 45_432_000 picoseconds.
-		Weight::from_parts(49_661_000, 4764)
-			.saturating_add(T::DbWeight::get().reads(3_u64))
-			.saturating_add(T::DbWeight::get().writes(2_u64))
+		Weight::from_parts(49_661_000, 106350)
+			.saturating_add(T::DbWeight::get().reads(23_u64))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
 	}
 	/// Storage: `Certificate::CertificateList` (r:1 w:1)
-	/// Proof: `Certificate::CertificateList` (`max_values`: None, `max_size`: Some(97), added: 2572, mode: `MaxEncodedLen`)
+	/// Proof: `Certificate::CertificateList` (`max_values`: None, `max_size`: Some(363), added: 2838, mode: `MaxEncodedLen`)
+	/// Storage: `Certificate::PendingCertificates` (r:1 w:1)
+	/// Proof: `Certificate::PendingCertificates` (`max_values`: None, `max_size`: Some(367), added: 2842, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Locks` (r:1 w:1)
 	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Freezes` (r:1 w:0)
 	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `Certificate::CertificateCount` (r:1 w:1)
+	/// Proof: `Certificate::CertificateCount` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `Certificate::RevokedCertificates` (r:0 w:1)
+	/// Proof: `Certificate::RevokedCertificates` (`max_values`: None, `max_size`: Some(367), added: 2842, mode: `MaxEncodedLen`)
+	/// Storage: `Certificate::CertificatesHeld` (r:0 w:1)
+	/// Proof: `Certificate::CertificatesHeld` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
+	/// Storage: `Certificate::ScheduledExpiries` (r:1 w:1)
+	/// Proof: `Certificate::ScheduledExpiries` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+	/// Storage: `Certificate::CertificateExpiries` (r:1 w:1)
+	/// Proof: `Certificate::CertificateExpiries` (`max_values`: None, `max_size`: Some(4110), added: 6585, mode: `MaxEncodedLen`)
+	/// Storage: `Certificate::PendingExpiries` (r:1 w:1)
+	/// Proof: `Certificate::PendingExpiries` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Certificate::ExpiryCursor` (r:0 w:1)
+	/// Proof: `Certificate::ExpiryCursor` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
 	fn revoke_certificate() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `190`
-		//  Estimated: `4764`
+		//  Estimated: `7575`
 		// Minimum execution time: 46_962_000 picoseconds.
-		Weight::from_parts(51_129_000, 4764)
-			.saturating_add(T::DbWeight::get().reads(3_u64))
-			.saturating_add(T::DbWeight::get().writes(2_u64))
+		Weight::from_parts(51_129_000, 7575)
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().writes(9_u64))
 	}
-	/// Storage: `Certificate::CertificateList` (r:1 w:1)
-	/// Proof: `Certificate::CertificateList` (`max_values`: None, `max_size`: Some(97), added: 2572, mode: `MaxEncodedLen`)
+	/// Storage: `Certificate::CertificateList` (r:1 w:0)
+	/// Proof: `Certificate::CertificateList` (`max_values`: None, `max_size`: Some(363), added: 2838, mode: `MaxEncodedLen`)
+	/// Storage: `Certificate::PendingCertificates` (r:1 w:1)
+	/// Proof: `Certificate::PendingCertificates` (`max_values`: None, `max_size`: Some(367), added: 2842, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Locks` (r:1 w:1)
 	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Freezes` (r:1 w:0)
 	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `Certificate::CertificateExpiries` (r:16 w:1)
+	/// Proof: `Certificate::CertificateExpiries` (`max_values`: None, `max_size`: Some(4110), added: 6585, mode: `MaxEncodedLen`)
+	/// Storage: `Certificate::ExpiryCursor` (r:1 w:1)
+	/// Proof: `Certificate::ExpiryCursor` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Certificate::CertificateCount` (r:1 w:1)
+	/// Proof: `Certificate::CertificateCount` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `Certificate::ScheduledExpiries` (r:0 w:1)
+	/// Proof: `Certificate::ScheduledExpiries` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+	/// Storage: `Certificate::PendingExpiries` (r:1 w:1)
+	/// Proof: `Certificate::PendingExpiries` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
 	fn send_certificate_heavy_storage() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `1712`
-		//  Estimated: `4764`
+		//  Estimated: `106350`
 		// Minimum execution time: 86_210_000 picoseconds.
-		Weight::from_parts(93_159_000, 4764)
-			.saturating_add(T::DbWeight::get().reads(3_u64))
-			.saturating_add(T::DbWeight::get().writes(2_u64))
+		Weight::from_parts(93_159_000, 106350)
+			.saturating_add(T::DbWeight::get().reads(23_u64))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
 	}
 	/// Storage: `Certificate::CertificateList` (r:1 w:1)
-	/// Proof: `Certificate::CertificateList` (`max_values`: None, `max_size`: Some(97), added: 2572, mode: `MaxEncodedLen`)
+	/// Proof: `Certificate::CertificateList` (`max_values`: None, `max_size`: Some(363), added: 2838, mode: `MaxEncodedLen`)
+	/// Storage: `Certificate::PendingCertificates` (r:1 w:1)
+	/// Proof: `Certificate::PendingCertificates` (`max_values`: None, `max_size`: Some(367), added: 2842, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Locks` (r:1 w:1)
 	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Freezes` (r:1 w:0)
 	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `Certificate::CertificateCount` (r:1 w:1)
+	/// Proof: `Certificate::CertificateCount` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `Certificate::RevokedCertificates` (r:0 w:1)
+	/// Proof: `Certificate::RevokedCertificates` (`max_values`: None, `max_size`: Some(367), added: 2842, mode: `MaxEncodedLen`)
+	/// Storage: `Certificate::CertificatesHeld` (r:0 w:1)
+	/// Proof: `Certificate::CertificatesHeld` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
+	/// Storage: `Certificate::ScheduledExpiries` (r:1 w:1)
+	/// Proof: `Certificate::ScheduledExpiries` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+	/// Storage: `Certificate::CertificateExpiries` (r:1 w:1)
+	/// Proof: `Certificate::CertificateExpiries` (`max_values`: None, `max_size`: Some(4110), added: 6585, mode: `MaxEncodedLen`)
+	/// Storage: `Certificate::PendingExpiries` (r:1 w:1)
+	/// Proof: `Certificate::PendingExpiries` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Certificate::ExpiryCursor` (r:0 w:1)
+	/// Proof: `Certificate::ExpiryCursor` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
 	fn revoke_certificate_heavy_storage() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `1729`
-		//  Estimated: `4764`
+		//  Estimated: `7575`
 		// Minimum execution time: 91_656_000 picoseconds.
-		Weight::from_parts(96_624_000, 4764)
-			.saturating_add(T::DbWeight::get().reads(3_u64))
-			.saturating_add(T::DbWeight::get().writes(2_u64))
+		Weight::from_parts(96_624_000, 7575)
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().writes(9_u64))
+	}
+	/// Storage: `Certificate::CertificateList` (r:1 w:1)
+	/// Proof: `Certificate::CertificateList` (`max_values`: None, `max_size`: Some(363), added: 2838, mode: `MaxEncodedLen`)
+	/// Storage: `Certificate::PendingCertificates` (r:1 w:1)
+	/// Proof: `Certificate::PendingCertificates` (`max_values`: None, `max_size`: Some(367), added: 2842, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Locks` (r:1 w:1)
+	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Freezes` (r:1 w:0)
+	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `Certificate::CertificateCount` (r:1 w:1)
+	/// Proof: `Certificate::CertificateCount` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `Certificate::CertificatesHeld` (r:0 w:1)
+	/// Proof: `Certificate::CertificatesHeld` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
+	/// Storage: `Certificate::ScheduledExpiries` (r:1 w:1)
+	/// Proof: `Certificate::ScheduledExpiries` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+	fn expire_certificate() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `224`
+		//  Estimated: `4764`
+		// Minimum execution time: 43_118_000 picoseconds.
+		Weight::from_parts(45_870_000, 4764)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	/// Storage: `Certificate::PendingCertificates` (r:1 w:1)
+	/// Proof: `Certificate::PendingCertificates` (`max_values`: None, `max_size`: Some(367), added: 2842, mode: `MaxEncodedLen`)
+	/// Storage: `Certificate::CertificateList` (r:0 w:1)
+	/// Proof: `Certificate::CertificateList` (`max_values`: None, `max_size`: Some(363), added: 2838, mode: `MaxEncodedLen`)
+	/// Storage: `Certificate::CertificatesHeld` (r:0 w:1)
+	/// Proof: `Certificate::CertificatesHeld` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
+	/// Storage: `Certificate::ScheduledExpiries` (r:1 w:1)
+	/// Proof: `Certificate::ScheduledExpiries` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+	/// Storage: `Certificate::CertificateExpiries` (r:17 w:2)
+	/// Proof: `Certificate::CertificateExpiries` (`max_values`: None, `max_size`: Some(4110), added: 6585, mode: `MaxEncodedLen`)
+	/// Storage: `Certificate::ExpiryCursor` (r:1 w:1)
+	/// Proof: `Certificate::ExpiryCursor` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Certificate::PendingExpiries` (r:1 w:1)
+	/// Proof: `Certificate::PendingExpiries` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	fn accept_certificate() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `232`
+		//  Estimated: `112935`
+		// Minimum execution time: 21_370_000 picoseconds.
+		Weight::from_parts(22_804_000, 112935)
+			.saturating_add(T::DbWeight::get().reads(21_u64))
+			.saturating_add(T::DbWeight::get().writes(8_u64))
+	}
+	/// Storage: `Certificate::PendingCertificates` (r:1 w:1)
+	/// Proof: `Certificate::PendingCertificates` (`max_values`: None, `max_size`: Some(367), added: 2842, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Locks` (r:1 w:1)
+	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Freezes` (r:1 w:0)
+	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `Certificate::CertificateCount` (r:1 w:1)
+	/// Proof: `Certificate::CertificateCount` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `Certificate::ScheduledExpiries` (r:1 w:1)
+	/// Proof: `Certificate::ScheduledExpiries` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+	/// Storage: `Certificate::CertificateExpiries` (r:1 w:1)
+	/// Proof: `Certificate::CertificateExpiries` (`max_values`: None, `max_size`: Some(4110), added: 6585, mode: `MaxEncodedLen`)
+	/// Storage: `Certificate::PendingExpiries` (r:1 w:1)
+	/// Proof: `Certificate::PendingExpiries` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Certificate::ExpiryCursor` (r:0 w:1)
+	/// Proof: `Certificate::ExpiryCursor` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	fn reject_certificate() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `266`
+		//  Estimated: `7575`
+		// Minimum execution time: 42_551_000 picoseconds.
+		Weight::from_parts(45_017_000, 7575)
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
 	}
 }
 
 // For backwards compatibility and tests.
 impl WeightInfo for () {
-	/// Storage: `Certificate::CertificateList` (r:1 w:1)
-	/// Proof: `Certificate::CertificateList` (`max_values`: None, `max_size`: Some(97), added: 2572, mode: `MaxEncodedLen`)
+	/// Storage: `Certificate::CertificateList` (r:1 w:0)
+	/// Proof: `Certificate::CertificateList` (`max_values`: None, `max_size`: Some(363), added: 2838, mode: `MaxEncodedLen`)
+	/// Storage: `Certificate::PendingCertificates` (r:1 w:1)
+	/// Proof: `Certificate::PendingCertificates` (`max_values`: None, `max_size`: Some(367), added: 2842, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Locks` (r:1 w:1)
 	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Freezes` (r:1 w:0)
 	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `Certificate::CertificateExpiries` (r:16 w:1)
+	/// Proof: `Certificate::CertificateExpiries` (`max_values`: None, `max_size`: Some(4110), added: 6585, mode: `MaxEncodedLen`)
+	/// Storage: `Certificate::ExpiryCursor` (r:1 w:1)
+	/// Proof: `Certificate::ExpiryCursor` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Certificate::CertificateCount` (r:1 w:1)
+	/// Proof: `Certificate::CertificateCount` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `Certificate::ScheduledExpiries` (r:0 w:1)
+	/// Proof: `Certificate::ScheduledExpiries` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+	/// Storage: `Certificate::PendingExpiries` (r:1 w:1)
+	/// Proof: `Certificate::PendingExpiries` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
 	fn send_certificate() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `6`
-		//  Estimated: `4764`
+		//  Estimated: `106350`
 		// Minimum execution time: 45_432_000 picoseconds.
-		Weight::from_parts(49_661_000, 4764)
-			.saturating_add(RocksDbWeight::get().reads(3_u64))
-			.saturating_add(RocksDbWeight::get().writes(2_u64))
+		Weight::from_parts(49_661_000, 106350)
+			.saturating_add(RocksDbWeight::get().reads(23_u64))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
 	}
 	/// Storage: `Certificate::CertificateList` (r:1 w:1)
-	/// Proof: `Certificate::CertificateList` (`max_values`: None, `max_size`: Some(97), added: 2572, mode: `MaxEncodedLen`)
+	/// Proof: `Certificate::CertificateList` (`max_values`: None, `max_size`: Some(363), added: 2838, mode: `MaxEncodedLen`)
+	/// Storage: `Certificate::PendingCertificates` (r:1 w:1)
+	/// Proof: `Certificate::PendingCertificates` (`max_values`: None, `max_size`: Some(367), added: 2842, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Locks` (r:1 w:1)
 	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Freezes` (r:1 w:0)
 	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `Certificate::CertificateCount` (r:1 w:1)
+	/// Proof: `Certificate::CertificateCount` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `Certificate::RevokedCertificates` (r:0 w:1)
+	/// Proof: `Certificate::RevokedCertificates` (`max_values`: None, `max_size`: Some(367), added: 2842, mode: `MaxEncodedLen`)
+	/// Storage: `Certificate::CertificatesHeld` (r:0 w:1)
+	/// Proof: `Certificate::CertificatesHeld` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
+	/// Storage: `Certificate::ScheduledExpiries` (r:1 w:1)
+	/// Proof: `Certificate::ScheduledExpiries` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+	/// Storage: `Certificate::CertificateExpiries` (r:1 w:1)
+	/// Proof: `Certificate::CertificateExpiries` (`max_values`: None, `max_size`: Some(4110), added: 6585, mode: `MaxEncodedLen`)
+	/// Storage: `Certificate::PendingExpiries` (r:1 w:1)
+	/// Proof: `Certificate::PendingExpiries` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Certificate::ExpiryCursor` (r:0 w:1)
+	/// Proof: `Certificate::ExpiryCursor` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
 	fn revoke_certificate() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `190`
-		//  Estimated: `4764`
+		//  Estimated: `7575`
 		// Minimum execution time: 46_962_000 picoseconds.
-		Weight::from_parts(51_129_000, 4764)
-			.saturating_add(RocksDbWeight::get().reads(3_u64))
-			.saturating_add(RocksDbWeight::get().writes(2_u64))
+		Weight::from_parts(51_129_000, 7575)
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().writes(9_u64))
 	}
-	/// Storage: `Certificate::CertificateList` (r:1 w:1)
-	/// Proof: `Certificate::CertificateList` (`max_values`: None, `max_size`: Some(97), added: 2572, mode: `MaxEncodedLen`)
+	/// Storage: `Certificate::CertificateList` (r:1 w:0)
+	/// Proof: `Certificate::CertificateList` (`max_values`: None, `max_size`: Some(363), added: 2838, mode: `MaxEncodedLen`)
+	/// Storage: `Certificate::PendingCertificates` (r:1 w:1)
+	/// Proof: `Certificate::PendingCertificates` (`max_values`: None, `max_size`: Some(367), added: 2842, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Locks` (r:1 w:1)
 	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Freezes` (r:1 w:0)
 	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `Certificate::CertificateExpiries` (r:16 w:1)
+	/// Proof: `Certificate::CertificateExpiries` (`max_values`: None, `max_size`: Some(4110), added: 6585, mode: `MaxEncodedLen`)
+	/// Storage: `Certificate::ExpiryCursor` (r:1 w:1)
+	/// Proof: `Certificate::ExpiryCursor` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Certificate::CertificateCount` (r:1 w:1)
+	/// Proof: `Certificate::CertificateCount` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `Certificate::ScheduledExpiries` (r:0 w:1)
+	/// Proof: `Certificate::ScheduledExpiries` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+	/// Storage: `Certificate::PendingExpiries` (r:1 w:1)
+	/// Proof: `Certificate::PendingExpiries` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
 	fn send_certificate_heavy_storage() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `1712`
-		//  Estimated: `4764`
+		//  Estimated: `106350`
 		// Minimum execution time: 86_210_000 picoseconds.
-		Weight::from_parts(93_159_000, 4764)
-			.saturating_add(RocksDbWeight::get().reads(3_u64))
-			.saturating_add(RocksDbWeight::get().writes(2_u64))
+		Weight::from_parts(93_159_000, 106350)
+			.saturating_add(RocksDbWeight::get().reads(23_u64))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
 	}
 	/// Storage: `Certificate::CertificateList` (r:1 w:1)
-	/// Proof: `Certificate::CertificateList` (`max_values`: None, `max_size`: Some(97), added: 2572, mode: `MaxEncodedLen`)
+	/// Proof: `Certificate::CertificateList` (`max_values`: None, `max_size`: Some(363), added: 2838, mode: `MaxEncodedLen`)
+	/// Storage: `Certificate::PendingCertificates` (r:1 w:1)
+	/// Proof: `Certificate::PendingCertificates` (`max_values`: None, `max_size`: Some(367), added: 2842, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Locks` (r:1 w:1)
 	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Freezes` (r:1 w:0)
 	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `Certificate::CertificateCount` (r:1 w:1)
+	/// Proof: `Certificate::CertificateCount` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `Certificate::RevokedCertificates` (r:0 w:1)
+	/// Proof: `Certificate::RevokedCertificates` (`max_values`: None, `max_size`: Some(367), added: 2842, mode: `MaxEncodedLen`)
+	/// Storage: `Certificate::CertificatesHeld` (r:0 w:1)
+	/// Proof: `Certificate::CertificatesHeld` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
+	/// Storage: `Certificate::ScheduledExpiries` (r:1 w:1)
+	/// Proof: `Certificate::ScheduledExpiries` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+	/// Storage: `Certificate::CertificateExpiries` (r:1 w:1)
+	/// Proof: `Certificate::CertificateExpiries` (`max_values`: None, `max_size`: Some(4110), added: 6585, mode: `MaxEncodedLen`)
+	/// Storage: `Certificate::PendingExpiries` (r:1 w:1)
+	/// Proof: `Certificate::PendingExpiries` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Certificate::ExpiryCursor` (r:0 w:1)
+	/// Proof: `Certificate::ExpiryCursor` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
 	fn revoke_certificate_heavy_storage() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `1729`
-		//  Estimated: `4764`
+		//  Estimated: `7575`
 		// Minimum execution time: 91_656_000 picoseconds.
-		Weight::from_parts(96_624_000, 4764)
-			.saturating_add(RocksDbWeight::get().reads(3_u64))
-			.saturating_add(RocksDbWeight::get().writes(2_u64))
+		Weight::from_parts(96_624_000, 7575)
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().writes(9_u64))
+	}
+	/// Storage: `Certificate::CertificateList` (r:1 w:1)
+	/// Proof: `Certificate::CertificateList` (`max_values`: None, `max_size`: Some(363), added: 2838, mode: `MaxEncodedLen`)
+	/// Storage: `Certificate::PendingCertificates` (r:1 w:1)
+	/// Proof: `Certificate::PendingCertificates` (`max_values`: None, `max_size`: Some(367), added: 2842, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Locks` (r:1 w:1)
+	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Freezes` (r:1 w:0)
+	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `Certificate::CertificateCount` (r:1 w:1)
+	/// Proof: `Certificate::CertificateCount` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `Certificate::CertificatesHeld` (r:0 w:1)
+	/// Proof: `Certificate::CertificatesHeld` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
+	/// Storage: `Certificate::ScheduledExpiries` (r:1 w:1)
+	/// Proof: `Certificate::ScheduledExpiries` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+	fn expire_certificate() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `224`
+		//  Estimated: `4764`
+		// Minimum execution time: 43_118_000 picoseconds.
+		Weight::from_parts(45_870_000, 4764)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// Storage: `Certificate::PendingCertificates` (r:1 w:1)
+	/// Proof: `Certificate::PendingCertificates` (`max_values`: None, `max_size`: Some(367), added: 2842, mode: `MaxEncodedLen`)
+	/// Storage: `Certificate::CertificateList` (r:0 w:1)
+	/// Proof: `Certificate::CertificateList` (`max_values`: None, `max_size`: Some(363), added: 2838, mode: `MaxEncodedLen`)
+	/// Storage: `Certificate::CertificatesHeld` (r:0 w:1)
+	/// Proof: `Certificate::CertificatesHeld` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
+	/// Storage: `Certificate::ScheduledExpiries` (r:1 w:1)
+	/// Proof: `Certificate::ScheduledExpiries` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+	/// Storage: `Certificate::CertificateExpiries` (r:17 w:2)
+	/// Proof: `Certificate::CertificateExpiries` (`max_values`: None, `max_size`: Some(4110), added: 6585, mode: `MaxEncodedLen`)
+	/// Storage: `Certificate::ExpiryCursor` (r:1 w:1)
+	/// Proof: `Certificate::ExpiryCursor` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Certificate::PendingExpiries` (r:1 w:1)
+	/// Proof: `Certificate::PendingExpiries` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	fn accept_certificate() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `232`
+		//  Estimated: `112935`
+		// Minimum execution time: 21_370_000 picoseconds.
+		Weight::from_parts(22_804_000, 112935)
+			.saturating_add(RocksDbWeight::get().reads(21_u64))
+			.saturating_add(RocksDbWeight::get().writes(8_u64))
+	}
+	/// Storage: `Certificate::PendingCertificates` (r:1 w:1)
+	/// Proof: `Certificate::PendingCertificates` (`max_values`: None, `max_size`: Some(367), added: 2842, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Locks` (r:1 w:1)
+	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Freezes` (r:1 w:0)
+	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `Certificate::CertificateCount` (r:1 w:1)
+	/// Proof: `Certificate::CertificateCount` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `Certificate::ScheduledExpiries` (r:1 w:1)
+	/// Proof: `Certificate::ScheduledExpiries` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+	/// Storage: `Certificate::CertificateExpiries` (r:1 w:1)
+	/// Proof: `Certificate::CertificateExpiries` (`max_values`: None, `max_size`: Some(4110), added: 6585, mode: `MaxEncodedLen`)
+	/// Storage: `Certificate::PendingExpiries` (r:1 w:1)
+	/// Proof: `Certificate::PendingExpiries` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Certificate::ExpiryCursor` (r:0 w:1)
+	/// Proof: `Certificate::ExpiryCursor` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	fn reject_certificate() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `266`
+		//  Estimated: `7575`
+		// Minimum execution time: 42_551_000 picoseconds.
+		Weight::from_parts(45_017_000, 7575)
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
 	}
 }
@@ -0,0 +1,77 @@
+//! RPC interface for the Fennel identity pallet.
+
+use std::sync::Arc;
+
+use codec::Codec;
+use jsonrpsee::{
+    core::RpcResult,
+    proc_macros::rpc,
+    types::{error::ErrorObject, ErrorObjectOwned},
+};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_core::Bytes;
+use sp_runtime::traits::Block as BlockT;
+
+pub use pallet_identity_runtime_api::IdentityApi as IdentityRuntimeApi;
+
+/// Identity RPC methods.
+#[rpc(client, server)]
+pub trait IdentityApi<BlockHash, AccountId> {
+    /// Returns the owner of an identity, or `None` if it does not exist or has been revoked.
+    #[method(name = "identity_getIdentity")]
+    fn identity(&self, identity_id: u32, at: Option<BlockHash>) -> RpcResult<Option<AccountId>>;
+
+    /// Returns the key/value traits stored against an identity.
+    #[method(name = "identity_traits")]
+    fn identity_traits(&self, identity_id: u32, at: Option<BlockHash>) -> RpcResult<Vec<(Bytes, Bytes)>>;
+
+    /// Returns the IDs of the identities currently owned by an account.
+    #[method(name = "identity_ownedBy")]
+    fn identities_owned_by(&self, owner: AccountId, at: Option<BlockHash>) -> RpcResult<Vec<u32>>;
+}
+
+/// Provides RPC methods to query identities.
+pub struct Identity<C, B> {
+    /// Shared reference to the client.
+    client: Arc<C>,
+    _marker: std::marker::PhantomData<B>,
+}
+
+impl<C, B> Identity<C, B> {
+    /// Creates a new instance of the Identity RPC helper.
+    pub fn new(client: Arc<C>) -> Self {
+        Self { client, _marker: Default::default() }
+    }
+}
+
+/// Error code returned when the runtime API call fails.
+const RUNTIME_ERROR: i32 = 1;
+
+fn runtime_error(error: impl ToString) -> ErrorObjectOwned {
+    ErrorObject::owned(RUNTIME_ERROR, "Unable to query identity.", Some(error.to_string()))
+}
+
+impl<C, Block, AccountId> IdentityApiServer<<Block as BlockT>::Hash, AccountId> for Identity<C, Block>
+where
+    Block: BlockT,
+    C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+    C::Api: IdentityRuntimeApi<Block, AccountId>,
+    AccountId: Codec + Send + Sync + 'static,
+{
+    fn identity(&self, identity_id: u32, at: Option<Block::Hash>) -> RpcResult<Option<AccountId>> {
+        let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+        self.client.runtime_api().identity(at_hash, identity_id).map_err(runtime_error)
+    }
+
+    fn identity_traits(&self, identity_id: u32, at: Option<Block::Hash>) -> RpcResult<Vec<(Bytes, Bytes)>> {
+        let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+        let traits = self.client.runtime_api().identity_traits(at_hash, identity_id).map_err(runtime_error)?;
+        Ok(traits.into_iter().map(|(key, value)| (key.into(), value.into())).collect())
+    }
+
+    fn identities_owned_by(&self, owner: AccountId, at: Option<Block::Hash>) -> RpcResult<Vec<u32>> {
+        let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+        self.client.runtime_api().identities_owned_by(at_hash, owner).map_err(runtime_error)
+    }
+}
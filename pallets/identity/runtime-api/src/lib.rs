@@ -0,0 +1,24 @@
+//! Runtime API definition for the Fennel identity pallet.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+use codec::Codec;
+
+sp_api::decl_runtime_apis! {
+    /// Read access to identities without building raw storage keys.
+    pub trait IdentityApi<AccountId>
+    where
+        AccountId: Codec,
+    {
+        /// Returns the owner of the identity with ID `identity_id`, or `None` if it does not
+        /// exist or has been revoked.
+        fn identity(identity_id: u32) -> Option<AccountId>;
+        /// Returns the key/value traits stored against the identity with ID `identity_id`.
+        fn identity_traits(identity_id: u32) -> Vec<(Vec<u8>, Vec<u8>)>;
+        /// Returns the IDs of the identities currently owned by `owner`.
+        fn identities_owned_by(owner: AccountId) -> Vec<u32>;
+    }
+}
@@ -2,9 +2,82 @@
 
 #![cfg(feature = "runtime-benchmarks")]
 
-use crate::{Config};
+use crate::{BalanceOf, Config, IdentityTraitList, Judgement, Pallet};
+use frame_support::traits::{fungible::{Inspect, Mutate}, EnsureOrigin, Get};
 use frame_benchmarking::v2::*;
-use scale_info::prelude::vec;
+use scale_info::prelude::{vec, vec::Vec};
+use frame_support::BoundedVec;
+use frame_system::RawOrigin;
+use sp_runtime::traits::{Hash, Saturating};
+
+/// A trait key or value.
+type TraitBytes<T> = BoundedVec<u8, <T as Config>::MaxSize>;
+
+/// Gives `who` enough funds to cover the deposits of a full set of identities and the traits
+/// created by the heaviest benchmarks.
+fn fund_account<T: Config>(who: &T::AccountId) {
+    let identities: BalanceOf<T> = T::MaxIdentitiesPerOwner::get().saturating_add(1).into();
+    let bytes: BalanceOf<T> = 10_000_000u32.into();
+    let amount = T::Currency::minimum_balance()
+        .saturating_add(T::BaseDeposit::get().saturating_mul(identities))
+        .saturating_add(T::ByteDeposit::get().saturating_mul(bytes));
+    T::Currency::set_balance(who, amount);
+}
+
+/// Adds `count` distinct small traits to `identity_id`.
+fn add_traits<T: Config>(
+    who: &T::AccountId,
+    identity_id: u32,
+    count: u32,
+) -> Result<(), frame_benchmarking::BenchmarkError> {
+    for i in 0..count {
+        let key: BoundedVec<u8, T::MaxSize> = i.to_le_bytes().to_vec().try_into().unwrap();
+        let val: BoundedVec<u8, T::MaxSize> = vec![1u8; 32].try_into().unwrap();
+        Pallet::<T>::add_or_update_identity_trait(
+            RawOrigin::Signed(who.clone()).into(),
+            identity_id,
+            key,
+            val,
+        )?;
+    }
+    Ok(())
+}
+
+/// Makes `registrar` a registrar charging the base deposit as its fee, and funds it.
+fn setup_registrar<T: Config>(registrar: &T::AccountId) -> Result<(), frame_benchmarking::BenchmarkError> {
+    fund_account::<T>(registrar);
+    let origin = T::RegistrarOrigin::try_successful_origin()
+        .map_err(|_| frame_benchmarking::BenchmarkError::Weightless)?;
+    Pallet::<T>::add_registrar(origin, registrar.clone(), T::BaseDeposit::get())?;
+    Ok(())
+}
+
+/// Creates an identity for `who` with a single trait and requests its attestation from
+/// `registrar`, returning the identity ID, trait key and trait value.
+fn setup_attestation_request<T: Config>(
+    who: &T::AccountId,
+    registrar: &T::AccountId,
+) -> Result<(u32, TraitBytes<T>, TraitBytes<T>), frame_benchmarking::BenchmarkError> {
+    fund_account::<T>(who);
+    Pallet::<T>::create_identity(RawOrigin::Signed(who.clone()).into())?;
+    let identity_id = Pallet::<T>::identity_number().saturating_sub(1);
+    let key: BoundedVec<u8, T::MaxSize> = vec![1u8; <T::MaxSize as Get<u32>>::get() as usize].try_into().unwrap();
+    let value: BoundedVec<u8, T::MaxSize> = vec![2u8; <T::MaxSize as Get<u32>>::get() as usize].try_into().unwrap();
+    Pallet::<T>::add_or_update_identity_trait(
+        RawOrigin::Signed(who.clone()).into(),
+        identity_id,
+        key.clone(),
+        value.clone(),
+    )?;
+    Pallet::<T>::request_attestation(
+        RawOrigin::Signed(who.clone()).into(),
+        identity_id,
+        registrar.clone(),
+        key.clone(),
+        T::BaseDeposit::get(),
+    )?;
+    Ok((identity_id, key, value))
+}
 
 #[benchmarks(
     where
@@ -12,15 +85,18 @@ use scale_info::prelude::vec;
 )]
 mod benchmarks {
 	use super::*;
-    use crate::{Call, Pallet};
-    use frame_system::RawOrigin;
-    use frame_support::BoundedVec;
-    use frame_benchmarking::{BenchmarkError, whitelisted_caller};
+    use crate::Call;
+    use frame_benchmarking::{account, BenchmarkError, whitelisted_caller};
     use scale_info::prelude::format;
 
 	#[benchmark]
 	fn create_identity() -> Result<(), BenchmarkError> {
         let who: T::AccountId = whitelisted_caller();
+        fund_account::<T>(&who);
+        // Fill the owner index up to one below the cap for the worst case.
+        for _ in 1..T::MaxIdentitiesPerOwner::get() {
+            Pallet::<T>::create_identity(RawOrigin::Signed(who.clone()).into())?;
+        }
 		#[extrinsic_call]
         Pallet::<T>::create_identity(RawOrigin::Signed(who.clone()));
         let last = Pallet::<T>::identity_number().saturating_sub(1);
@@ -29,24 +105,30 @@ mod benchmarks {
 	}
 
 	#[benchmark]
-	fn revoke_identity() -> Result<(), BenchmarkError> {
+	fn revoke_identity(t: Linear<0, { T::MaxTraitRemovals::get() }>) -> Result<(), BenchmarkError> {
         let who: T::AccountId = whitelisted_caller();
-        Pallet::<T>::create_identity(RawOrigin::Signed(who.clone()).into())?;
+        fund_account::<T>(&who);
+        for _ in 0..T::MaxIdentitiesPerOwner::get() {
+            Pallet::<T>::create_identity(RawOrigin::Signed(who.clone()).into())?;
+        }
         let last = Pallet::<T>::identity_number().saturating_sub(1);
+        add_traits::<T>(&who, last, t)?;
 		#[extrinsic_call]
         Pallet::<T>::revoke_identity(RawOrigin::Signed(who.clone()), last);
         // Check that the identity no longer exists in the IdentityList.
         assert!(Pallet::<T>::identity_list(last).is_none());
+        assert!(!Pallet::<T>::owned_identities(&who).contains(&last));
 		Ok(())
 	}
 
 	#[benchmark]
-    fn add_or_update_identity_trait(l: Linear<1, 1024>) -> Result<(), BenchmarkError> {
+    fn add_or_update_identity_trait(l: Linear<1, 1024>, v: Linear<0, 1024>) -> Result<(), BenchmarkError> {
         let who: T::AccountId = whitelisted_caller();
+        fund_account::<T>(&who);
         Pallet::<T>::create_identity(RawOrigin::Signed(who.clone()).into())?;
         let last = Pallet::<T>::identity_number().saturating_sub(1);
         let key: BoundedVec<u8, T::MaxSize> = vec![0u8; l as usize].try_into().unwrap();
-        let val: BoundedVec<u8, T::MaxSize> = vec![1u8; l as usize].try_into().unwrap();
+        let val: BoundedVec<u8, T::MaxSize> = vec![1u8; v as usize].try_into().unwrap();
 		#[extrinsic_call]
         Pallet::<T>::add_or_update_identity_trait(RawOrigin::Signed(who.clone()), last, key.clone(), val.clone());
         // Check that the identity no longer exists in the IdentityList.
@@ -57,6 +139,7 @@ mod benchmarks {
 	#[benchmark]
     fn remove_identity_trait(l: Linear<1, 1024>) -> Result<(), BenchmarkError> {
         let who: T::AccountId = whitelisted_caller();
+        fund_account::<T>(&who);
         Pallet::<T>::create_identity(RawOrigin::Signed(who.clone()).into())?;
         let last = Pallet::<T>::identity_number().saturating_sub(1);
         let key: BoundedVec<u8, T::MaxSize> = vec![0u8; l as usize].try_into().unwrap();
@@ -77,15 +160,22 @@ mod benchmarks {
     #[benchmark]
     fn revoke_identity_heavy_storage() -> Result<(), BenchmarkError> {
         let who: T::AccountId = whitelisted_caller();
+        fund_account::<T>(&who);
         
-        // Create 1000 identities for heavy storage testing
-        for _ in 0..1000 {
+        // Create 1000 identities for heavy storage testing, spread across accounts so that no
+        // single owner exceeds `MaxIdentitiesPerOwner`.
+        for i in 0..1000 {
+            let owner: T::AccountId = account("owner", i, 0);
+            fund_account::<T>(&owner);
+            Pallet::<T>::create_identity(RawOrigin::Signed(owner).into())?;
+        }
+
+        // Fill the caller's owner index and revoke the first of its identities.
+        let identity_to_revoke = Pallet::<T>::identity_number();
+        for _ in 0..T::MaxIdentitiesPerOwner::get() {
             Pallet::<T>::create_identity(RawOrigin::Signed(who.clone()).into())?;
         }
         
-        // Get an identity ID to revoke - choose an index in the middle
-        let identity_to_revoke = 500;
-        
         #[extrinsic_call]
         Pallet::<T>::revoke_identity(RawOrigin::Signed(who.clone()), identity_to_revoke);
         
@@ -98,6 +188,7 @@ mod benchmarks {
     #[benchmark]
     fn add_or_update_long_identity_trait() -> Result<(), BenchmarkError> {
         let who: T::AccountId = whitelisted_caller();
+        fund_account::<T>(&who);
         Pallet::<T>::create_identity(RawOrigin::Signed(who.clone()).into())?;
         let identity_id = Pallet::<T>::identity_number().saturating_sub(1);
         
@@ -123,6 +214,7 @@ mod benchmarks {
     fn add_or_update_many_identity_traits() -> Result<(), BenchmarkError> {
         // Create first identity with many traits
         let first_user: T::AccountId = whitelisted_caller();
+        fund_account::<T>(&first_user);
         Pallet::<T>::create_identity(RawOrigin::Signed(first_user.clone()).into())?;
         let first_identity_id = Pallet::<T>::identity_number().saturating_sub(1);
         
@@ -143,6 +235,7 @@ mod benchmarks {
         
         // Create a second identity and test adding a trait with heavy storage
         let second_user: T::AccountId = whitelisted_caller();
+        fund_account::<T>(&second_user);
         Pallet::<T>::create_identity(RawOrigin::Signed(second_user.clone()).into())?;
         let second_identity_id = Pallet::<T>::identity_number().saturating_sub(1);
         
@@ -166,6 +259,7 @@ mod benchmarks {
     #[benchmark]
     fn remove_identity_trait_heavy_storage() -> Result<(), BenchmarkError> {
         let who: T::AccountId = whitelisted_caller();
+        fund_account::<T>(&who);
         Pallet::<T>::create_identity(RawOrigin::Signed(who.clone()).into())?;
         let identity_id = Pallet::<T>::identity_number().saturating_sub(1);
         
@@ -215,6 +309,7 @@ mod benchmarks {
     #[benchmark]
     fn remove_long_identity_trait() -> Result<(), BenchmarkError> {
         let who: T::AccountId = whitelisted_caller();
+        fund_account::<T>(&who);
         Pallet::<T>::create_identity(RawOrigin::Signed(who.clone()).into())?;
         let identity_id = Pallet::<T>::identity_number().saturating_sub(1);
         
@@ -246,6 +341,227 @@ mod benchmarks {
         Ok(())
     }
 
+    #[benchmark]
+    fn clear_identity_traits(t: Linear<1, { T::MaxTraitRemovals::get() }>) -> Result<(), BenchmarkError> {
+        let who: T::AccountId = whitelisted_caller();
+        fund_account::<T>(&who);
+        Pallet::<T>::create_identity(RawOrigin::Signed(who.clone()).into())?;
+        let identity_id = Pallet::<T>::identity_number().saturating_sub(1);
+
+        // Leave `t` traits behind after the removals done by `revoke_identity`.
+        add_traits::<T>(&who, identity_id, T::MaxTraitRemovals::get().saturating_add(t))?;
+        Pallet::<T>::revoke_identity(RawOrigin::Signed(who.clone()).into(), identity_id)?;
+        assert!(Pallet::<T>::pending_trait_cleanup(identity_id).is_some());
+
+        #[extrinsic_call]
+        Pallet::<T>::clear_identity_traits(RawOrigin::Signed(who.clone()), identity_id);
+
+        assert!(IdentityTraitList::<T>::iter_prefix(identity_id).next().is_none());
+
+        Ok(())
+    }
+
+    #[benchmark]
+    fn propose_identity_transfer() -> Result<(), BenchmarkError> {
+        let who: T::AccountId = whitelisted_caller();
+        fund_account::<T>(&who);
+        Pallet::<T>::create_identity(RawOrigin::Signed(who.clone()).into())?;
+        let identity_id = Pallet::<T>::identity_number().saturating_sub(1);
+        let new_owner: T::AccountId = account("new_owner", 0, 0);
+
+        #[extrinsic_call]
+        Pallet::<T>::propose_identity_transfer(RawOrigin::Signed(who), identity_id, new_owner.clone());
+
+        assert_eq!(Pallet::<T>::pending_transfer(identity_id), Some(new_owner));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn accept_identity_transfer() -> Result<(), BenchmarkError> {
+        let who: T::AccountId = whitelisted_caller();
+        let new_owner: T::AccountId = account("new_owner", 0, 0);
+        fund_account::<T>(&who);
+        fund_account::<T>(&new_owner);
+        // Fill both owner indexes so the transfer touches the largest lists it can.
+        for _ in 0..T::MaxIdentitiesPerOwner::get() {
+            Pallet::<T>::create_identity(RawOrigin::Signed(who.clone()).into())?;
+        }
+        for _ in 1..T::MaxIdentitiesPerOwner::get() {
+            Pallet::<T>::create_identity(RawOrigin::Signed(new_owner.clone()).into())?;
+        }
+        let identity_id = Pallet::<T>::owned_identities(&who)[0];
+        Pallet::<T>::propose_identity_transfer(
+            RawOrigin::Signed(who.clone()).into(),
+            identity_id,
+            new_owner.clone(),
+        )?;
+
+        #[extrinsic_call]
+        Pallet::<T>::accept_identity_transfer(RawOrigin::Signed(new_owner.clone()), identity_id);
+
+        assert_eq!(Pallet::<T>::identity_list(identity_id), Some(new_owner));
+        assert!(!Pallet::<T>::owned_identities(&who).contains(&identity_id));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn cancel_identity_transfer() -> Result<(), BenchmarkError> {
+        let who: T::AccountId = whitelisted_caller();
+        fund_account::<T>(&who);
+        Pallet::<T>::create_identity(RawOrigin::Signed(who.clone()).into())?;
+        let identity_id = Pallet::<T>::identity_number().saturating_sub(1);
+        Pallet::<T>::propose_identity_transfer(
+            RawOrigin::Signed(who.clone()).into(),
+            identity_id,
+            account("new_owner", 0, 0),
+        )?;
+
+        #[extrinsic_call]
+        Pallet::<T>::cancel_identity_transfer(RawOrigin::Signed(who), identity_id);
+
+        assert!(Pallet::<T>::pending_transfer(identity_id).is_none());
+        Ok(())
+    }
+
+    #[benchmark]
+    fn add_registrar() -> Result<(), BenchmarkError> {
+        for i in 1..T::MaxRegistrars::get() {
+            setup_registrar::<T>(&account("registrar", i, 0))?;
+        }
+        let origin = T::RegistrarOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let registrar: T::AccountId = account("registrar", 0, 0);
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, registrar.clone(), T::BaseDeposit::get());
+
+        assert_eq!(Pallet::<T>::registrar_fee(&registrar), Some(T::BaseDeposit::get()));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn remove_registrar() -> Result<(), BenchmarkError> {
+        let registrar: T::AccountId = account("registrar", 0, 0);
+        setup_registrar::<T>(&registrar)?;
+        let origin = T::RegistrarOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, registrar.clone());
+
+        assert!(Pallet::<T>::registrar_fee(&registrar).is_none());
+        Ok(())
+    }
+
+    #[benchmark]
+    fn request_attestation() -> Result<(), BenchmarkError> {
+        let who: T::AccountId = whitelisted_caller();
+        let registrar: T::AccountId = account("registrar", 0, 0);
+        setup_registrar::<T>(&registrar)?;
+        fund_account::<T>(&who);
+        Pallet::<T>::create_identity(RawOrigin::Signed(who.clone()).into())?;
+        let identity_id = Pallet::<T>::identity_number().saturating_sub(1);
+        let key: BoundedVec<u8, T::MaxSize> = vec![1u8; <T::MaxSize as Get<u32>>::get() as usize].try_into().unwrap();
+        let value: BoundedVec<u8, T::MaxSize> = vec![2u8; <T::MaxSize as Get<u32>>::get() as usize].try_into().unwrap();
+        Pallet::<T>::add_or_update_identity_trait(
+            RawOrigin::Signed(who.clone()).into(),
+            identity_id,
+            key.clone(),
+            value,
+        )?;
+
+        #[extrinsic_call]
+        Pallet::<T>::request_attestation(
+            RawOrigin::Signed(who.clone()),
+            identity_id,
+            registrar.clone(),
+            key.clone(),
+            T::BaseDeposit::get(),
+        );
+
+        assert!(Pallet::<T>::attestation_request(identity_id, (registrar, key)).is_some());
+        Ok(())
+    }
+
+    #[benchmark]
+    fn cancel_attestation_request() -> Result<(), BenchmarkError> {
+        let who: T::AccountId = whitelisted_caller();
+        let registrar: T::AccountId = account("registrar", 0, 0);
+        setup_registrar::<T>(&registrar)?;
+        let (identity_id, key, _) = setup_attestation_request::<T>(&who, &registrar)?;
+
+        #[extrinsic_call]
+        Pallet::<T>::cancel_attestation_request(
+            RawOrigin::Signed(who.clone()),
+            identity_id,
+            registrar.clone(),
+            key.clone(),
+        );
+
+        assert!(Pallet::<T>::attestation_request(identity_id, (registrar, key)).is_none());
+        Ok(())
+    }
+
+    #[benchmark]
+    fn provide_judgement() -> Result<(), BenchmarkError> {
+        let who: T::AccountId = whitelisted_caller();
+        let registrar: T::AccountId = account("registrar", 0, 0);
+        setup_registrar::<T>(&registrar)?;
+        let (identity_id, key, value) = setup_attestation_request::<T>(&who, &registrar)?;
+        let value_hash = T::Hashing::hash(&value);
+
+        #[extrinsic_call]
+        Pallet::<T>::provide_judgement(
+            RawOrigin::Signed(registrar.clone()),
+            identity_id,
+            key.clone(),
+            Judgement::KnownGood,
+            value_hash,
+        );
+
+        assert_eq!(Pallet::<T>::attestations(identity_id, key).len(), 1);
+        Ok(())
+    }
+
+    #[benchmark]
+    fn set_identity_traits(
+        u: Linear<0, { T::MaxTraitUpdates::get() }>,
+        r: Linear<0, { T::MaxTraitUpdates::get() }>,
+        b: Linear<0, { T::MaxTraitUpdates::get().saturating_mul(<T::MaxSize as Get<u32>>::get()) }>,
+    ) -> Result<(), BenchmarkError> {
+        let who: T::AccountId = whitelisted_caller();
+        fund_account::<T>(&who);
+        Pallet::<T>::create_identity(RawOrigin::Signed(who.clone()).into())?;
+        let identity_id = Pallet::<T>::identity_number().saturating_sub(1);
+        // Overwrite existing traits so every upsert reads an old value and adjusts the deposit,
+        // and give every removal a trait to release.
+        add_traits::<T>(&who, identity_id, u.saturating_add(r))?;
+        let len = b.checked_div(u).unwrap_or_default().min(<T::MaxSize as Get<u32>>::get()) as usize;
+        let upserts: BoundedVec<_, T::MaxTraitUpdates> = (0..u)
+            .map(|i| {
+                let key: TraitBytes<T> = i.to_le_bytes().to_vec().try_into().unwrap();
+                let value: TraitBytes<T> = vec![2u8; len].try_into().unwrap();
+                (key, value)
+            })
+            .collect::<Vec<_>>()
+            .try_into()
+            .unwrap();
+        let removals: BoundedVec<_, T::MaxTraitUpdates> = (u..u.saturating_add(r))
+            .map(|i| -> TraitBytes<T> { i.to_le_bytes().to_vec().try_into().unwrap() })
+            .collect::<Vec<_>>()
+            .try_into()
+            .unwrap();
+
+        #[extrinsic_call]
+        Pallet::<T>::set_identity_traits(
+            RawOrigin::Signed(who.clone()),
+            identity_id,
+            upserts,
+            removals,
+        );
+
+        assert_eq!(IdentityTraitList::<T>::iter_prefix(identity_id).count(), u as usize);
+        Ok(())
+    }
+
     impl_benchmark_test_suite!(
         Pallet,
         crate::mock::new_test_ext(),
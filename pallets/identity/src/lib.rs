@@ -9,17 +9,80 @@ mod tests;
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
 
+pub mod migrations;
 pub mod weights;
 
+extern crate alloc;
+
 pub use pallet::*;
 use weights::*;
 
 #[frame_support::pallet]
 pub mod pallet {
     use super::*;
-    use frame_support::{dispatch::{DispatchResultWithPostInfo, DispatchResult}, pallet_prelude::*};
+    use frame_support::{
+        dispatch::{DispatchResultWithPostInfo, DispatchResult},
+        pallet_prelude::*,
+        traits::{
+            fungible::{Inspect, Mutate, MutateHold},
+            tokens::{Fortitude, Precision, Restriction},
+        },
+    };
     use frame_system::pallet_prelude::*;
-    use sp_runtime::traits::One;
+    use alloc::vec::Vec;
+    use core::cmp::Ordering;
+    use sp_runtime::traits::{Hash, One, Saturating, Zero};
+
+    pub type BalanceOf<T> =
+        <<T as Config>::Currency as Inspect<<T as frame_system::Config>::AccountId>>::Balance;
+
+    /// A registrar's assessment of an identity trait.
+    #[derive(
+        Encode, Decode, DecodeWithMemTracking, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo,
+        MaxEncodedLen,
+    )]
+    pub enum Judgement {
+        /// The trait value has been checked and is believed to be correct.
+        KnownGood,
+        /// The trait value looks reasonable, but could not be fully checked.
+        Reasonable,
+        /// The trait value is of low quality or cannot be relied upon.
+        LowQuality,
+        /// The trait value is wrong.
+        Erroneous,
+    }
+
+    /// A judgement given by a registrar on the value a trait held at the time.
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    pub struct Attestation<AccountId, Hash, BlockNumber> {
+        /// The registrar that gave the judgement.
+        pub registrar: AccountId,
+        /// The registrar's judgement.
+        pub judgement: Judgement,
+        /// The hash of the trait value that was judged.
+        pub value_hash: Hash,
+        /// The block in which the judgement was given.
+        pub block: BlockNumber,
+    }
+
+    /// An open request for a registrar to judge an identity trait.
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    pub struct AttestationRequest<AccountId, Balance> {
+        /// The account that made the request and whose fee is held.
+        pub requester: AccountId,
+        /// The fee paid to the registrar once the judgement is given.
+        pub fee: Balance,
+    }
+
+    /// A trait key/value pair.
+    pub type TraitEntryOf<T> =
+        (BoundedVec<u8, <T as Config>::MaxSize>, BoundedVec<u8, <T as Config>::MaxSize>);
+
+    pub type AttestationOf<T> = Attestation<
+        <T as frame_system::Config>::AccountId,
+        <T as frame_system::Config>::Hash,
+        BlockNumberFor<T>,
+    >;
 
     #[pallet::config]
     pub trait Config: frame_system::Config {
@@ -29,9 +92,48 @@ pub mod pallet {
         type WeightInfo: WeightInfo;
         // Please add one line of comment here about this config
         type MaxSize: Get<u32>;
+        /// The maximum number of identities a single account can own at once.
+        #[pallet::constant]
+        type MaxIdentitiesPerOwner: Get<u32>;
+        /// The overarching hold reason.
+        type RuntimeHoldReason: From<HoldReason>;
+        /// The currency used to hold identity and trait deposits.
+        type Currency: Mutate<Self::AccountId>
+            + MutateHold<Self::AccountId, Reason = Self::RuntimeHoldReason>;
+        /// The deposit held for every identity created.
+        #[pallet::constant]
+        type BaseDeposit: Get<BalanceOf<Self>>;
+        /// The deposit held per byte of trait key and value stored against an identity.
+        #[pallet::constant]
+        type ByteDeposit: Get<BalanceOf<Self>>;
+        /// The maximum number of traits removed from a revoked identity in a single call.
+        #[pallet::constant]
+        type MaxTraitRemovals: Get<u32>;
+        /// The origin allowed to add and remove registrars.
+        type RegistrarOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+        /// The maximum number of registrars, and so of attestations held against a single trait.
+        #[pallet::constant]
+        type MaxRegistrars: Get<u32>;
+        /// The maximum number of upserts, and separately of removals, in one `set_identity_traits`
+        /// call.
+        #[pallet::constant]
+        type MaxTraitUpdates: Get<u32>;
+    }
+
+    /// A reason for the pallet placing a hold on funds.
+    #[pallet::composite_enum]
+    pub enum HoldReason {
+        /// Funds are held as the storage deposit for an identity and its traits.
+        IdentityDeposit,
+        /// Funds are held as the fee for a pending attestation request.
+        AttestationFee,
     }
 
+    /// The in-code storage version.
+    const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
+
     #[pallet::pallet]
+    #[pallet::storage_version(STORAGE_VERSION)]
     pub struct Pallet<T>(_);
 
     #[pallet::type_value]
@@ -54,6 +156,23 @@ pub mod pallet {
     /// Maps accounts to the array of identities it owns.
     pub type IdentityList<T: Config> = StorageMap<_, Blake2_128Concat, u32, T::AccountId>;
 
+    #[pallet::storage]
+    #[pallet::getter(fn owned_identities)]
+    /// Maps accounts to the IDs of the identities they currently own.
+    pub type OwnedIdentities<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        BoundedVec<u32, T::MaxIdentitiesPerOwner>,
+        ValueQuery,
+    >;
+
+    #[pallet::storage]
+    #[pallet::getter(fn identity_deposit)]
+    /// Tracks the total deposit held from the owner of each identity.
+    pub type IdentityDeposits<T: Config> =
+        StorageMap<_, Blake2_128Concat, u32, BalanceOf<T>, ValueQuery>;
+
     #[pallet::storage]
     #[pallet::getter(fn identity_trait_list)]
     /// Maps identity ID numbers to their key/value attributes.
@@ -67,6 +186,48 @@ pub mod pallet {
         ValueQuery,
     >;
 
+    #[pallet::storage]
+    #[pallet::getter(fn pending_trait_cleanup)]
+    /// Maps revoked identities whose traits are still being removed to their former owner, who
+    /// gets each trait's deposit back as it is removed.
+    pub type PendingTraitCleanup<T: Config> = StorageMap<_, Blake2_128Concat, u32, T::AccountId>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn pending_transfer)]
+    /// Maps identities with an open transfer proposal to the account invited to take ownership.
+    pub type PendingTransfers<T: Config> = StorageMap<_, Blake2_128Concat, u32, T::AccountId>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn registrar_fee)]
+    /// Maps registrar accounts to the fee they charge for an attestation.
+    pub type Registrars<T: Config> =
+        CountedStorageMap<_, Blake2_128Concat, T::AccountId, BalanceOf<T>>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn attestation_request)]
+    /// Maps identity ID numbers and (registrar, trait key) pairs to open attestation requests.
+    pub type AttestationRequests<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        u32,
+        Blake2_128Concat,
+        (T::AccountId, BoundedVec<u8, T::MaxSize>),
+        AttestationRequest<T::AccountId, BalanceOf<T>>,
+    >;
+
+    #[pallet::storage]
+    #[pallet::getter(fn attestations)]
+    /// Maps identity ID numbers and trait keys to the registrar judgements on the current value.
+    pub type Attestations<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        u32,
+        Blake2_128Concat,
+        BoundedVec<u8, T::MaxSize>,
+        BoundedVec<AttestationOf<T>, T::MaxRegistrars>,
+        ValueQuery,
+    >;
+
     #[pallet::event]
     #[pallet::generate_deposit(pub(super) fn deposit_event)]
     pub enum Event<T: Config> {
@@ -76,9 +237,49 @@ pub mod pallet {
         /// Announce that an identity has been revoked. Contains the ID number of the identity ad
         /// the owning AccountId.
         IdentityRevoked { identity_id: u32, owner: T::AccountId },
-        /// Announce that an identity has been updated. Contains the ID number of the identity and
-        /// the owning AccountId.
-        IdentityUpdated { identity_id: u32, owner: T::AccountId },
+        /// Announce that a trait of an identity was added or updated. Contains the ID number of
+        /// the identity, the trait key and the hash of the new value.
+        IdentityTraitSet { identity_id: u32, key: BoundedVec<u8, T::MaxSize>, value_hash: T::Hash },
+        /// Announce that a trait was removed from an identity. Contains the ID number of the
+        /// identity and the trait key.
+        IdentityTraitRemoved { identity_id: u32, key: BoundedVec<u8, T::MaxSize> },
+        /// Announce that traits were removed from a revoked identity. Contains the ID number of
+        /// the identity, the number of traits removed and whether any traits remain.
+        IdentityTraitsCleared { identity_id: u32, removed: u32, complete: bool },
+        /// Announce that the owner of an identity has proposed transferring it to a new account.
+        IdentityTransferProposed { identity_id: u32, owner: T::AccountId, new_owner: T::AccountId },
+        /// Announce that an identity has moved to a new owner.
+        IdentityTransferred { identity_id: u32, old_owner: T::AccountId, new_owner: T::AccountId },
+        /// Announce that a pending identity transfer was withdrawn by the owner.
+        IdentityTransferCancelled { identity_id: u32, owner: T::AccountId },
+        /// Announce that an account was made a registrar, along with the fee it charges.
+        RegistrarAdded { registrar: T::AccountId, fee: BalanceOf<T> },
+        /// Announce that an account is no longer a registrar.
+        RegistrarRemoved { registrar: T::AccountId },
+        /// Announce that the owner of an identity asked a registrar to judge one of its traits.
+        AttestationRequested {
+            identity_id: u32,
+            registrar: T::AccountId,
+            key: BoundedVec<u8, T::MaxSize>,
+            fee: BalanceOf<T>,
+        },
+        /// Announce that an attestation request was withdrawn and its fee released.
+        AttestationRequestCancelled {
+            identity_id: u32,
+            registrar: T::AccountId,
+            key: BoundedVec<u8, T::MaxSize>,
+        },
+        /// Announce that a registrar judged the current value of an identity trait.
+        TraitAttested {
+            identity_id: u32,
+            registrar: T::AccountId,
+            key: BoundedVec<u8, T::MaxSize>,
+            judgement: Judgement,
+            value_hash: T::Hash,
+        },
+        /// Announce that the attestations on an identity trait were dropped because its value
+        /// changed or it was removed.
+        AttestationsInvalidated { identity_id: u32, key: BoundedVec<u8, T::MaxSize> },
     }
 
     #[pallet::error]
@@ -88,6 +289,34 @@ pub mod pallet {
         StorageOverflow,
         /// The current account does not own the identity.
         IdentityNotOwned,
+        /// The current account already owns the maximum number of identities.
+        TooManyIdentities,
+        /// The identity has no traits left to clear.
+        NoTraitsToClear,
+        /// An identity cannot be transferred to its current owner.
+        TransferToSelf,
+        /// The identity has no pending transfer.
+        NoPendingTransfer,
+        /// The current account is not the recipient of the pending transfer.
+        NotTransferRecipient,
+        /// The account is already a registrar.
+        AlreadyRegistrar,
+        /// The account is not a registrar.
+        NotRegistrar,
+        /// The maximum number of registrars has been reached.
+        TooManyRegistrars,
+        /// The identity has no trait with the given key.
+        TraitNotFound,
+        /// The registrar's fee is higher than the requester is willing to pay.
+        FeeTooHigh,
+        /// An attestation of this trait has already been requested from the registrar.
+        AttestationAlreadyRequested,
+        /// There is no open attestation request for this trait and registrar.
+        NoAttestationRequest,
+        /// The current account did not make the attestation request.
+        NotAttestationRequester,
+        /// The given hash does not match the current value of the trait.
+        ValueHashMismatch,
     }
 
     impl<T: Config> Pallet<T> {
@@ -97,6 +326,140 @@ pub mod pallet {
                 Result::Err(_) => false,
             }
         }
+
+        /// Returns the key/value traits stored against `identity_id`.
+        pub fn traits_of(identity_id: u32) -> Vec<(Vec<u8>, Vec<u8>)> {
+            <IdentityTraitList<T>>::iter_prefix(identity_id)
+                .map(|(key, value)| (key.into_inner(), value.into_inner()))
+                .collect()
+        }
+
+        /// Removes up to `MaxTraitRemovals` traits from `identity_id`, releasing each one's
+        /// deposit to `owner`, and returns the number of traits removed. If any traits remain the
+        /// identity is marked for a later `clear_identity_traits` call; otherwise the rest of its
+        /// deposit is released too.
+        fn clear_traits(owner: &T::AccountId, identity_id: u32) -> u32 {
+            let mut removed: u32 = 0;
+            for (key, value) in <IdentityTraitList<T>>::drain_prefix(identity_id)
+                .take(T::MaxTraitRemovals::get() as usize)
+            {
+                <Attestations<T>>::remove(identity_id, &key);
+                Self::release_deposit(owner, identity_id, Self::trait_deposit(key.len().saturating_add(value.len())));
+                removed.saturating_inc();
+            }
+            let complete = <IdentityTraitList<T>>::iter_key_prefix(identity_id).next().is_none();
+            if complete {
+                <PendingTraitCleanup<T>>::remove(identity_id);
+                Self::release_deposit(owner, identity_id, <IdentityDeposits<T>>::get(identity_id));
+                <IdentityDeposits<T>>::remove(identity_id);
+            } else {
+                <PendingTraitCleanup<T>>::insert(identity_id, owner);
+            }
+            Self::deposit_event(Event::IdentityTraitsCleared { identity_id, removed, complete });
+            removed
+        }
+
+        /// Stores `value` under `key` for `identity_id`, adjusting the owner's deposit and
+        /// dropping attestations if the value changed.
+        fn set_trait(
+            who: &T::AccountId,
+            identity_id: u32,
+            key: BoundedVec<u8, T::MaxSize>,
+            value: BoundedVec<u8, T::MaxSize>,
+        ) -> DispatchResult {
+            let old = <IdentityTraitList<T>>::try_get(identity_id, &key).ok();
+            let old_deposit = old
+                .as_ref()
+                .map(|old| Self::trait_deposit(key.len().saturating_add(old.len())))
+                .unwrap_or_else(Zero::zero);
+            let new_deposit = Self::trait_deposit(key.len().saturating_add(value.len()));
+            Self::update_deposit(who, identity_id, old_deposit, new_deposit)?;
+            if old.is_some_and(|old| old != value) {
+                Self::invalidate_attestations(identity_id, &key);
+            }
+            let value_hash = T::Hashing::hash(&value);
+            <IdentityTraitList<T>>::insert(identity_id, &key, value);
+            Self::deposit_event(Event::IdentityTraitSet { identity_id, key, value_hash });
+            Ok(())
+        }
+
+        /// Removes `key` from `identity_id`, releasing its deposit and dropping its attestations.
+        fn remove_trait(
+            who: &T::AccountId,
+            identity_id: u32,
+            key: &BoundedVec<u8, T::MaxSize>,
+        ) -> DispatchResult {
+            if let Ok(old) = <IdentityTraitList<T>>::try_get(identity_id, key) {
+                let old_deposit = Self::trait_deposit(key.len().saturating_add(old.len()));
+                Self::update_deposit(who, identity_id, old_deposit, Zero::zero())?;
+                Self::invalidate_attestations(identity_id, key);
+                <IdentityTraitList<T>>::remove(identity_id, key);
+                Self::deposit_event(Event::IdentityTraitRemoved { identity_id, key: key.clone() });
+            }
+            Ok(())
+        }
+
+        /// The total number of key and value bytes in a `set_identity_traits` call.
+        fn trait_update_bytes(
+            upserts: &[TraitEntryOf<T>],
+            removals: &[BoundedVec<u8, T::MaxSize>],
+        ) -> u32 {
+            let upsert_bytes = upserts.iter().map(|(key, value)| key.len().saturating_add(value.len()));
+            let removal_bytes = removals.iter().map(|key| key.len());
+            upsert_bytes.chain(removal_bytes).fold(0usize, |total, len| total.saturating_add(len)) as u32
+        }
+
+        /// Drops every attestation given on the trait `key` of `identity_id`.
+        fn invalidate_attestations(identity_id: u32, key: &BoundedVec<u8, T::MaxSize>) {
+            if !<Attestations<T>>::take(identity_id, key).is_empty() {
+                Self::deposit_event(Event::AttestationsInvalidated { identity_id, key: key.clone() });
+            }
+        }
+
+        /// The deposit required to store `bytes` bytes of trait data.
+        fn trait_deposit(bytes: usize) -> BalanceOf<T> {
+            T::ByteDeposit::get().saturating_mul((bytes as u32).into())
+        }
+
+        /// Releases up to `amount` of the deposit held for `identity_id` to `owner`. The release
+        /// is best-effort, so a failure cannot stall the cleanup of a revoked identity.
+        fn release_deposit(owner: &T::AccountId, identity_id: u32, amount: BalanceOf<T>) {
+            let held = <IdentityDeposits<T>>::get(identity_id);
+            let amount = amount.min(held);
+            if amount.is_zero() {
+                return;
+            }
+            let reason = HoldReason::IdentityDeposit.into();
+            if let Ok(released) = T::Currency::release(&reason, owner, amount, Precision::BestEffort) {
+                <IdentityDeposits<T>>::insert(identity_id, held.saturating_sub(released));
+            }
+        }
+
+        /// Moves the deposit held for `identity_id` from `old` to `new`, holding or releasing
+        /// the difference on `who`.
+        fn update_deposit(
+            who: &T::AccountId,
+            identity_id: u32,
+            old: BalanceOf<T>,
+            new: BalanceOf<T>,
+        ) -> DispatchResult {
+            let reason = HoldReason::IdentityDeposit.into();
+            match new.cmp(&old) {
+                Ordering::Greater => {
+                    let extra = new.saturating_sub(old);
+                    T::Currency::hold(&reason, who, extra)?;
+                    <IdentityDeposits<T>>::mutate(identity_id, |held| held.saturating_accrue(extra));
+                },
+                Ordering::Less => {
+                    let held = <IdentityDeposits<T>>::get(identity_id);
+                    let excess = old.saturating_sub(new).min(held);
+                    let released = T::Currency::release(&reason, who, excess, Precision::BestEffort)?;
+                    <IdentityDeposits<T>>::insert(identity_id, held.saturating_sub(released));
+                },
+                Ordering::Equal => {},
+            }
+            Ok(())
+        }
     }
 
     #[pallet::call]
@@ -113,34 +476,44 @@ pub mod pallet {
             })?;
             let new_id: u32 = <IdentityNumber<T>>::get();
             // Ensure that the index current_id isn't already in use.
-            ensure!(!<IdentityList<T>>::contains_key(&current_id), Error::<T>::StorageOverflow);
-            <IdentityList<T>>::try_mutate(&current_id, |owner| -> DispatchResult {
+            ensure!(!<IdentityList<T>>::contains_key(current_id), Error::<T>::StorageOverflow);
+            <OwnedIdentities<T>>::try_mutate(&who, |owned| -> DispatchResult {
+                owned.try_push(current_id).map_err(|_| Error::<T>::TooManyIdentities)?;
+                Ok(())
+            })?;
+            <IdentityList<T>>::try_mutate(current_id, |owner| -> DispatchResult {
                 *owner = Some(who.clone());
                 Ok(())
             })?;
+            Self::update_deposit(&who, current_id, Zero::zero(), T::BaseDeposit::get())?;
             <IdentityNumber<T>>::put(new_id);
             Self::deposit_event(Event::IdentityCreated { identity_id: current_id, owner: who.clone() });
             Ok(().into())
         }
 
         /// Revokes the identity with ID number identity_id, as long as the identity is owned by
-        /// origin.
-        #[pallet::weight(T::WeightInfo::revoke_identity())]
+        /// origin. Up to `MaxTraitRemovals` of the identity's traits are removed straight away;
+        /// any that remain can be cleared with `clear_identity_traits`. The deposit of each trait
+        /// is released as it is removed, and the base deposit once no traits remain.
+        #[pallet::weight(T::WeightInfo::revoke_identity(T::MaxTraitRemovals::get()))]
         #[pallet::call_index(1)]
         pub fn revoke_identity(origin: OriginFor<T>, identity_id: u32) -> DispatchResultWithPostInfo {
             let who = ensure_signed(origin)?;
             // Make sure the identity is owned by the sender.
             ensure!(Self::is_identity_owned_by_sender(&who, &identity_id), Error::<T>::IdentityNotOwned);
-            <IdentityList<T>>::try_mutate(&identity_id, |owner| -> DispatchResult {
+            <IdentityList<T>>::try_mutate(identity_id, |owner| -> DispatchResult {
                 *owner = None;
                 Ok(())
             })?;
+            <OwnedIdentities<T>>::mutate(&who, |owned| owned.retain(|id| *id != identity_id));
+            <PendingTransfers<T>>::remove(identity_id);
+            let removed = Self::clear_traits(&who, identity_id);
             Self::deposit_event(Event::IdentityRevoked { identity_id, owner: who.clone() });
-            Ok(().into())
+            Ok(Some(T::WeightInfo::revoke_identity(removed)).into())
         }
 
         /// Add a new identity trait to identity_id with key/value.
-        #[pallet::weight(T::WeightInfo::add_or_update_identity_trait(key.len() as u32))]
+        #[pallet::weight(T::WeightInfo::add_or_update_identity_trait(key.len() as u32, value.len() as u32))]
         #[pallet::call_index(2)]
         pub fn add_or_update_identity_trait(
             origin: OriginFor<T>,
@@ -150,11 +523,7 @@ pub mod pallet {
         ) -> DispatchResultWithPostInfo {
             let who = ensure_signed(origin)?;
             ensure!(Self::is_identity_owned_by_sender(&who, &identity_id), Error::<T>::IdentityNotOwned);
-            <IdentityTraitList<T>>::try_mutate(identity_id, key.clone(), |v| -> DispatchResult {
-                *v = value;
-                Ok(())
-            })?;
-            Self::deposit_event(Event::IdentityUpdated { identity_id, owner: who.clone() });
+            Self::set_trait(&who, identity_id, key, value)?;
             Ok(().into())
         }
 
@@ -168,8 +537,229 @@ pub mod pallet {
         ) -> DispatchResultWithPostInfo {
             let who = ensure_signed(origin)?;
             ensure!(Self::is_identity_owned_by_sender(&who, &identity_id), Error::<T>::IdentityNotOwned);
-            <IdentityTraitList<T>>::remove(identity_id, key.clone());
-            Self::deposit_event(Event::IdentityUpdated { identity_id, owner: who.clone() });
+            Self::remove_trait(&who, identity_id, &key)?;
+            Ok(().into())
+        }
+
+        /// Continues removing the traits of a revoked identity whose cleanup did not complete
+        /// within `revoke_identity`, releasing their deposits to the former owner. Any signed
+        /// origin may call this.
+        #[pallet::weight(T::WeightInfo::clear_identity_traits(T::MaxTraitRemovals::get()))]
+        #[pallet::call_index(4)]
+        pub fn clear_identity_traits(origin: OriginFor<T>, identity_id: u32) -> DispatchResultWithPostInfo {
+            ensure_signed(origin)?;
+            let owner = <PendingTraitCleanup<T>>::get(identity_id).ok_or(Error::<T>::NoTraitsToClear)?;
+            let removed = Self::clear_traits(&owner, identity_id);
+            Ok(Some(T::WeightInfo::clear_identity_traits(removed)).into())
+        }
+
+        /// Proposes transferring the identity with ID identity_id to new_owner. The transfer only
+        /// takes effect once new_owner accepts it, and replaces any earlier proposal.
+        #[pallet::weight(T::WeightInfo::propose_identity_transfer())]
+        #[pallet::call_index(5)]
+        pub fn propose_identity_transfer(
+            origin: OriginFor<T>,
+            identity_id: u32,
+            new_owner: T::AccountId,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            ensure!(Self::is_identity_owned_by_sender(&who, &identity_id), Error::<T>::IdentityNotOwned);
+            ensure!(who != new_owner, Error::<T>::TransferToSelf);
+            <PendingTransfers<T>>::insert(identity_id, new_owner.clone());
+            Self::deposit_event(Event::IdentityTransferProposed { identity_id, owner: who, new_owner });
+            Ok(().into())
+        }
+
+        /// Accepts a pending transfer of the identity with ID identity_id to origin. The
+        /// identity's deposit is held from origin and released back to the previous owner.
+        #[pallet::weight(T::WeightInfo::accept_identity_transfer())]
+        #[pallet::call_index(6)]
+        pub fn accept_identity_transfer(origin: OriginFor<T>, identity_id: u32) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            let new_owner = <PendingTransfers<T>>::get(identity_id).ok_or(Error::<T>::NoPendingTransfer)?;
+            ensure!(who == new_owner, Error::<T>::NotTransferRecipient);
+            let old_owner = <IdentityList<T>>::get(identity_id).ok_or(Error::<T>::NoPendingTransfer)?;
+            <OwnedIdentities<T>>::try_mutate(&who, |owned| -> DispatchResult {
+                owned.try_push(identity_id).map_err(|_| Error::<T>::TooManyIdentities)?;
+                Ok(())
+            })?;
+            <OwnedIdentities<T>>::mutate(&old_owner, |owned| owned.retain(|id| *id != identity_id));
+            let deposit = <IdentityDeposits<T>>::get(identity_id);
+            if !deposit.is_zero() {
+                let reason = HoldReason::IdentityDeposit.into();
+                T::Currency::hold(&reason, &who, deposit)?;
+                T::Currency::release(&reason, &old_owner, deposit, Precision::BestEffort)?;
+            }
+            <IdentityList<T>>::insert(identity_id, who.clone());
+            <PendingTransfers<T>>::remove(identity_id);
+            Self::deposit_event(Event::IdentityTransferred { identity_id, old_owner, new_owner: who });
+            Ok(().into())
+        }
+
+        /// Withdraws the pending transfer of the identity with ID identity_id, as long as the
+        /// identity is owned by origin.
+        #[pallet::weight(T::WeightInfo::cancel_identity_transfer())]
+        #[pallet::call_index(7)]
+        pub fn cancel_identity_transfer(origin: OriginFor<T>, identity_id: u32) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            ensure!(Self::is_identity_owned_by_sender(&who, &identity_id), Error::<T>::IdentityNotOwned);
+            ensure!(<PendingTransfers<T>>::contains_key(identity_id), Error::<T>::NoPendingTransfer);
+            <PendingTransfers<T>>::remove(identity_id);
+            Self::deposit_event(Event::IdentityTransferCancelled { identity_id, owner: who });
+            Ok(().into())
+        }
+
+        /// Makes registrar a registrar that charges fee for each attestation, or updates the
+        /// fee of an existing registrar.
+        #[pallet::weight(T::WeightInfo::add_registrar())]
+        #[pallet::call_index(8)]
+        pub fn add_registrar(
+            origin: OriginFor<T>,
+            registrar: T::AccountId,
+            fee: BalanceOf<T>,
+        ) -> DispatchResultWithPostInfo {
+            T::RegistrarOrigin::ensure_origin(origin)?;
+            if !<Registrars<T>>::contains_key(&registrar) {
+                ensure!(<Registrars<T>>::count() < T::MaxRegistrars::get(), Error::<T>::TooManyRegistrars);
+            }
+            <Registrars<T>>::insert(&registrar, fee);
+            Self::deposit_event(Event::RegistrarAdded { registrar, fee });
+            Ok(().into())
+        }
+
+        /// Removes registrar from the registrar set. Attestations it has already given are kept.
+        #[pallet::weight(T::WeightInfo::remove_registrar())]
+        #[pallet::call_index(9)]
+        pub fn remove_registrar(origin: OriginFor<T>, registrar: T::AccountId) -> DispatchResultWithPostInfo {
+            T::RegistrarOrigin::ensure_origin(origin)?;
+            ensure!(<Registrars<T>>::contains_key(&registrar), Error::<T>::NotRegistrar);
+            <Registrars<T>>::remove(&registrar);
+            Self::deposit_event(Event::RegistrarRemoved { registrar });
+            Ok(().into())
+        }
+
+        /// Asks registrar to judge the trait key of the identity with ID identity_id, as long as
+        /// the identity is owned by origin. The registrar's fee, which may not exceed max_fee, is
+        /// held until the judgement is given.
+        #[pallet::weight(T::WeightInfo::request_attestation())]
+        #[pallet::call_index(10)]
+        pub fn request_attestation(
+            origin: OriginFor<T>,
+            identity_id: u32,
+            registrar: T::AccountId,
+            key: BoundedVec<u8, T::MaxSize>,
+            max_fee: BalanceOf<T>,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            ensure!(Self::is_identity_owned_by_sender(&who, &identity_id), Error::<T>::IdentityNotOwned);
+            ensure!(<IdentityTraitList<T>>::contains_key(identity_id, &key), Error::<T>::TraitNotFound);
+            let fee = <Registrars<T>>::get(&registrar).ok_or(Error::<T>::NotRegistrar)?;
+            ensure!(fee <= max_fee, Error::<T>::FeeTooHigh);
+            let request_key = (registrar.clone(), key.clone());
+            ensure!(
+                !<AttestationRequests<T>>::contains_key(identity_id, &request_key),
+                Error::<T>::AttestationAlreadyRequested
+            );
+            T::Currency::hold(&HoldReason::AttestationFee.into(), &who, fee)?;
+            <AttestationRequests<T>>::insert(
+                identity_id,
+                request_key,
+                AttestationRequest { requester: who, fee },
+            );
+            Self::deposit_event(Event::AttestationRequested { identity_id, registrar, key, fee });
+            Ok(().into())
+        }
+
+        /// Withdraws an attestation request made by origin and releases its fee.
+        #[pallet::weight(T::WeightInfo::cancel_attestation_request())]
+        #[pallet::call_index(11)]
+        pub fn cancel_attestation_request(
+            origin: OriginFor<T>,
+            identity_id: u32,
+            registrar: T::AccountId,
+            key: BoundedVec<u8, T::MaxSize>,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            let request_key = (registrar.clone(), key.clone());
+            let request = <AttestationRequests<T>>::get(identity_id, &request_key)
+                .ok_or(Error::<T>::NoAttestationRequest)?;
+            ensure!(request.requester == who, Error::<T>::NotAttestationRequester);
+            T::Currency::release(&HoldReason::AttestationFee.into(), &who, request.fee, Precision::BestEffort)?;
+            <AttestationRequests<T>>::remove(identity_id, &request_key);
+            Self::deposit_event(Event::AttestationRequestCancelled { identity_id, registrar, key });
+            Ok(().into())
+        }
+
+        /// Records origin's judgement on the trait key of the identity with ID identity_id and
+        /// pays origin the held fee. value_hash must be the hash of the trait's current value;
+        /// the attestation is dropped as soon as the owner changes or removes the trait.
+        #[pallet::weight(T::WeightInfo::provide_judgement())]
+        #[pallet::call_index(12)]
+        pub fn provide_judgement(
+            origin: OriginFor<T>,
+            identity_id: u32,
+            key: BoundedVec<u8, T::MaxSize>,
+            judgement: Judgement,
+            value_hash: T::Hash,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            ensure!(<Registrars<T>>::contains_key(&who), Error::<T>::NotRegistrar);
+            let request_key = (who.clone(), key.clone());
+            let request = <AttestationRequests<T>>::get(identity_id, &request_key)
+                .ok_or(Error::<T>::NoAttestationRequest)?;
+            let value = <IdentityTraitList<T>>::try_get(identity_id, &key)
+                .map_err(|_| Error::<T>::TraitNotFound)?;
+            ensure!(T::Hashing::hash(&value) == value_hash, Error::<T>::ValueHashMismatch);
+            let attestation = Attestation {
+                registrar: who.clone(),
+                judgement,
+                value_hash,
+                block: frame_system::Pallet::<T>::block_number(),
+            };
+            <Attestations<T>>::try_mutate(identity_id, &key, |attestations| -> DispatchResult {
+                match attestations.iter_mut().find(|a| a.registrar == who) {
+                    Some(existing) => *existing = attestation,
+                    None => attestations.try_push(attestation).map_err(|_| Error::<T>::TooManyRegistrars)?,
+                }
+                Ok(())
+            })?;
+            T::Currency::transfer_on_hold(
+                &HoldReason::AttestationFee.into(),
+                &request.requester,
+                &who,
+                request.fee,
+                Precision::BestEffort,
+                Restriction::Free,
+                Fortitude::Polite,
+            )?;
+            <AttestationRequests<T>>::remove(identity_id, &request_key);
+            Self::deposit_event(Event::TraitAttested { identity_id, registrar: who, key, judgement, value_hash });
+            Ok(().into())
+        }
+
+        /// Applies a batch of trait changes to the identity with ID identity_id, as long as the
+        /// identity is owned by origin. Every (key, value) pair in upserts is stored first, then
+        /// every key in removals is removed. Either all changes are applied or none are.
+        #[pallet::weight(T::WeightInfo::set_identity_traits(
+            upserts.len() as u32,
+            removals.len() as u32,
+            Pallet::<T>::trait_update_bytes(upserts, removals),
+        ))]
+        #[pallet::call_index(13)]
+        pub fn set_identity_traits(
+            origin: OriginFor<T>,
+            identity_id: u32,
+            upserts: BoundedVec<TraitEntryOf<T>, T::MaxTraitUpdates>,
+            removals: BoundedVec<BoundedVec<u8, T::MaxSize>, T::MaxTraitUpdates>,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            ensure!(Self::is_identity_owned_by_sender(&who, &identity_id), Error::<T>::IdentityNotOwned);
+            for (key, value) in upserts {
+                Self::set_trait(&who, identity_id, key, value)?;
+            }
+            for key in removals.iter() {
+                Self::remove_trait(&who, identity_id, key)?;
+            }
             Ok(().into())
         }
     }
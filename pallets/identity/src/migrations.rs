@@ -0,0 +1,142 @@
+//! Storage migrations for pallet-identity.
+
+/// Migrates the pallet from storage version 0 to 1.
+///
+/// Version 1 introduces the `OwnedIdentities` index, which this migration fills in from
+/// `IdentityList` for identities created before the upgrade.
+pub mod v1 {
+    use crate::{Config, IdentityList, OwnedIdentities, Pallet};
+    use core::marker::PhantomData;
+    use frame_support::{
+        migrations::VersionedMigration,
+        traits::{Get, UncheckedOnRuntimeUpgrade},
+        weights::Weight,
+    };
+    #[cfg(feature = "try-runtime")]
+    use {
+        alloc::{collections::btree_map::BTreeMap, vec::Vec},
+        codec::{Decode, Encode},
+        frame_support::ensure,
+        sp_runtime::TryRuntimeError,
+    };
+
+    const LOG_TARGET: &str = "runtime::identity";
+
+    /// Backfills `OwnedIdentities` from `IdentityList`. Use [`MigrateV0ToV1`] instead, which
+    /// only runs this once and bumps the storage version.
+    ///
+    /// Owners already at `MaxIdentitiesPerOwner` keep their identities, but only the first ones
+    /// fit in the index. Each identity left out is logged, along with how many were left out.
+    pub struct InnerMigrateV0ToV1<T>(PhantomData<T>);
+
+    impl<T: Config> UncheckedOnRuntimeUpgrade for InnerMigrateV0ToV1<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let mut reads: u64 = 0;
+            let mut writes: u64 = 0;
+            let mut skipped: u32 = 0;
+            for (identity_id, owner) in IdentityList::<T>::iter() {
+                reads = reads.saturating_add(2);
+                OwnedIdentities::<T>::mutate(&owner, |owned| {
+                    if owned.contains(&identity_id) {
+                        return;
+                    }
+                    if owned.try_push(identity_id).is_ok() {
+                        writes = writes.saturating_add(1);
+                    } else {
+                        skipped = skipped.saturating_add(1);
+                        log::warn!(
+                            target: LOG_TARGET,
+                            "identity {identity_id} of {owner:?} does not fit in OwnedIdentities and is left out",
+                        );
+                    }
+                });
+            }
+            if skipped > 0 {
+                log::warn!(
+                    target: LOG_TARGET,
+                    "{skipped} identities owned past MaxIdentitiesPerOwner were left out of OwnedIdentities",
+                );
+            }
+            T::DbWeight::get().reads_writes(reads, writes)
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+            Ok((IdentityList::<T>::iter().count() as u64).encode())
+        }
+
+        /// Checks that, for every owner, `OwnedIdentities` lists only identities `IdentityList`
+        /// gives them, each once, and as many of them as fit under `MaxIdentitiesPerOwner`.
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+            let identities = u64::decode(&mut &state[..]).map_err(|_| "invalid pre-upgrade state")?;
+            let mut owned_counts: BTreeMap<T::AccountId, u32> = BTreeMap::new();
+            for (_, owner) in IdentityList::<T>::iter() {
+                let count = owned_counts.entry(owner).or_default();
+                *count = count.saturating_add(1);
+            }
+            let total = owned_counts.values().map(|count| u64::from(*count)).sum::<u64>();
+            ensure!(total == identities, "IdentityList changed during the migration");
+            for owner in OwnedIdentities::<T>::iter_keys() {
+                ensure!(owned_counts.contains_key(&owner), "OwnedIdentities lists an account that owns no identity");
+            }
+            for (owner, count) in owned_counts {
+                let mut owned = OwnedIdentities::<T>::get(&owner).into_inner();
+                ensure!(
+                    owned.iter().all(|identity_id| IdentityList::<T>::get(identity_id).as_ref() == Some(&owner)),
+                    "OwnedIdentities lists an identity its account does not own"
+                );
+                let indexed = owned.len();
+                owned.sort_unstable();
+                owned.dedup();
+                ensure!(owned.len() == indexed, "OwnedIdentities lists an identity twice");
+                ensure!(
+                    indexed as u32 == count.min(T::MaxIdentitiesPerOwner::get()),
+                    "OwnedIdentities is missing identities that fit under MaxIdentitiesPerOwner"
+                );
+            }
+            Ok(())
+        }
+    }
+
+    /// Migrates pallet-identity from storage version 0 to 1.
+    pub type MigrateV0ToV1<T> = VersionedMigration<
+        0,
+        1,
+        InnerMigrateV0ToV1<T>,
+        Pallet<T>,
+        <T as frame_system::Config>::DbWeight,
+    >;
+}
+
+/// Migrates the pallet from storage version 1 to 2.
+///
+/// Version 2 replaces `Event::IdentityUpdated { identity_id, owner }` with
+/// `Event::IdentityTraitSet { identity_id, key, value_hash }` and
+/// `Event::IdentityTraitRemoved { identity_id, key }`. Events are not kept in state, so no stored
+/// data changes shape and this migration only bumps the version, but off-chain mirrors must
+/// switch to the new events from the upgrade block onwards.
+pub mod v2 {
+    use crate::{Config, Pallet};
+    use core::marker::PhantomData;
+    use frame_support::{migrations::VersionedMigration, traits::UncheckedOnRuntimeUpgrade, weights::Weight};
+
+    /// Leaves storage untouched. Use [`MigrateV1ToV2`] instead, which marks the event change by
+    /// bumping the storage version.
+    pub struct InnerMigrateV1ToV2<T>(PhantomData<T>);
+
+    impl<T: Config> UncheckedOnRuntimeUpgrade for InnerMigrateV1ToV2<T> {
+        fn on_runtime_upgrade() -> Weight {
+            Weight::zero()
+        }
+    }
+
+    /// Migrates pallet-identity from storage version 1 to 2.
+    pub type MigrateV1ToV2<T> = VersionedMigration<
+        1,
+        2,
+        InnerMigrateV1ToV2<T>,
+        Pallet<T>,
+        <T as frame_system::Config>::DbWeight,
+    >;
+}
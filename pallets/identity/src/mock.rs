@@ -20,6 +20,8 @@ parameter_types! {
 	pub const SS58Prefix: u8 = 42;
     pub const ExistentialDeposit: u128 = 1;
     pub const MaxLocks: u32 = 16;
+    pub const BaseDeposit: u128 = 10;
+    pub const ByteDeposit: u128 = 1;
 }
 
 impl frame_system::Config for Test {
@@ -65,7 +67,7 @@ impl pallet_balances::Config for Test {
     type WeightInfo = ();
     type MaxLocks = MaxLocks;
     // Additional types for recent Substrate
-    type RuntimeHoldReason = ();
+    type RuntimeHoldReason = RuntimeHoldReason;
     type RuntimeFreezeReason = ();
     type ReserveIdentifier = [u8; 8];
     type FreezeIdentifier = [u8; 8];
@@ -78,6 +80,15 @@ impl pallet_identity::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type WeightInfo = ();
 	type MaxSize = ConstU32<1024>;
+	type MaxIdentitiesPerOwner = ConstU32<5>;
+	type RuntimeHoldReason = RuntimeHoldReason;
+	type Currency = Balances;
+	type BaseDeposit = BaseDeposit;
+	type ByteDeposit = ByteDeposit;
+	type MaxTraitRemovals = ConstU32<2>;
+	type RegistrarOrigin = frame_system::EnsureRoot<u64>;
+	type MaxRegistrars = ConstU32<2>;
+	type MaxTraitUpdates = ConstU32<4>;
 }
 
 // Build genesis storage according to the mock runtime.
@@ -91,6 +102,10 @@ pub fn new_test_ext() -> sp_io::TestExternalities {
             (0, 1_000_000_000_000),
             (1, 1_000_000_000_000),
             (2, 1_000_000_000_000),
+            (3, 1_000_000_000_000),
+            (10, 1_000_000_000_000),
+            (20, 1_000_000_000_000),
+            (42, 1_000_000_000_000),
         ],
         dev_accounts: None,
     }
@@ -1,8 +1,18 @@
-use crate::mock::{new_test_ext, Test, System, RuntimeOrigin};
-use crate::{Error, Event, Pallet, IdentityNumber};
-use frame_support::{assert_noop, assert_ok, BoundedVec};
+use crate::mock::{new_test_ext, Balances, Test, System, RuntimeHoldReason, RuntimeOrigin};
+use crate::{migrations, Error, Event, HoldReason, IdentityList, IdentityNumber, IdentityTraitList, Judgement, OwnedIdentities, Pallet};
+use frame_support::{
+    assert_noop, assert_ok,
+    traits::{fungible::InspectHold, Get, GetStorageVersion, OnRuntimeUpgrade, StorageVersion},
+    BoundedVec,
+};
+use sp_runtime::{traits::Hash, DispatchError, TokenError};
 use crate as pallet_identity;
 
+fn held(who: u64) -> u128 {
+    let reason: RuntimeHoldReason = HoldReason::IdentityDeposit.into();
+    <Balances as InspectHold<u64>>::balance_on_hold(&reason, &who)
+}
+
 #[test]
 fn create_identity_works_and_emits_event() {
 	new_test_ext().execute_with(|| {
@@ -35,6 +45,45 @@ fn revoke_identity_works_and_emits_event() {
 	});
 }
 
+#[test]
+fn create_identity_updates_owner_index() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+        assert_ok!(Pallet::<Test>::create_identity(RuntimeOrigin::signed(1)));
+        assert_ok!(Pallet::<Test>::create_identity(RuntimeOrigin::signed(2)));
+        assert_ok!(Pallet::<Test>::create_identity(RuntimeOrigin::signed(1)));
+        assert_eq!(Pallet::<Test>::owned_identities(1).into_inner(), vec![0, 2]);
+        assert_eq!(Pallet::<Test>::owned_identities(2).into_inner(), vec![1]);
+	});
+}
+
+#[test]
+fn revoke_identity_updates_owner_index() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+        assert_ok!(Pallet::<Test>::create_identity(RuntimeOrigin::signed(1)));
+        assert_ok!(Pallet::<Test>::create_identity(RuntimeOrigin::signed(1)));
+        assert_ok!(Pallet::<Test>::revoke_identity(RuntimeOrigin::signed(1), 0));
+        assert_eq!(Pallet::<Test>::owned_identities(1).into_inner(), vec![1]);
+	});
+}
+
+#[test]
+fn create_identity_beyond_owner_cap_fails() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+        let max: u32 = <Test as pallet_identity::Config>::MaxIdentitiesPerOwner::get();
+        for _ in 0..max {
+            assert_ok!(Pallet::<Test>::create_identity(RuntimeOrigin::signed(1)));
+        }
+        assert_noop!(Pallet::<Test>::create_identity(RuntimeOrigin::signed(1)), Error::<Test>::TooManyIdentities);
+        // Revoking frees up a slot for the owner again.
+        assert_ok!(Pallet::<Test>::revoke_identity(RuntimeOrigin::signed(1), 0));
+        assert_ok!(Pallet::<Test>::create_identity(RuntimeOrigin::signed(1)));
+        assert_eq!(Pallet::<Test>::owned_identities(1).len() as u32, max);
+	});
+}
+
 #[test]
 fn revoke_identity_from_non_owner_fails() {
 	new_test_ext().execute_with(|| {
@@ -62,7 +111,8 @@ fn add_or_update_identity_trait_works_and_emits_event() {
         let value = BoundedVec::<u8, MaxSize>::try_from(b"Luke Skywalker".to_vec()).unwrap();
         assert_ok!(Pallet::<Test>::create_identity(RuntimeOrigin::signed(account_id)));
         assert_ok!(Pallet::<Test>::add_or_update_identity_trait(RuntimeOrigin::signed(account_id), 0, key.clone(), value.clone()));
-        System::assert_last_event(Event::IdentityUpdated { identity_id: 0, owner: account_id }.into());
+        let value_hash = <Test as frame_system::Config>::Hashing::hash(&value);
+        System::assert_last_event(Event::IdentityTraitSet { identity_id: 0, key: key.clone(), value_hash }.into());
         assert_eq!(Pallet::<Test>::identity_trait_list(0, key.clone()), value);
 	});
 }
@@ -90,7 +140,7 @@ fn remove_identity_trait_works_and_emits_event() {
         assert_ok!(Pallet::<Test>::create_identity(RuntimeOrigin::signed(account_id)));
         assert_ok!(Pallet::<Test>::add_or_update_identity_trait(RuntimeOrigin::signed(account_id), 0, key.clone(), value.clone()));
         assert_ok!(Pallet::<Test>::remove_identity_trait(RuntimeOrigin::signed(account_id), 0, key.clone()));
-        System::assert_last_event(Event::IdentityUpdated { identity_id: 0, owner: account_id }.into());
+        System::assert_last_event(Event::IdentityTraitRemoved { identity_id: 0, key: key.clone() }.into());
         assert_eq!(Pallet::<Test>::identity_trait_list(0, key), BoundedVec::<u8, <Test as pallet_identity::Config>::MaxSize>::default());
 	});
 }
@@ -145,3 +195,584 @@ fn remove_trait_on_nonexistent_identity_should_fail() {
         assert_noop!(Pallet::<Test>::remove_identity_trait(RuntimeOrigin::signed(1), 99, key), Error::<Test>::IdentityNotOwned);
 	});
 }
+
+#[test]
+fn create_and_revoke_identity_holds_and_releases_base_deposit() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(Pallet::<Test>::create_identity(RuntimeOrigin::signed(1)));
+        assert_eq!(held(1), 10);
+        assert_eq!(Pallet::<Test>::identity_deposit(0), 10);
+        assert_ok!(Pallet::<Test>::revoke_identity(RuntimeOrigin::signed(1), 0));
+        assert_eq!(held(1), 0);
+        assert_eq!(Pallet::<Test>::identity_deposit(0), 0);
+    });
+}
+
+#[test]
+fn create_identity_without_funds_fails() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_noop!(Pallet::<Test>::create_identity(RuntimeOrigin::signed(99)), TokenError::FundsUnavailable);
+    });
+}
+
+#[test]
+fn trait_deposits_track_stored_bytes() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        type MaxSize = <Test as pallet_identity::Config>::MaxSize;
+        let key = BoundedVec::<u8, MaxSize>::try_from(b"name".to_vec()).unwrap();
+        let long = BoundedVec::<u8, MaxSize>::try_from(b"Luke Skywalker".to_vec()).unwrap();
+        let short = BoundedVec::<u8, MaxSize>::try_from(b"Luke".to_vec()).unwrap();
+        assert_ok!(Pallet::<Test>::create_identity(RuntimeOrigin::signed(1)));
+        assert_ok!(Pallet::<Test>::add_or_update_identity_trait(RuntimeOrigin::signed(1), 0, key.clone(), long));
+        assert_eq!(held(1), 10 + 4 + 14);
+        assert_ok!(Pallet::<Test>::add_or_update_identity_trait(RuntimeOrigin::signed(1), 0, key.clone(), short));
+        assert_eq!(held(1), 10 + 4 + 4);
+        assert_ok!(Pallet::<Test>::remove_identity_trait(RuntimeOrigin::signed(1), 0, key));
+        assert_eq!(held(1), 10);
+        assert_eq!(Pallet::<Test>::identity_deposit(0), 10);
+    });
+}
+
+#[test]
+fn revoke_identity_releases_trait_deposits() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        type MaxSize = <Test as pallet_identity::Config>::MaxSize;
+        let key = BoundedVec::<u8, MaxSize>::try_from(b"name".to_vec()).unwrap();
+        let value = BoundedVec::<u8, MaxSize>::try_from(b"Luke Skywalker".to_vec()).unwrap();
+        assert_ok!(Pallet::<Test>::create_identity(RuntimeOrigin::signed(1)));
+        assert_ok!(Pallet::<Test>::add_or_update_identity_trait(RuntimeOrigin::signed(1), 0, key, value));
+        assert_ok!(Pallet::<Test>::revoke_identity(RuntimeOrigin::signed(1), 0));
+        assert_eq!(held(1), 0);
+    });
+}
+
+#[test]
+fn revoke_identity_clears_traits_and_emits_event() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        type MaxSize = <Test as pallet_identity::Config>::MaxSize;
+        let key = BoundedVec::<u8, MaxSize>::try_from(b"name".to_vec()).unwrap();
+        let value = BoundedVec::<u8, MaxSize>::try_from(b"Luke Skywalker".to_vec()).unwrap();
+        assert_ok!(Pallet::<Test>::create_identity(RuntimeOrigin::signed(1)));
+        assert_ok!(Pallet::<Test>::add_or_update_identity_trait(RuntimeOrigin::signed(1), 0, key.clone(), value));
+        assert_ok!(Pallet::<Test>::revoke_identity(RuntimeOrigin::signed(1), 0));
+        System::assert_has_event(Event::IdentityTraitsCleared { identity_id: 0, removed: 1, complete: true }.into());
+        assert!(!IdentityTraitList::<Test>::contains_key(0, key));
+        assert!(Pallet::<Test>::pending_trait_cleanup(0).is_none());
+    });
+}
+
+#[test]
+fn clear_identity_traits_resumes_bounded_cleanup() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        type MaxSize = <Test as pallet_identity::Config>::MaxSize;
+        let value = BoundedVec::<u8, MaxSize>::try_from(b"value".to_vec()).unwrap();
+        assert_ok!(Pallet::<Test>::create_identity(RuntimeOrigin::signed(1)));
+        for key in [b"a", b"b", b"c"] {
+            let key = BoundedVec::<u8, MaxSize>::try_from(key.to_vec()).unwrap();
+            assert_ok!(Pallet::<Test>::add_or_update_identity_trait(RuntimeOrigin::signed(1), 0, key, value.clone()));
+        }
+        assert_eq!(held(1), 10 + 3 * 6);
+        // `MaxTraitRemovals` is two in the mock, so one trait is left behind, and its deposit
+        // and the base deposit stay held.
+        assert_ok!(Pallet::<Test>::revoke_identity(RuntimeOrigin::signed(1), 0));
+        System::assert_has_event(Event::IdentityTraitsCleared { identity_id: 0, removed: 2, complete: false }.into());
+        assert_eq!(IdentityTraitList::<Test>::iter_prefix(0).count(), 1);
+        assert_eq!(Pallet::<Test>::pending_trait_cleanup(0), Some(1));
+        assert_eq!(held(1), 10 + 6);
+        assert_eq!(Pallet::<Test>::identity_deposit(0), 10 + 6);
+
+        assert_ok!(Pallet::<Test>::clear_identity_traits(RuntimeOrigin::signed(2), 0));
+        System::assert_last_event(Event::IdentityTraitsCleared { identity_id: 0, removed: 1, complete: true }.into());
+        assert_eq!(IdentityTraitList::<Test>::iter_prefix(0).count(), 0);
+        assert!(Pallet::<Test>::pending_trait_cleanup(0).is_none());
+        assert_eq!(held(1), 0);
+        assert_eq!(Pallet::<Test>::identity_deposit(0), 0);
+    });
+}
+
+#[test]
+fn clear_identity_traits_without_pending_cleanup_fails() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(Pallet::<Test>::create_identity(RuntimeOrigin::signed(1)));
+        assert_ok!(Pallet::<Test>::revoke_identity(RuntimeOrigin::signed(1), 0));
+        assert_noop!(Pallet::<Test>::clear_identity_traits(RuntimeOrigin::signed(1), 0), Error::<Test>::NoTraitsToClear);
+    });
+}
+
+#[test]
+fn identity_transfer_moves_ownership_and_deposit() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        type MaxSize = <Test as pallet_identity::Config>::MaxSize;
+        let key = BoundedVec::<u8, MaxSize>::try_from(b"name".to_vec()).unwrap();
+        let value = BoundedVec::<u8, MaxSize>::try_from(b"Luke Skywalker".to_vec()).unwrap();
+        assert_ok!(Pallet::<Test>::create_identity(RuntimeOrigin::signed(1)));
+        assert_ok!(Pallet::<Test>::add_or_update_identity_trait(RuntimeOrigin::signed(1), 0, key, value));
+        let deposit = held(1);
+
+        assert_ok!(Pallet::<Test>::propose_identity_transfer(RuntimeOrigin::signed(1), 0, 2));
+        System::assert_last_event(Event::IdentityTransferProposed { identity_id: 0, owner: 1, new_owner: 2 }.into());
+        assert_eq!(Pallet::<Test>::pending_transfer(0), Some(2));
+        // Ownership does not change until the transfer is accepted.
+        assert_eq!(Pallet::<Test>::identity_list(0), Some(1));
+
+        assert_ok!(Pallet::<Test>::accept_identity_transfer(RuntimeOrigin::signed(2), 0));
+        System::assert_last_event(Event::IdentityTransferred { identity_id: 0, old_owner: 1, new_owner: 2 }.into());
+        assert_eq!(Pallet::<Test>::identity_list(0), Some(2));
+        assert!(Pallet::<Test>::pending_transfer(0).is_none());
+        assert!(Pallet::<Test>::owned_identities(1).is_empty());
+        assert_eq!(Pallet::<Test>::owned_identities(2).into_inner(), vec![0]);
+        assert_eq!(held(1), 0);
+        assert_eq!(held(2), deposit);
+        assert_noop!(Pallet::<Test>::revoke_identity(RuntimeOrigin::signed(1), 0), Error::<Test>::IdentityNotOwned);
+    });
+}
+
+#[test]
+fn propose_identity_transfer_checks_owner_and_recipient() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(Pallet::<Test>::create_identity(RuntimeOrigin::signed(1)));
+        assert_noop!(
+            Pallet::<Test>::propose_identity_transfer(RuntimeOrigin::signed(2), 0, 3),
+            Error::<Test>::IdentityNotOwned
+        );
+        assert_noop!(
+            Pallet::<Test>::propose_identity_transfer(RuntimeOrigin::signed(1), 0, 1),
+            Error::<Test>::TransferToSelf
+        );
+    });
+}
+
+#[test]
+fn accept_identity_transfer_requires_pending_recipient() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(Pallet::<Test>::create_identity(RuntimeOrigin::signed(1)));
+        assert_noop!(
+            Pallet::<Test>::accept_identity_transfer(RuntimeOrigin::signed(2), 0),
+            Error::<Test>::NoPendingTransfer
+        );
+        assert_ok!(Pallet::<Test>::propose_identity_transfer(RuntimeOrigin::signed(1), 0, 2));
+        assert_noop!(
+            Pallet::<Test>::accept_identity_transfer(RuntimeOrigin::signed(3), 0),
+            Error::<Test>::NotTransferRecipient
+        );
+    });
+}
+
+#[test]
+fn accept_identity_transfer_beyond_owner_cap_fails() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let max: u32 = <Test as pallet_identity::Config>::MaxIdentitiesPerOwner::get();
+        for _ in 0..max {
+            assert_ok!(Pallet::<Test>::create_identity(RuntimeOrigin::signed(2)));
+        }
+        assert_ok!(Pallet::<Test>::create_identity(RuntimeOrigin::signed(1)));
+        assert_ok!(Pallet::<Test>::propose_identity_transfer(RuntimeOrigin::signed(1), max, 2));
+        assert_noop!(
+            Pallet::<Test>::accept_identity_transfer(RuntimeOrigin::signed(2), max),
+            Error::<Test>::TooManyIdentities
+        );
+    });
+}
+
+#[test]
+fn cancel_identity_transfer_works_and_emits_event() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(Pallet::<Test>::create_identity(RuntimeOrigin::signed(1)));
+        assert_noop!(
+            Pallet::<Test>::cancel_identity_transfer(RuntimeOrigin::signed(1), 0),
+            Error::<Test>::NoPendingTransfer
+        );
+        assert_ok!(Pallet::<Test>::propose_identity_transfer(RuntimeOrigin::signed(1), 0, 2));
+        assert_noop!(
+            Pallet::<Test>::cancel_identity_transfer(RuntimeOrigin::signed(2), 0),
+            Error::<Test>::IdentityNotOwned
+        );
+        assert_ok!(Pallet::<Test>::cancel_identity_transfer(RuntimeOrigin::signed(1), 0));
+        System::assert_last_event(Event::IdentityTransferCancelled { identity_id: 0, owner: 1 }.into());
+        assert_noop!(
+            Pallet::<Test>::accept_identity_transfer(RuntimeOrigin::signed(2), 0),
+            Error::<Test>::NoPendingTransfer
+        );
+    });
+}
+
+#[test]
+fn revoke_identity_drops_pending_transfer() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(Pallet::<Test>::create_identity(RuntimeOrigin::signed(1)));
+        assert_ok!(Pallet::<Test>::propose_identity_transfer(RuntimeOrigin::signed(1), 0, 2));
+        assert_ok!(Pallet::<Test>::revoke_identity(RuntimeOrigin::signed(1), 0));
+        assert!(Pallet::<Test>::pending_transfer(0).is_none());
+        assert_noop!(
+            Pallet::<Test>::accept_identity_transfer(RuntimeOrigin::signed(2), 0),
+            Error::<Test>::NoPendingTransfer
+        );
+    });
+}
+
+fn attestation_fee_held(who: u64) -> u128 {
+    let reason: RuntimeHoldReason = HoldReason::AttestationFee.into();
+    <Balances as InspectHold<u64>>::balance_on_hold(&reason, &who)
+}
+
+fn trait_key(key: &[u8]) -> BoundedVec<u8, <Test as pallet_identity::Config>::MaxSize> {
+    BoundedVec::try_from(key.to_vec()).unwrap()
+}
+
+/// Makes 10 a registrar charging 5, and gives identity 0 of account 1 a `name` trait with an
+/// open attestation request to it.
+fn setup_attestation_request() {
+    assert_ok!(Pallet::<Test>::add_registrar(RuntimeOrigin::root(), 10, 5));
+    assert_ok!(Pallet::<Test>::create_identity(RuntimeOrigin::signed(1)));
+    assert_ok!(Pallet::<Test>::add_or_update_identity_trait(
+        RuntimeOrigin::signed(1),
+        0,
+        trait_key(b"name"),
+        trait_key(b"Luke Skywalker")
+    ));
+    assert_ok!(Pallet::<Test>::request_attestation(RuntimeOrigin::signed(1), 0, 10, trait_key(b"name"), 5));
+}
+
+#[test]
+fn registrars_are_managed_by_privileged_origin() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_noop!(Pallet::<Test>::add_registrar(RuntimeOrigin::signed(1), 10, 5), DispatchError::BadOrigin);
+        assert_ok!(Pallet::<Test>::add_registrar(RuntimeOrigin::root(), 10, 5));
+        System::assert_last_event(Event::RegistrarAdded { registrar: 10, fee: 5 }.into());
+        assert_eq!(Pallet::<Test>::registrar_fee(10), Some(5));
+        // Updating the fee of an existing registrar does not count against the cap.
+        assert_ok!(Pallet::<Test>::add_registrar(RuntimeOrigin::root(), 20, 5));
+        assert_ok!(Pallet::<Test>::add_registrar(RuntimeOrigin::root(), 10, 7));
+        assert_eq!(Pallet::<Test>::registrar_fee(10), Some(7));
+        assert_noop!(Pallet::<Test>::add_registrar(RuntimeOrigin::root(), 42, 5), Error::<Test>::TooManyRegistrars);
+
+        assert_noop!(Pallet::<Test>::remove_registrar(RuntimeOrigin::signed(1), 10), DispatchError::BadOrigin);
+        assert_ok!(Pallet::<Test>::remove_registrar(RuntimeOrigin::root(), 10));
+        System::assert_last_event(Event::RegistrarRemoved { registrar: 10 }.into());
+        assert_noop!(Pallet::<Test>::remove_registrar(RuntimeOrigin::root(), 10), Error::<Test>::NotRegistrar);
+    });
+}
+
+#[test]
+fn provide_judgement_records_attestation_and_pays_registrar() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        setup_attestation_request();
+        assert_eq!(attestation_fee_held(1), 5);
+        let registrar_balance = Balances::free_balance(10);
+        let value_hash = <Test as frame_system::Config>::Hashing::hash(b"Luke Skywalker");
+
+        assert_ok!(Pallet::<Test>::provide_judgement(
+            RuntimeOrigin::signed(10),
+            0,
+            trait_key(b"name"),
+            Judgement::KnownGood,
+            value_hash
+        ));
+        System::assert_last_event(
+            Event::TraitAttested {
+                identity_id: 0,
+                registrar: 10,
+                key: trait_key(b"name"),
+                judgement: Judgement::KnownGood,
+                value_hash,
+            }
+            .into(),
+        );
+        assert_eq!(attestation_fee_held(1), 0);
+        assert_eq!(Balances::free_balance(10), registrar_balance + 5);
+        assert!(Pallet::<Test>::attestation_request(0, (10, trait_key(b"name"))).is_none());
+        let attestations = Pallet::<Test>::attestations(0, trait_key(b"name"));
+        assert_eq!(attestations.len(), 1);
+        assert_eq!(attestations[0].registrar, 10);
+        assert_eq!(attestations[0].judgement, Judgement::KnownGood);
+        assert_eq!(attestations[0].block, 1);
+    });
+}
+
+#[test]
+fn provide_judgement_rejects_stale_value_hash() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        setup_attestation_request();
+        let stale_hash = <Test as frame_system::Config>::Hashing::hash(b"Anakin Skywalker");
+        assert_noop!(
+            Pallet::<Test>::provide_judgement(RuntimeOrigin::signed(10), 0, trait_key(b"name"), Judgement::KnownGood, stale_hash),
+            Error::<Test>::ValueHashMismatch
+        );
+        let value_hash = <Test as frame_system::Config>::Hashing::hash(b"Luke Skywalker");
+        assert_noop!(
+            Pallet::<Test>::provide_judgement(RuntimeOrigin::signed(20), 0, trait_key(b"name"), Judgement::KnownGood, value_hash),
+            Error::<Test>::NotRegistrar
+        );
+    });
+}
+
+#[test]
+fn request_attestation_checks_trait_fee_and_duplicates() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        setup_attestation_request();
+        assert_noop!(
+            Pallet::<Test>::request_attestation(RuntimeOrigin::signed(1), 0, 10, trait_key(b"name"), 5),
+            Error::<Test>::AttestationAlreadyRequested
+        );
+        assert_noop!(
+            Pallet::<Test>::request_attestation(RuntimeOrigin::signed(1), 0, 10, trait_key(b"email"), 5),
+            Error::<Test>::TraitNotFound
+        );
+        assert_noop!(
+            Pallet::<Test>::request_attestation(RuntimeOrigin::signed(1), 0, 20, trait_key(b"name"), 5),
+            Error::<Test>::NotRegistrar
+        );
+        assert_noop!(
+            Pallet::<Test>::request_attestation(RuntimeOrigin::signed(2), 0, 10, trait_key(b"name"), 5),
+            Error::<Test>::IdentityNotOwned
+        );
+        assert_ok!(Pallet::<Test>::add_registrar(RuntimeOrigin::root(), 20, 8));
+        assert_noop!(
+            Pallet::<Test>::request_attestation(RuntimeOrigin::signed(1), 0, 20, trait_key(b"name"), 5),
+            Error::<Test>::FeeTooHigh
+        );
+    });
+}
+
+#[test]
+fn cancel_attestation_request_releases_fee() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        setup_attestation_request();
+        assert_noop!(
+            Pallet::<Test>::cancel_attestation_request(RuntimeOrigin::signed(2), 0, 10, trait_key(b"name")),
+            Error::<Test>::NotAttestationRequester
+        );
+        assert_ok!(Pallet::<Test>::cancel_attestation_request(RuntimeOrigin::signed(1), 0, 10, trait_key(b"name")));
+        System::assert_last_event(
+            Event::AttestationRequestCancelled { identity_id: 0, registrar: 10, key: trait_key(b"name") }.into(),
+        );
+        assert_eq!(attestation_fee_held(1), 0);
+        assert_noop!(
+            Pallet::<Test>::cancel_attestation_request(RuntimeOrigin::signed(1), 0, 10, trait_key(b"name")),
+            Error::<Test>::NoAttestationRequest
+        );
+    });
+}
+
+#[test]
+fn changing_trait_value_invalidates_attestations() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        setup_attestation_request();
+        let value_hash = <Test as frame_system::Config>::Hashing::hash(b"Luke Skywalker");
+        assert_ok!(Pallet::<Test>::provide_judgement(
+            RuntimeOrigin::signed(10),
+            0,
+            trait_key(b"name"),
+            Judgement::Reasonable,
+            value_hash
+        ));
+
+        // Writing the same value again keeps the attestation.
+        assert_ok!(Pallet::<Test>::add_or_update_identity_trait(
+            RuntimeOrigin::signed(1),
+            0,
+            trait_key(b"name"),
+            trait_key(b"Luke Skywalker")
+        ));
+        assert_eq!(Pallet::<Test>::attestations(0, trait_key(b"name")).len(), 1);
+
+        assert_ok!(Pallet::<Test>::add_or_update_identity_trait(
+            RuntimeOrigin::signed(1),
+            0,
+            trait_key(b"name"),
+            trait_key(b"Anakin Skywalker")
+        ));
+        System::assert_has_event(Event::AttestationsInvalidated { identity_id: 0, key: trait_key(b"name") }.into());
+        assert!(Pallet::<Test>::attestations(0, trait_key(b"name")).is_empty());
+    });
+}
+
+#[test]
+fn removing_trait_invalidates_attestations() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        setup_attestation_request();
+        let value_hash = <Test as frame_system::Config>::Hashing::hash(b"Luke Skywalker");
+        assert_ok!(Pallet::<Test>::provide_judgement(
+            RuntimeOrigin::signed(10),
+            0,
+            trait_key(b"name"),
+            Judgement::KnownGood,
+            value_hash
+        ));
+        assert_ok!(Pallet::<Test>::remove_identity_trait(RuntimeOrigin::signed(1), 0, trait_key(b"name")));
+        System::assert_has_event(Event::AttestationsInvalidated { identity_id: 0, key: trait_key(b"name") }.into());
+        assert!(Pallet::<Test>::attestations(0, trait_key(b"name")).is_empty());
+    });
+}
+
+#[test]
+fn traits_of_lists_identity_traits() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(Pallet::<Test>::create_identity(RuntimeOrigin::signed(1)));
+        assert_ok!(Pallet::<Test>::create_identity(RuntimeOrigin::signed(1)));
+        assert_ok!(Pallet::<Test>::add_or_update_identity_trait(
+            RuntimeOrigin::signed(1),
+            0,
+            trait_key(b"name"),
+            trait_key(b"Luke Skywalker")
+        ));
+        assert_ok!(Pallet::<Test>::add_or_update_identity_trait(
+            RuntimeOrigin::signed(1),
+            1,
+            trait_key(b"name"),
+            trait_key(b"Leia Organa")
+        ));
+        assert_eq!(Pallet::<Test>::traits_of(0), vec![(b"name".to_vec(), b"Luke Skywalker".to_vec())]);
+        assert!(Pallet::<Test>::traits_of(2).is_empty());
+    });
+}
+
+#[test]
+fn set_identity_traits_applies_upserts_then_removals() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(Pallet::<Test>::create_identity(RuntimeOrigin::signed(1)));
+        assert_ok!(Pallet::<Test>::add_or_update_identity_trait(
+            RuntimeOrigin::signed(1),
+            0,
+            trait_key(b"email"),
+            trait_key(b"luke@rebellion.org")
+        ));
+        let upserts = BoundedVec::try_from(vec![
+            (trait_key(b"name"), trait_key(b"Luke Skywalker")),
+            (trait_key(b"home"), trait_key(b"Tatooine")),
+        ])
+        .unwrap();
+        let removals = BoundedVec::try_from(vec![trait_key(b"email")]).unwrap();
+        assert_ok!(Pallet::<Test>::set_identity_traits(RuntimeOrigin::signed(1), 0, upserts, removals));
+        let name_hash = <Test as frame_system::Config>::Hashing::hash(b"Luke Skywalker");
+        System::assert_has_event(Event::IdentityTraitSet { identity_id: 0, key: trait_key(b"name"), value_hash: name_hash }.into());
+        System::assert_last_event(Event::IdentityTraitRemoved { identity_id: 0, key: trait_key(b"email") }.into());
+
+        assert_eq!(Pallet::<Test>::identity_trait_list(0, trait_key(b"name")), trait_key(b"Luke Skywalker"));
+        assert_eq!(Pallet::<Test>::identity_trait_list(0, trait_key(b"home")), trait_key(b"Tatooine"));
+        assert!(!IdentityTraitList::<Test>::contains_key(0, trait_key(b"email")));
+        let base: u128 = <Test as pallet_identity::Config>::BaseDeposit::get();
+        assert_eq!(held(1), base + (4 + 14) + (4 + 8));
+    });
+}
+
+#[test]
+fn set_identity_traits_from_non_owner_fails() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(Pallet::<Test>::create_identity(RuntimeOrigin::signed(1)));
+        let upserts = BoundedVec::try_from(vec![(trait_key(b"name"), trait_key(b"Darth Vader"))]).unwrap();
+        assert_noop!(
+            Pallet::<Test>::set_identity_traits(RuntimeOrigin::signed(2), 0, upserts, BoundedVec::default()),
+            Error::<Test>::IdentityNotOwned
+        );
+    });
+}
+
+#[test]
+fn set_identity_traits_is_atomic() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        <Balances as frame_support::traits::fungible::Mutate<u64>>::set_balance(&5, 100);
+        assert_ok!(Pallet::<Test>::create_identity(RuntimeOrigin::signed(5)));
+        // The second upsert needs a larger deposit than account 5 can afford.
+        let upserts = BoundedVec::try_from(vec![
+            (trait_key(b"name"), trait_key(b"Luke Skywalker")),
+            (trait_key(b"bio"), trait_key(&[0u8; 1000])),
+        ])
+        .unwrap();
+        assert_noop!(
+            Pallet::<Test>::set_identity_traits(RuntimeOrigin::signed(5), 0, upserts, BoundedVec::default()),
+            TokenError::FundsUnavailable
+        );
+    });
+}
+
+#[test]
+fn remove_missing_identity_trait_emits_no_event() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(Pallet::<Test>::create_identity(RuntimeOrigin::signed(1)));
+        assert_ok!(Pallet::<Test>::remove_identity_trait(RuntimeOrigin::signed(1), 0, trait_key(b"name")));
+        System::assert_last_event(Event::IdentityCreated { identity_id: 0, owner: 1 }.into());
+    });
+}
+
+#[test]
+fn migrate_v0_to_v1_backfills_owner_index() {
+    new_test_ext().execute_with(|| {
+        IdentityList::<Test>::insert(0, 1);
+        IdentityList::<Test>::insert(1, 2);
+        IdentityList::<Test>::insert(2, 1);
+        StorageVersion::new(0).put::<Pallet<Test>>();
+
+        migrations::v1::MigrateV0ToV1::<Test>::on_runtime_upgrade();
+
+        let mut owned = Pallet::<Test>::owned_identities(1).into_inner();
+        owned.sort();
+        assert_eq!(owned, vec![0, 2]);
+        assert_eq!(Pallet::<Test>::owned_identities(2).into_inner(), vec![1]);
+        assert_eq!(Pallet::<Test>::on_chain_storage_version(), StorageVersion::new(1));
+
+        // Running it again is a no-op once the version has been bumped.
+        OwnedIdentities::<Test>::remove(2);
+        migrations::v1::MigrateV0ToV1::<Test>::on_runtime_upgrade();
+        assert!(Pallet::<Test>::owned_identities(2).is_empty());
+    });
+}
+
+#[test]
+fn migrate_v0_to_v1_leaves_identities_past_the_limit_out_of_the_index() {
+    new_test_ext().execute_with(|| {
+        // `MaxIdentitiesPerOwner` is 5 in the mock.
+        for identity_id in 0..7 {
+            IdentityList::<Test>::insert(identity_id, 1);
+        }
+        IdentityList::<Test>::insert(7, 2);
+        StorageVersion::new(0).put::<Pallet<Test>>();
+
+        migrations::v1::MigrateV0ToV1::<Test>::on_runtime_upgrade();
+
+        let owned = Pallet::<Test>::owned_identities(1);
+        assert_eq!(owned.len(), 5);
+        assert!(owned.iter().all(|identity_id| *identity_id < 7));
+        assert_eq!(Pallet::<Test>::owned_identities(2).into_inner(), vec![7]);
+        // The identities left out of the index are still owned.
+        assert!((0..7).all(|identity_id| IdentityList::<Test>::get(identity_id) == Some(1)));
+    });
+}
+
+#[test]
+fn migrate_v1_to_v2_only_bumps_the_version() {
+    new_test_ext().execute_with(|| {
+        IdentityList::<Test>::insert(0, 1);
+        StorageVersion::new(1).put::<Pallet<Test>>();
+
+        migrations::v2::MigrateV1ToV2::<Test>::on_runtime_upgrade();
+
+        assert_eq!(Pallet::<Test>::on_chain_storage_version(), StorageVersion::new(2));
+        assert_eq!(Pallet::<Test>::identity_list(0), Some(1));
+    });
+}
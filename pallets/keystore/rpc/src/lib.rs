@@ -0,0 +1,114 @@
+//! RPC interface for the Fennel keystore pallet.
+
+use std::sync::Arc;
+
+use codec::Codec;
+use jsonrpsee::{
+    core::RpcResult,
+    proc_macros::rpc,
+    types::{error::ErrorObject, ErrorObjectOwned},
+};
+use pallet_keystore_runtime_api::{EncryptionKey, EncryptionKeyType};
+use serde::{Deserialize, Serialize};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_core::Bytes;
+use sp_runtime::traits::Block as BlockT;
+
+pub use pallet_keystore_runtime_api::KeystoreApi as KeystoreRuntimeApi;
+
+/// An encryption key as returned over RPC.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EncryptionKeyInfo<BlockNumber> {
+    /// The algorithm the key is meant for: `x25519`, `sr25519` or `ed25519`.
+    pub key_type: String,
+    /// The public key itself.
+    pub key: Bytes,
+    /// The block in which the key was issued.
+    pub created: BlockNumber,
+}
+
+impl<BlockNumber> From<EncryptionKey<BlockNumber>> for EncryptionKeyInfo<BlockNumber> {
+    fn from(issued: EncryptionKey<BlockNumber>) -> Self {
+        let key_type = match issued.key_type {
+            EncryptionKeyType::X25519 => "x25519",
+            EncryptionKeyType::Sr25519 => "sr25519",
+            EncryptionKeyType::Ed25519 => "ed25519",
+        };
+        Self { key_type: key_type.into(), key: issued.key.to_vec().into(), created: issued.created }
+    }
+}
+
+/// Keystore RPC methods.
+#[rpc(client, server)]
+pub trait KeystoreApi<BlockHash, AccountId, BlockNumber> {
+    /// Returns the fingerprints and locations of the currently valid keys an account has
+    /// announced.
+    #[method(name = "keystore_keys")]
+    fn keys(&self, who: AccountId, at: Option<BlockHash>) -> RpcResult<Vec<(Bytes, Bytes)>>;
+
+    /// Returns the accounts whose announcement of a fingerprint is currently valid.
+    #[method(name = "keystore_keyOwners")]
+    fn key_owners(&self, fingerprint: Bytes, at: Option<BlockHash>) -> RpcResult<Vec<AccountId>>;
+
+    /// Returns the encryption key issued by an account, if any.
+    #[method(name = "keystore_encryptionKey")]
+    fn encryption_key(
+        &self,
+        who: AccountId,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Option<EncryptionKeyInfo<BlockNumber>>>;
+}
+
+/// Provides RPC methods to query announced keys.
+pub struct Keystore<C, B> {
+    /// Shared reference to the client.
+    client: Arc<C>,
+    _marker: std::marker::PhantomData<B>,
+}
+
+impl<C, B> Keystore<C, B> {
+    /// Creates a new instance of the Keystore RPC helper.
+    pub fn new(client: Arc<C>) -> Self {
+        Self { client, _marker: Default::default() }
+    }
+}
+
+/// Error code returned when the runtime API call fails.
+const RUNTIME_ERROR: i32 = 1;
+
+fn runtime_error(error: impl ToString) -> ErrorObjectOwned {
+    ErrorObject::owned(RUNTIME_ERROR, "Unable to query keystore.", Some(error.to_string()))
+}
+
+impl<C, Block, AccountId, BlockNumber> KeystoreApiServer<<Block as BlockT>::Hash, AccountId, BlockNumber>
+    for Keystore<C, Block>
+where
+    Block: BlockT,
+    C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+    C::Api: KeystoreRuntimeApi<Block, AccountId, BlockNumber>,
+    AccountId: Codec + Send + Sync + 'static,
+    BlockNumber: Codec + Serialize + Send + Sync + 'static,
+{
+    fn keys(&self, who: AccountId, at: Option<Block::Hash>) -> RpcResult<Vec<(Bytes, Bytes)>> {
+        let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+        let keys = self.client.runtime_api().keys(at_hash, who).map_err(runtime_error)?;
+        Ok(keys.into_iter().map(|(fingerprint, location)| (fingerprint.into(), location.into())).collect())
+    }
+
+    fn key_owners(&self, fingerprint: Bytes, at: Option<Block::Hash>) -> RpcResult<Vec<AccountId>> {
+        let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+        self.client.runtime_api().key_owners(at_hash, fingerprint.to_vec()).map_err(runtime_error)
+    }
+
+    fn encryption_key(
+        &self,
+        who: AccountId,
+        at: Option<Block::Hash>,
+    ) -> RpcResult<Option<EncryptionKeyInfo<BlockNumber>>> {
+        let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+        let issued = self.client.runtime_api().encryption_key(at_hash, who).map_err(runtime_error)?;
+        Ok(issued.map(Into::into))
+    }
+}
@@ -0,0 +1,28 @@
+//! Runtime API definition for the Fennel keystore pallet.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+use codec::Codec;
+
+pub use pallet_keystore::{EncryptionKey, EncryptionKeyType};
+
+sp_api::decl_runtime_apis! {
+    /// Read access to announced keys without building raw storage keys.
+    pub trait KeystoreApi<AccountId, BlockNumber>
+    where
+        AccountId: Codec,
+        BlockNumber: Codec,
+    {
+        /// Returns the fingerprints and locations of the keys `who` has announced that are valid
+        /// in the current block.
+        fn keys(who: AccountId) -> Vec<(Vec<u8>, Vec<u8>)>;
+        /// Returns the accounts whose announcement of `fingerprint` is valid in the current
+        /// block.
+        fn key_owners(fingerprint: Vec<u8>) -> Vec<AccountId>;
+        /// Returns the encryption key issued by `who`, if any.
+        fn encryption_key(who: AccountId) -> Option<EncryptionKey<BlockNumber>>;
+    }
+}
@@ -1,11 +1,54 @@
 #![cfg(feature = "runtime-benchmarks")]
 
 use super::*;
+use fennel_expiry::MAX_EXPIRY_DELAY;
 use frame_benchmarking::v2::*;
-use frame_system::RawOrigin;
+use frame_support::traits::Get;
+use frame_system::{pallet_prelude::BlockNumberFor, RawOrigin};
 use sp_runtime::BoundedVec;
 use scale_info::prelude::{format, vec};
 
+/// The Curve25519 base point, a valid X25519 public key.
+const X25519_BASE_POINT: [u8; 32] = {
+    let mut point = [0u8; 32];
+    point[0] = 9;
+    point
+};
+
+/// Fills the expiry bucket of each block in `blocks`, leaving `room` places free in each.
+fn fill_expiries<T: Config>(
+    blocks: impl Iterator<Item = BlockNumberFor<T>>,
+    room: u32,
+) -> Result<(), BenchmarkError> {
+    let filler: T::AccountId = account("filler", 0, 0);
+    let fingerprint = BoundedVec::<u8, T::MaxSize>::try_from(vec![0u8; T::MaxSize::get() as usize])
+        .map_err(|_| BenchmarkError::Stop("Failed to create filler fingerprint"))?;
+    let count = T::MaxExpiriesPerBlock::get().saturating_sub(room) as usize;
+    for block in blocks {
+        KeyExpiries::<T>::insert(block, BoundedVec::truncate_from(vec![(filler.clone(), fingerprint.clone()); count]));
+    }
+    Ok(())
+}
+
+/// Announces a key for `caller` that expires in a block with one place left, so revoking it has
+/// to search a full bucket.
+fn announce_expiring_key<T: Config>(
+    caller: &T::AccountId,
+    fingerprint: BoundedVec<u8, T::MaxSize>,
+    location: BoundedVec<u8, T::MaxSize>,
+) -> Result<(), BenchmarkError> {
+    let valid_until = frame_system::Pallet::<T>::block_number() + 10u32.into();
+    fill_expiries::<T>(core::iter::once(valid_until), 1)?;
+    Pallet::<T>::announce_key_with_validity(
+        RawOrigin::Signed(caller.clone()).into(),
+        fingerprint,
+        location,
+        None,
+        Some(valid_until),
+    )?;
+    Ok(())
+}
+
 #[benchmarks(
     where
         <T as frame_system::Config>::RuntimeEvent: From<Event<T>>,
@@ -132,12 +175,8 @@ mod benchmarks {
         let location = BoundedVec::<u8, T::MaxSize>::try_from(vec![0u8; 32])
             .map_err(|_| BenchmarkError::Stop("Failed to create location"))?;
         
-        // Pre-insert key
-        Pallet::<T>::announce_key(
-            RawOrigin::Signed(caller.clone()).into(),
-            fingerprint.clone(),
-            location
-        )?;
+        // Pre-insert a key with a scheduled expiry, which revoking has to clear.
+        announce_expiring_key::<T>(&caller, fingerprint.clone(), location)?;
         
 		#[extrinsic_call]
         revoke_key(RawOrigin::Signed(caller.clone()), fingerprint.clone());
@@ -194,11 +233,13 @@ mod benchmarks {
 	#[benchmark]
     fn issue_encryption_key() -> Result<(), BenchmarkError> {
         let caller: T::AccountId = whitelisted_caller();
-        let key = [0u8; 32];
+        let key = X25519_BASE_POINT;
+        // Replacing an existing key is the more expensive path.
+        Pallet::<T>::issue_encryption_key(RawOrigin::Signed(caller.clone()).into(), EncryptionKeyType::X25519, key)?;
 		#[extrinsic_call]
-        issue_encryption_key(RawOrigin::Signed(caller.clone()), key);
+        issue_encryption_key(RawOrigin::Signed(caller.clone()), EncryptionKeyType::X25519, key);
         // Storage and event check
-        assert_eq!(IssuedEncryptionKeys::<T>::get(&caller), Some(key));
+        assert_eq!(IssuedEncryptionKeys::<T>::get(&caller).map(|issued| issued.key), Some(key));
         frame_system::Pallet::<T>::assert_last_event(Event::EncryptionKeyIssued { who: caller }.into());
         Ok(())
 	}
@@ -208,26 +249,188 @@ mod benchmarks {
         let caller: T::AccountId = whitelisted_caller();
         
         // Override the encryption key 100,000 times as in the original benchmark
-        for i in 0..100_000 {
-            let temp_key = [i as u8 % 255; 32];
+        for _ in 0..100_000 {
             Pallet::<T>::issue_encryption_key(
                 RawOrigin::Signed(caller.clone()).into(),
-                temp_key
+                EncryptionKeyType::X25519,
+                X25519_BASE_POINT
             )?;
         }
         
         // Now issue the final key we'll measure
-        let final_key = [0u8; 32];
+        let final_key = X25519_BASE_POINT;
         
         #[extrinsic_call]
-        issue_encryption_key(RawOrigin::Signed(caller.clone()), final_key);
+        issue_encryption_key(RawOrigin::Signed(caller.clone()), EncryptionKeyType::X25519, final_key);
         
         // Verify the key was stored
-        assert_eq!(IssuedEncryptionKeys::<T>::get(&caller), Some(final_key));
+        assert_eq!(IssuedEncryptionKeys::<T>::get(&caller).map(|issued| issued.key), Some(final_key));
         frame_system::Pallet::<T>::assert_last_event(Event::EncryptionKeyIssued { who: caller }.into());
         
         Ok(())
     }
 
+    #[benchmark]
+    fn announce_key_with_validity() -> Result<(), BenchmarkError> {
+        let caller: T::AccountId = whitelisted_caller();
+        let fingerprint = BoundedVec::<u8, T::MaxSize>::try_from(b"fingerprint".to_vec())
+            .map_err(|_| BenchmarkError::Stop("Failed to create fingerprint"))?;
+        let location = BoundedVec::<u8, T::MaxSize>::try_from(b"location".to_vec())
+            .map_err(|_| BenchmarkError::Stop("Failed to create location"))?;
+        let valid_until = frame_system::Pallet::<T>::block_number() + 10u32.into();
+        // Fill every block but the last one the expiry can be pushed back to.
+        let last = valid_until + (MAX_EXPIRY_DELAY - 1).into();
+        fill_expiries::<T>((0..MAX_EXPIRY_DELAY - 1).map(|delay| valid_until + delay.into()), 0)?;
+
+        #[extrinsic_call]
+        announce_key_with_validity(
+            RawOrigin::Signed(caller.clone()),
+            fingerprint.clone(),
+            location.clone(),
+            None,
+            Some(valid_until),
+        );
+
+        assert_eq!(IssuedKeys::<T>::get(&caller, &fingerprint), Some(location));
+        assert_eq!(ScheduledExpiries::<T>::get(&caller, &fingerprint), Some(last));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn expire_key() -> Result<(), BenchmarkError> {
+        let caller: T::AccountId = whitelisted_caller();
+        let fingerprint = BoundedVec::<u8, T::MaxSize>::try_from(vec![0u8; 1000])
+            .map_err(|_| BenchmarkError::Stop("Failed to create fingerprint - MaxSize might be too small"))?;
+        let location = BoundedVec::<u8, T::MaxSize>::try_from(vec![1u8; 1000])
+            .map_err(|_| BenchmarkError::Stop("Failed to create location - MaxSize might be too small"))?;
+        let valid_until = frame_system::Pallet::<T>::block_number() + 1u32.into();
+        Pallet::<T>::announce_key_with_validity(
+            RawOrigin::Signed(caller.clone()).into(),
+            fingerprint.clone(),
+            location,
+            None,
+            Some(valid_until),
+        )?;
+
+        #[block]
+        {
+            Pallet::<T>::expire_key(&caller, fingerprint.clone(), valid_until);
+        }
+
+        assert_eq!(IssuedKeys::<T>::get(&caller, &fingerprint), None);
+        frame_system::Pallet::<T>::assert_last_event(Event::KeyExpired { key: fingerprint, who: caller }.into());
+        Ok(())
+    }
+
+    #[benchmark]
+    fn rotate_key() -> Result<(), BenchmarkError> {
+        let caller: T::AccountId = whitelisted_caller();
+        let fingerprint = BoundedVec::<u8, T::MaxSize>::try_from(vec![0u8; 1000])
+            .map_err(|_| BenchmarkError::Stop("Failed to create fingerprint - MaxSize might be too small"))?;
+        let new_fingerprint = BoundedVec::<u8, T::MaxSize>::try_from(vec![1u8; 1000])
+            .map_err(|_| BenchmarkError::Stop("Failed to create new fingerprint - MaxSize might be too small"))?;
+        let location = BoundedVec::<u8, T::MaxSize>::try_from(vec![2u8; 1000])
+            .map_err(|_| BenchmarkError::Stop("Failed to create location - MaxSize might be too small"))?;
+        announce_expiring_key::<T>(&caller, fingerprint.clone(), location.clone())?;
+
+        #[extrinsic_call]
+        rotate_key(RawOrigin::Signed(caller.clone()), fingerprint.clone(), new_fingerprint.clone(), location.clone());
+
+        assert_eq!(IssuedKeys::<T>::get(&caller, &new_fingerprint), Some(location));
+        assert_eq!(
+            RevokedKeys::<T>::get(&caller, &fingerprint).map(|revocation| revocation.reason),
+            Some(RevocationReason::Superseded)
+        );
+        frame_system::Pallet::<T>::assert_last_event(
+            Event::KeyRotated { old_key: fingerprint, new_key: new_fingerprint, who: caller }.into(),
+        );
+        Ok(())
+    }
+
+    #[benchmark]
+    fn report_compromised_key() -> Result<(), BenchmarkError> {
+        let caller: T::AccountId = whitelisted_caller();
+        let fingerprint = BoundedVec::<u8, T::MaxSize>::try_from(vec![0u8; 1000])
+            .map_err(|_| BenchmarkError::Stop("Failed to create fingerprint - MaxSize might be too small"))?;
+        let location = BoundedVec::<u8, T::MaxSize>::try_from(vec![1u8; 1000])
+            .map_err(|_| BenchmarkError::Stop("Failed to create location - MaxSize might be too small"))?;
+        announce_expiring_key::<T>(&caller, fingerprint.clone(), location)?;
+
+        #[extrinsic_call]
+        report_compromised_key(RawOrigin::Signed(caller.clone()), fingerprint.clone());
+
+        assert_eq!(IssuedKeys::<T>::get(&caller, &fingerprint), None);
+        assert_eq!(
+            RevokedKeys::<T>::get(&caller, &fingerprint).map(|revocation| revocation.reason),
+            Some(RevocationReason::Compromised)
+        );
+        frame_system::Pallet::<T>::assert_last_event(Event::KeyCompromised { key: fingerprint, who: caller }.into());
+        Ok(())
+    }
+
+    #[benchmark]
+    fn forget_revoked_key() -> Result<(), BenchmarkError> {
+        let caller: T::AccountId = whitelisted_caller();
+        let fingerprint = BoundedVec::<u8, T::MaxSize>::try_from(vec![0u8; 1000])
+            .map_err(|_| BenchmarkError::Stop("Failed to create fingerprint - MaxSize might be too small"))?;
+        let location = BoundedVec::<u8, T::MaxSize>::try_from(vec![1u8; 1000])
+            .map_err(|_| BenchmarkError::Stop("Failed to create location - MaxSize might be too small"))?;
+        Pallet::<T>::announce_key(RawOrigin::Signed(caller.clone()).into(), fingerprint.clone(), location)?;
+        Pallet::<T>::revoke_key(RawOrigin::Signed(caller.clone()).into(), fingerprint.clone())?;
+
+        #[extrinsic_call]
+        forget_revoked_key(RawOrigin::Signed(caller.clone()), fingerprint.clone());
+
+        assert_eq!(RevokedKeys::<T>::get(&caller, &fingerprint), None);
+        assert_eq!(RevokedKeyCount::<T>::get(&caller), 0);
+        frame_system::Pallet::<T>::assert_last_event(Event::RevocationForgotten { key: fingerprint, who: caller }.into());
+        Ok(())
+    }
+
+    #[benchmark]
+    fn revoke_encryption_key() -> Result<(), BenchmarkError> {
+        let caller: T::AccountId = whitelisted_caller();
+        Pallet::<T>::issue_encryption_key(
+            RawOrigin::Signed(caller.clone()).into(),
+            EncryptionKeyType::X25519,
+            X25519_BASE_POINT,
+        )?;
+
+        #[extrinsic_call]
+        revoke_encryption_key(RawOrigin::Signed(caller.clone()));
+
+        assert_eq!(IssuedEncryptionKeys::<T>::get(&caller), None);
+        frame_system::Pallet::<T>::assert_last_event(
+            Event::EncryptionKeyRevoked { who: caller, key: X25519_BASE_POINT }.into(),
+        );
+        Ok(())
+    }
+
+    #[benchmark]
+    fn announce_key_with_proof() -> Result<(), BenchmarkError> {
+        let caller: T::AccountId = whitelisted_caller();
+        // ECDSA is the most expensive signature scheme to verify.
+        let public = sp_io::crypto::ecdsa_generate(sp_core::crypto::key_types::DUMMY, None);
+        let payload = Pallet::<T>::proof_payload(&caller, 0);
+        let signature = sp_io::crypto::ecdsa_sign(sp_core::crypto::key_types::DUMMY, &public, &payload)
+            .ok_or(BenchmarkError::Stop("Failed to sign proof"))?;
+        let fingerprint = BoundedVec::<u8, T::MaxSize>::try_from(public.0.to_vec())
+            .map_err(|_| BenchmarkError::Stop("Failed to create fingerprint"))?;
+        let location = BoundedVec::<u8, T::MaxSize>::try_from(vec![1u8; 1000])
+            .map_err(|_| BenchmarkError::Stop("Failed to create location - MaxSize might be too small"))?;
+
+        #[extrinsic_call]
+        announce_key_with_proof(
+            RawOrigin::Signed(caller.clone()),
+            fingerprint.clone(),
+            location.clone(),
+            KeyProof::Ecdsa { public, signature },
+        );
+
+        assert_eq!(IssuedKeys::<T>::get(&caller, &fingerprint), Some(location));
+        assert!(Pallet::<T>::is_key_proven(&caller, &fingerprint));
+        Ok(())
+    }
+
 	impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Test);
 }
@@ -9,8 +9,11 @@ mod tests;
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
 
+pub mod migrations;
 pub mod weights;
 
+extern crate alloc;
+
 pub use pallet::*;
 pub use weights::*;
 
@@ -18,6 +21,13 @@ pub use weights::*;
 pub mod pallet {
     use frame_support::{dispatch::DispatchResultWithPostInfo, pallet_prelude::*};
 	use frame_system::pallet_prelude::*;
+	use alloc::vec::Vec;
+	use curve25519_dalek::{
+		edwards::CompressedEdwardsY, montgomery::MontgomeryPoint, ristretto::CompressedRistretto,
+	};
+	use fennel_expiry::ExpiryQueue;
+	use sp_core::{ecdsa, ed25519, sr25519};
+	use sp_runtime::traits::Zero;
 
 	use crate::weights::WeightInfo;
 
@@ -29,9 +39,145 @@ pub mod pallet {
 		type WeightInfo: WeightInfo;
 		/// The maximum size of a key.
 		type MaxSize: Get<u32>;
+		/// The maximum number of keys that can be scheduled to expire in the same block.
+		#[pallet::constant]
+		type MaxExpiriesPerBlock: Get<u32>;
+		/// The maximum number of revocation records an account can keep in `RevokedKeys`.
+		#[pallet::constant]
+		type MaxRevokedKeys: Get<u32>;
+		/// The maximum number of keys an account can report as compromised. Their records are
+		/// kept for good, so this bounds them separately from `MaxRevokedKeys`.
+		#[pallet::constant]
+		type MaxCompromisedKeys: Get<u32>;
+	}
+
+	/// The blocks between which an announced key is considered valid.
+	#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub struct ValidityPeriod<BlockNumber> {
+		/// The first block in which the key is valid, if it is not valid straight away.
+		pub valid_from: Option<BlockNumber>,
+		/// The block in which the key expires, if it ever does.
+		pub valid_until: Option<BlockNumber>,
+	}
+
+	/// Why a key was taken out of circulation.
+	#[derive(Encode, Decode, DecodeWithMemTracking, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub enum RevocationReason {
+		/// The key was replaced by a successor through `rotate_key`.
+		Superseded,
+		/// The key's private half was exposed. Signatures made with it should not be trusted,
+		/// including ones made before it was revoked.
+		Compromised,
+		/// The key was retired without a replacement.
+		Retired,
+	}
+
+	/// What is kept about a key after it has been revoked.
+	#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub struct Revocation<Fingerprint, BlockNumber> {
+		/// Why the key was revoked.
+		pub reason: RevocationReason,
+		/// The fingerprint of the key that replaced it, if any.
+		pub successor: Option<Fingerprint>,
+		/// The block in which the key was revoked.
+		pub block: BlockNumber,
+	}
+
+	/// The revocation record of a key announced by an account of runtime `T`.
+	pub type RevocationOf<T> = Revocation<BoundedVec<u8, <T as Config>::MaxSize>, BlockNumberFor<T>>;
+
+	/// The algorithm an encryption key is meant to be used with.
+	#[derive(Encode, Decode, DecodeWithMemTracking, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub enum EncryptionKeyType {
+		/// A Curve25519 Diffie-Hellman key, given as its Montgomery u-coordinate.
+		X25519,
+		/// A Ristretto-encoded Schnorr key, as used by sr25519.
+		Sr25519,
+		/// A compressed Edwards point, as used by ed25519.
+		Ed25519,
+	}
+
+	impl EncryptionKeyType {
+		/// Whether `key` encodes a point on the curve this key type uses. Points of small order,
+		/// which would leak or fix any shared secret derived from them, are rejected as well.
+		pub fn is_valid_key(&self, key: &[u8; 32]) -> bool {
+			match self {
+				Self::X25519 =>
+					MontgomeryPoint(*key).to_edwards(0).is_some_and(|point| !point.is_small_order()),
+				// Ristretto has no small subgroup, only the identity needs ruling out.
+				Self::Sr25519 => *key != [0u8; 32] && CompressedRistretto(*key).decompress().is_some(),
+				Self::Ed25519 =>
+					CompressedEdwardsY(*key).decompress().is_some_and(|point| !point.is_small_order()),
+			}
+		}
+	}
+
+	/// A signature by an announced key over the announcing account, the genesis hash and the
+	/// account's proof nonce, showing that the account holds the key.
+	#[derive(Encode, Decode, DecodeWithMemTracking, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub enum KeyProof {
+		/// An sr25519 public key and signature.
+		Sr25519 { public: sr25519::Public, signature: sr25519::Signature },
+		/// An ed25519 public key and signature.
+		Ed25519 { public: ed25519::Public, signature: ed25519::Signature },
+		/// A compressed secp256k1 public key and ECDSA signature.
+		Ecdsa { public: ecdsa::Public, signature: ecdsa::Signature },
+	}
+
+	impl KeyProof {
+		/// The encoded public key, which is the fingerprint the key is announced under.
+		pub fn public_key(&self) -> &[u8] {
+			match self {
+				Self::Sr25519 { public, .. } => public.as_ref(),
+				Self::Ed25519 { public, .. } => public.as_ref(),
+				Self::Ecdsa { public, .. } => public.as_ref(),
+			}
+		}
+
+		/// Whether the signature is a valid signature of `message` by the public key.
+		pub fn verify(&self, message: &[u8]) -> bool {
+			match self {
+				Self::Sr25519 { public, signature } => sp_io::crypto::sr25519_verify(signature, message, public),
+				Self::Ed25519 { public, signature } => sp_io::crypto::ed25519_verify(signature, message, public),
+				Self::Ecdsa { public, signature } => sp_io::crypto::ecdsa_verify(signature, message, public),
+			}
+		}
+	}
+
+	/// An encryption key issued by an account.
+	#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub struct EncryptionKey<BlockNumber> {
+		/// The algorithm the key is meant to be used with.
+		pub key_type: EncryptionKeyType,
+		/// The public key itself.
+		pub key: [u8; 32],
+		/// The block in which the key was issued.
+		pub created: BlockNumber,
+	}
+
+	/// The encryption key record of runtime `T`.
+	pub type EncryptionKeyOf<T> = EncryptionKey<BlockNumberFor<T>>;
+
+	/// A key scheduled to expire, identified by the announcing account and the key fingerprint.
+	pub type ExpiringKeyOf<T> = (<T as frame_system::Config>::AccountId, BoundedVec<u8, <T as Config>::MaxSize>);
+
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
+
+	/// The `KeyExpiries` queue swept in idle time.
+	pub struct KeyExpiryQueue<T>(PhantomData<T>);
+
+	impl<T: Config> ExpiryQueue for KeyExpiryQueue<T> {
+		type BlockNumber = BlockNumberFor<T>;
+		type Item = ExpiringKeyOf<T>;
+		type MaxPerBlock = T::MaxExpiriesPerBlock;
+		type Buckets = KeyExpiries<T>;
+		type Pending = PendingExpiries<T>;
+		type Cursor = ExpiryCursor<T>;
+		type DbWeight = T::DbWeight;
 	}
 
 	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
 	#[pallet::storage]
@@ -47,11 +193,116 @@ pub mod pallet {
 		BoundedVec<u8, T::MaxSize>,
 	>;
 
+	#[pallet::storage]
+	/// Reverse index of `IssuedKeys`, mapping a fingerprint to every account that has announced
+	/// it and not revoked it.
+	pub type KeyOwners<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		BoundedVec<u8, T::MaxSize>,
+		Blake2_128Concat,
+		T::AccountId,
+		(),
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn key_validity)]
+	/// Maps announced keys to the period in which they are valid. Keys without an entry are
+	/// valid from the moment they are announced until they are revoked.
+	pub type KeyValidity<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		Blake2_128Concat,
+		BoundedVec<u8, T::MaxSize>,
+		ValidityPeriod<BlockNumberFor<T>>,
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn key_expiries)]
+	/// Maps blocks to the keys that expire in them.
+	pub type KeyExpiries<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		BlockNumberFor<T>,
+		BoundedVec<ExpiringKeyOf<T>, T::MaxExpiriesPerBlock>,
+		ValueQuery,
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn scheduled_expiry)]
+	/// Maps announced keys to the block whose `KeyExpiries` entry holds them. Each expiring key
+	/// is scheduled exactly once.
+	pub type ScheduledExpiries<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		Blake2_128Concat,
+		BoundedVec<u8, T::MaxSize>,
+		BlockNumberFor<T>,
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn pending_expiries)]
+	/// The number of keys held in `KeyExpiries` that have not been swept yet.
+	pub type PendingExpiries<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn expiry_cursor)]
+	/// The next block whose expiring keys have not been swept yet, while any are pending.
+	pub type ExpiryCursor<T: Config> = StorageValue<_, BlockNumberFor<T>>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn revocation)]
+	/// Maps keys that have been revoked to the reason and block of their revocation. Records are
+	/// kept after the key itself is removed so signatures can still be checked against them, and
+	/// a revoked fingerprint cannot be announced again by the same account until its record is
+	/// forgotten.
+	pub type RevokedKeys<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		Blake2_128Concat,
+		BoundedVec<u8, T::MaxSize>,
+		RevocationOf<T>,
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn revoked_key_count)]
+	/// The number of records each account has in `RevokedKeys`, capped at `MaxRevokedKeys`.
+	/// Records of compromised keys are kept for good and are counted in `CompromisedKeyCount`
+	/// instead.
+	pub type RevokedKeyCount<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, u32, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn compromised_key_count)]
+	/// The number of records of compromised keys each account has in `RevokedKeys`, capped at
+	/// `MaxCompromisedKeys`.
+	pub type CompromisedKeyCount<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, u32, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn proven_key)]
+	/// Maps keys announced with a proof of possession to the block in which they were proven.
+	pub type ProvenKeys<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		Blake2_128Concat,
+		BoundedVec<u8, T::MaxSize>,
+		BlockNumberFor<T>,
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn proof_nonce)]
+	/// The nonce an account's next proof of possession has to sign. It goes up with every
+	/// proven announcement, so a proof cannot be replayed.
+	pub type ProofNonces<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, u64, ValueQuery>;
+
 	#[pallet::storage]
 	#[pallet::getter(fn encryption_key)]
-	/// Maps an account to an encryption key that they've issued.
+	/// Maps an account to the encryption key that they've issued.
 	pub type IssuedEncryptionKeys<T: Config> =
-		StorageMap<_, Blake2_128Concat, T::AccountId, [u8; 32]>;
+		StorageMap<_, Blake2_128Concat, T::AccountId, EncryptionKeyOf<T>>;
 
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
@@ -62,6 +313,16 @@ pub mod pallet {
         KeyRevoked { key: BoundedVec<u8, T::MaxSize>, who: T::AccountId },
         /// Announce that an encryption key was issued.
         EncryptionKeyIssued { who: T::AccountId },
+		/// Announce that a key reached the end of its validity period and was removed.
+		KeyExpired { key: BoundedVec<u8, T::MaxSize>, who: T::AccountId },
+		/// Announce that a key was replaced by a successor.
+		KeyRotated { old_key: BoundedVec<u8, T::MaxSize>, new_key: BoundedVec<u8, T::MaxSize>, who: T::AccountId },
+		/// Announce that a key was reported as compromised.
+		KeyCompromised { key: BoundedVec<u8, T::MaxSize>, who: T::AccountId },
+		/// Announce that the revocation record of a key was removed.
+		RevocationForgotten { key: BoundedVec<u8, T::MaxSize>, who: T::AccountId },
+		/// Announce that an encryption key was withdrawn, either explicitly or by issuing a new one.
+		EncryptionKeyRevoked { who: T::AccountId, key: [u8; 32] },
 	}
 
 	#[pallet::error]
@@ -70,6 +331,187 @@ pub mod pallet {
 		KeyExists,
 		/// The specified key does not exist.
 		KeyDoesNotExist,
+		/// The validity period ends before it starts or has already ended.
+		InvalidValidityPeriod,
+		/// Too many keys are already scheduled to expire in the requested block and the
+		/// `MAX_EXPIRY_DELAY` blocks after it.
+		TooManyExpiries,
+		/// The key was revoked and cannot be announced again until its revocation is forgotten.
+		KeyPreviouslyRevoked,
+		/// A key cannot be rotated to itself.
+		RotationToSameKey,
+		/// The key has already been reported as compromised.
+		AlreadyCompromised,
+		/// The account already keeps `MaxRevokedKeys` revocation records. Forget one first.
+		TooManyRevokedKeys,
+		/// The key has no revocation record.
+		KeyNotRevoked,
+		/// The encryption key is not a valid public key of the given type.
+		InvalidEncryptionKey,
+		/// The account has not issued an encryption key.
+		NoEncryptionKey,
+		/// The fingerprint is not the public key the proof was made with.
+		FingerprintMismatch,
+		/// The proof of possession is not a valid signature by the announced key.
+		InvalidProof,
+		/// The record of a compromised key is kept for verifiers and cannot be forgotten.
+		CompromisedKeyRecord,
+		/// The account has already reported `MaxCompromisedKeys` keys as compromised.
+		TooManyCompromisedKeys,
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_idle(n: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+			Self::sweep_expired_keys(n, remaining_weight)
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// Whether the key `fingerprint` announced by `who` exists and is valid in block `at`.
+		pub fn is_key_valid(
+			who: &T::AccountId,
+			fingerprint: &BoundedVec<u8, T::MaxSize>,
+			at: BlockNumberFor<T>,
+		) -> bool {
+			<IssuedKeys<T>>::contains_key(who, fingerprint) && Self::is_within_validity(who, fingerprint, at)
+		}
+
+		/// Whether block `at` falls in the validity period of the key `fingerprint` announced by
+		/// `who`. Keys without a validity period are valid in every block.
+		fn is_within_validity(who: &T::AccountId, fingerprint: &BoundedVec<u8, T::MaxSize>, at: BlockNumberFor<T>) -> bool {
+			<KeyValidity<T>>::get(who, fingerprint).is_none_or(|period| {
+				period.valid_from.is_none_or(|from| from <= at) && period.valid_until.is_none_or(|until| at < until)
+			})
+		}
+
+		/// Whether the key `fingerprint` announced by `who` was announced with a proof of
+		/// possession.
+		pub fn is_key_proven(who: &T::AccountId, fingerprint: &BoundedVec<u8, T::MaxSize>) -> bool {
+			<ProvenKeys<T>>::contains_key(who, fingerprint)
+		}
+
+		/// The message a proof of possession for an announcement by `who` has to sign: the
+		/// SCALE encoding of `(who, genesis_hash, nonce)`.
+		pub fn proof_payload(who: &T::AccountId, nonce: u64) -> Vec<u8> {
+			let genesis_hash = frame_system::Pallet::<T>::block_hash(BlockNumberFor::<T>::zero());
+			(who, genesis_hash, nonce).encode()
+		}
+
+		/// Returns the fingerprints and locations of the keys `who` has announced that are valid
+		/// in the current block. Keys not valid yet, or expired but not swept yet, are left out.
+		pub fn keys_of(who: &T::AccountId) -> Vec<(Vec<u8>, Vec<u8>)> {
+			let now = frame_system::Pallet::<T>::block_number();
+			<IssuedKeys<T>>::iter_prefix(who)
+				.filter(|(fingerprint, _)| Self::is_within_validity(who, fingerprint, now))
+				.map(|(fingerprint, location)| (fingerprint.into_inner(), location.into_inner()))
+				.collect()
+		}
+
+		/// Returns the accounts whose announcement of `fingerprint` is valid in the current block.
+		pub fn owners_of(fingerprint: &[u8]) -> Vec<T::AccountId> {
+			let Ok(fingerprint) = BoundedVec::<u8, T::MaxSize>::try_from(fingerprint.to_vec()) else {
+				return Vec::new();
+			};
+			let now = frame_system::Pallet::<T>::block_number();
+			<KeyOwners<T>>::iter_key_prefix(&fingerprint)
+				.filter(|who| Self::is_within_validity(who, &fingerprint, now))
+				.collect()
+		}
+
+		/// Records the key `fingerprint` as announced by `who`.
+		fn insert_key(
+			who: &T::AccountId,
+			fingerprint: &BoundedVec<u8, T::MaxSize>,
+			location: &BoundedVec<u8, T::MaxSize>,
+		) {
+			<IssuedKeys<T>>::insert(who, fingerprint, location);
+			<KeyOwners<T>>::insert(fingerprint, who, ());
+		}
+
+		/// Takes the key `fingerprint` announced by `who` out of circulation and records why.
+		/// Fails if `who` already keeps `MaxRevokedKeys` revocation records, or for a compromised
+		/// key, `MaxCompromisedKeys` records of compromised keys.
+		fn do_revoke(
+			who: &T::AccountId,
+			fingerprint: &BoundedVec<u8, T::MaxSize>,
+			reason: RevocationReason,
+			successor: Option<BoundedVec<u8, T::MaxSize>>,
+		) -> DispatchResult {
+			if reason == RevocationReason::Compromised {
+				Self::count_compromise(who)?;
+			} else {
+				let count = <RevokedKeyCount<T>>::get(who);
+				ensure!(count < T::MaxRevokedKeys::get(), Error::<T>::TooManyRevokedKeys);
+				<RevokedKeyCount<T>>::insert(who, count.saturating_add(1));
+			}
+			<IssuedKeys<T>>::remove(who, fingerprint);
+			<KeyOwners<T>>::remove(fingerprint, who);
+			<KeyValidity<T>>::remove(who, fingerprint);
+			<ProvenKeys<T>>::remove(who, fingerprint);
+			Self::unschedule_expiry(who, fingerprint);
+			let block = frame_system::Pallet::<T>::block_number();
+			<RevokedKeys<T>>::insert(who, fingerprint, Revocation { reason, successor, block });
+			Ok(())
+		}
+
+		/// Takes a place for a record of a compromised key of `who` under `MaxCompromisedKeys`.
+		fn count_compromise(who: &T::AccountId) -> DispatchResult {
+			let count = <CompromisedKeyCount<T>>::get(who);
+			ensure!(count < T::MaxCompromisedKeys::get(), Error::<T>::TooManyCompromisedKeys);
+			<CompromisedKeyCount<T>>::insert(who, count.saturating_add(1));
+			Ok(())
+		}
+
+		/// Frees the place of one of the revocation records of `who` under `MaxRevokedKeys`.
+		fn uncount_revocation(who: &T::AccountId) {
+			<RevokedKeyCount<T>>::mutate_exists(who, |count| {
+				*count = count.map(|count| count.saturating_sub(1)).filter(|count| *count > 0);
+			});
+		}
+
+		/// Schedules the key `fingerprint` announced by `who` to be removed in block `at`, or in
+		/// the first of the `MAX_EXPIRY_DELAY` blocks after it with room.
+		pub(crate) fn schedule_expiry(
+			who: &T::AccountId,
+			fingerprint: &BoundedVec<u8, T::MaxSize>,
+			at: BlockNumberFor<T>,
+			now: BlockNumberFor<T>,
+		) -> DispatchResult {
+			let block = KeyExpiryQueue::<T>::schedule((who.clone(), fingerprint.clone()), at, now)
+				.ok_or(Error::<T>::TooManyExpiries)?;
+			<ScheduledExpiries<T>>::insert(who, fingerprint, block);
+			Ok(())
+		}
+
+		/// Removes the key `fingerprint` announced by `who` from the block it is scheduled in,
+		/// freeing its place there.
+		fn unschedule_expiry(who: &T::AccountId, fingerprint: &BoundedVec<u8, T::MaxSize>) {
+			let Some(block) = <ScheduledExpiries<T>>::take(who, fingerprint) else { return };
+			KeyExpiryQueue::<T>::unschedule(&(who.clone(), fingerprint.clone()), block);
+		}
+
+		/// Removes keys whose validity ended in or before block `now`, oldest first, until either
+		/// no expired keys remain or `remaining_weight` is used up. Returns the weight used.
+		pub(crate) fn sweep_expired_keys(now: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+			KeyExpiryQueue::<T>::sweep(now, remaining_weight, T::WeightInfo::expire_key(), |(who, fingerprint), at| {
+				Self::expire_key(&who, fingerprint, at)
+			})
+		}
+
+		/// Removes the key `fingerprint` announced by `who` if it is still scheduled to expire in
+		/// block `at`. Keys revoked since the expiry was scheduled are left alone.
+		pub(crate) fn expire_key(who: &T::AccountId, fingerprint: BoundedVec<u8, T::MaxSize>, at: BlockNumberFor<T>) {
+			if <ScheduledExpiries<T>>::get(who, &fingerprint) != Some(at) {
+				return;
+			}
+			<ScheduledExpiries<T>>::remove(who, &fingerprint);
+			<IssuedKeys<T>>::remove(who, &fingerprint);
+			<KeyOwners<T>>::remove(&fingerprint, who);
+			<KeyValidity<T>>::remove(who, &fingerprint);
+			<ProvenKeys<T>>::remove(who, &fingerprint);
+			Self::deposit_event(Event::KeyExpired { key: fingerprint, who: who.clone() });
+		}
 	}
 
 	#[pallet::call]
@@ -85,14 +527,15 @@ pub mod pallet {
 			let who = ensure_signed(origin)?;
 
 			ensure!(!<IssuedKeys<T>>::contains_key(&who, &fingerprint), Error::<T>::KeyExists);
+			ensure!(!<RevokedKeys<T>>::contains_key(&who, &fingerprint), Error::<T>::KeyPreviouslyRevoked);
 
-			<IssuedKeys<T>>::insert(&who, &fingerprint, &location);
+			Self::insert_key(&who, &fingerprint, &location);
             Self::deposit_event(Event::KeyAnnounced { key: fingerprint.clone(), who: who.clone() });
             Ok(().into())
 		}
 
 		/// If a key needs to be removed from circulation, this extrinsic will handle deleting it
-		/// and informing the network.
+		/// and informing the network. The key is recorded as retired.
 		#[pallet::weight(T::WeightInfo::revoke_key())]
 		#[pallet::call_index(1)]
 		pub fn revoke_key(
@@ -103,20 +546,171 @@ pub mod pallet {
 
 			ensure!(<IssuedKeys<T>>::contains_key(&who, &key_index), Error::<T>::KeyDoesNotExist);
 
-			<IssuedKeys<T>>::remove(&who, &key_index);
+			Self::do_revoke(&who, &key_index, RevocationReason::Retired, None)?;
             Self::deposit_event(Event::KeyRevoked { key: key_index.clone(), who: who.clone() });
             Ok(().into())
 		}
 
-		/// Announces an encryption key to the network.
+		/// Announces an encryption key to the network, tagged with the algorithm it is meant for.
+		/// A previously issued key is replaced and reported as revoked.
 		#[pallet::weight(T::WeightInfo::issue_encryption_key())]
 		#[pallet::call_index(2)]
-        pub fn issue_encryption_key(origin: OriginFor<T>, key: [u8; 32]) -> DispatchResultWithPostInfo {
+        pub fn issue_encryption_key(
+			origin: OriginFor<T>,
+			key_type: EncryptionKeyType,
+			key: [u8; 32],
+		) -> DispatchResultWithPostInfo {
 			let who = ensure_signed(origin)?;
 
-			<IssuedEncryptionKeys<T>>::insert(&who, key);
+			ensure!(key_type.is_valid_key(&key), Error::<T>::InvalidEncryptionKey);
+
+			let created = frame_system::Pallet::<T>::block_number();
+			if let Some(previous) = <IssuedEncryptionKeys<T>>::take(&who) {
+				Self::deposit_event(Event::EncryptionKeyRevoked { who: who.clone(), key: previous.key });
+			}
+			<IssuedEncryptionKeys<T>>::insert(&who, EncryptionKey { key_type, key, created });
             Self::deposit_event(Event::EncryptionKeyIssued { who: who.clone() });
             Ok(().into())
 		}
+
+		/// Announces a key like `announce_key`, but only treats it as valid from block
+		/// `valid_from` (if given) until block `valid_until` (if given). Once valid_until is reached
+		/// the key is removed once the chain has spare block weight, emitting `KeyExpired`. If
+		/// that block already holds `MaxExpiriesPerBlock` expiries, the removal is scheduled in
+		/// the next block with room, up to `MAX_EXPIRY_DELAY` blocks later.
+		#[pallet::weight(T::WeightInfo::announce_key_with_validity())]
+		#[pallet::call_index(3)]
+		pub fn announce_key_with_validity(
+			origin: OriginFor<T>,
+			fingerprint: BoundedVec<u8, T::MaxSize>,
+			location: BoundedVec<u8, T::MaxSize>,
+			valid_from: Option<BlockNumberFor<T>>,
+			valid_until: Option<BlockNumberFor<T>>,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+
+			ensure!(!<IssuedKeys<T>>::contains_key(&who, &fingerprint), Error::<T>::KeyExists);
+			ensure!(!<RevokedKeys<T>>::contains_key(&who, &fingerprint), Error::<T>::KeyPreviouslyRevoked);
+			let now = frame_system::Pallet::<T>::block_number();
+			if let Some(until) = valid_until {
+				ensure!(until > now, Error::<T>::InvalidValidityPeriod);
+				ensure!(valid_from.is_none_or(|from| from < until), Error::<T>::InvalidValidityPeriod);
+				Self::schedule_expiry(&who, &fingerprint, until, now)?;
+			}
+
+			Self::insert_key(&who, &fingerprint, &location);
+			<KeyValidity<T>>::insert(&who, &fingerprint, ValidityPeriod { valid_from, valid_until });
+			Self::deposit_event(Event::KeyAnnounced { key: fingerprint, who });
+			Ok(().into())
+		}
+
+		/// Replaces the key `fingerprint` with a new key in one step. The old key is revoked as
+		/// superseded, with `new_fingerprint` recorded as its successor.
+		#[pallet::weight(T::WeightInfo::rotate_key())]
+		#[pallet::call_index(4)]
+		pub fn rotate_key(
+			origin: OriginFor<T>,
+			fingerprint: BoundedVec<u8, T::MaxSize>,
+			new_fingerprint: BoundedVec<u8, T::MaxSize>,
+			new_location: BoundedVec<u8, T::MaxSize>,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+
+			ensure!(fingerprint != new_fingerprint, Error::<T>::RotationToSameKey);
+			ensure!(<IssuedKeys<T>>::contains_key(&who, &fingerprint), Error::<T>::KeyDoesNotExist);
+			ensure!(!<IssuedKeys<T>>::contains_key(&who, &new_fingerprint), Error::<T>::KeyExists);
+			ensure!(!<RevokedKeys<T>>::contains_key(&who, &new_fingerprint), Error::<T>::KeyPreviouslyRevoked);
+
+			Self::do_revoke(&who, &fingerprint, RevocationReason::Superseded, Some(new_fingerprint.clone()))?;
+			Self::insert_key(&who, &new_fingerprint, &new_location);
+			Self::deposit_event(Event::KeyRotated { old_key: fingerprint, new_key: new_fingerprint, who });
+			Ok(().into())
+		}
+
+		/// Reports the key `fingerprint` as compromised. A key still in circulation is revoked;
+		/// a key that was already retired or rotated out keeps its successor and revocation
+		/// block, but its reason is changed to compromised. Either way the record is kept for
+		/// good and counts towards `MaxCompromisedKeys` instead of `MaxRevokedKeys`.
+		#[pallet::weight(T::WeightInfo::report_compromised_key())]
+		#[pallet::call_index(5)]
+		pub fn report_compromised_key(
+			origin: OriginFor<T>,
+			fingerprint: BoundedVec<u8, T::MaxSize>,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+
+			if let Some(mut revocation) = <RevokedKeys<T>>::get(&who, &fingerprint) {
+				ensure!(revocation.reason != RevocationReason::Compromised, Error::<T>::AlreadyCompromised);
+				Self::count_compromise(&who)?;
+				revocation.reason = RevocationReason::Compromised;
+				<RevokedKeys<T>>::insert(&who, &fingerprint, revocation);
+				Self::uncount_revocation(&who);
+			} else {
+				ensure!(<IssuedKeys<T>>::contains_key(&who, &fingerprint), Error::<T>::KeyDoesNotExist);
+				Self::do_revoke(&who, &fingerprint, RevocationReason::Compromised, None)?;
+				Self::deposit_event(Event::KeyRevoked { key: fingerprint.clone(), who: who.clone() });
+			}
+			Self::deposit_event(Event::KeyCompromised { key: fingerprint, who });
+			Ok(().into())
+		}
+
+		/// Removes the revocation record of the key `fingerprint`, freeing its place under
+		/// `MaxRevokedKeys`. Verifiers can no longer look up why or when the key was revoked, and
+		/// the fingerprint can be announced again. Records of compromised keys cannot be
+		/// forgotten.
+		#[pallet::weight(T::WeightInfo::forget_revoked_key())]
+		#[pallet::call_index(6)]
+		pub fn forget_revoked_key(
+			origin: OriginFor<T>,
+			fingerprint: BoundedVec<u8, T::MaxSize>,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+
+			let revocation = <RevokedKeys<T>>::get(&who, &fingerprint).ok_or(Error::<T>::KeyNotRevoked)?;
+			ensure!(revocation.reason != RevocationReason::Compromised, Error::<T>::CompromisedKeyRecord);
+
+			<RevokedKeys<T>>::remove(&who, &fingerprint);
+			Self::uncount_revocation(&who);
+			Self::deposit_event(Event::RevocationForgotten { key: fingerprint, who });
+			Ok(().into())
+		}
+
+		/// Withdraws the caller's encryption key, so clients stop encrypting messages to it.
+		#[pallet::weight(T::WeightInfo::revoke_encryption_key())]
+		#[pallet::call_index(7)]
+		pub fn revoke_encryption_key(origin: OriginFor<T>) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+
+			let previous = <IssuedEncryptionKeys<T>>::take(&who).ok_or(Error::<T>::NoEncryptionKey)?;
+			Self::deposit_event(Event::EncryptionKeyRevoked { who, key: previous.key });
+			Ok(().into())
+		}
+
+		/// Announces a key like `announce_key`, along with a signature by that key showing the
+		/// caller holds it. The fingerprint has to be the encoded public key and the signature
+		/// has to cover `proof_payload` for the caller's current proof nonce. The key is marked
+		/// as proven, so verifiers can tell it apart from keys merely claimed by an account.
+		#[pallet::weight(T::WeightInfo::announce_key_with_proof())]
+		#[pallet::call_index(8)]
+		pub fn announce_key_with_proof(
+			origin: OriginFor<T>,
+			fingerprint: BoundedVec<u8, T::MaxSize>,
+			location: BoundedVec<u8, T::MaxSize>,
+			proof: KeyProof,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+
+			ensure!(!<IssuedKeys<T>>::contains_key(&who, &fingerprint), Error::<T>::KeyExists);
+			ensure!(!<RevokedKeys<T>>::contains_key(&who, &fingerprint), Error::<T>::KeyPreviouslyRevoked);
+			ensure!(fingerprint[..] == *proof.public_key(), Error::<T>::FingerprintMismatch);
+			let nonce = <ProofNonces<T>>::get(&who);
+			ensure!(proof.verify(&Self::proof_payload(&who, nonce)), Error::<T>::InvalidProof);
+
+			<ProofNonces<T>>::insert(&who, nonce.saturating_add(1));
+			Self::insert_key(&who, &fingerprint, &location);
+			<ProvenKeys<T>>::insert(&who, &fingerprint, frame_system::Pallet::<T>::block_number());
+			Self::deposit_event(Event::KeyAnnounced { key: fingerprint, who });
+			Ok(().into())
+		}
 	}
 }
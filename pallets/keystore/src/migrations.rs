@@ -0,0 +1,80 @@
+//! Storage migrations for pallet-keystore.
+
+/// Migrates the pallet from storage version 0 to 1.
+///
+/// Version 1 stores encryption keys as typed [`EncryptionKey`](crate::EncryptionKey) records
+/// instead of bare 32-byte arrays. Keys issued before the upgrade were meant for Elliptic-curve
+/// Diffie-Hellman, so they are tagged as X25519, and since their issuing block was never recorded
+/// they are stamped with the block the migration runs in.
+pub mod v1 {
+    use crate::{Config, EncryptionKey, EncryptionKeyType, IssuedEncryptionKeys, Pallet};
+    use core::marker::PhantomData;
+    use frame_support::{
+        migrations::VersionedMigration,
+        traits::{Get, UncheckedOnRuntimeUpgrade},
+        weights::Weight,
+    };
+
+    /// Converts `IssuedEncryptionKeys` to typed records. Use [`MigrateV0ToV1`] instead, which
+    /// only runs this once and bumps the storage version.
+    pub struct InnerMigrateV0ToV1<T>(PhantomData<T>);
+
+    impl<T: Config> UncheckedOnRuntimeUpgrade for InnerMigrateV0ToV1<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let created = frame_system::Pallet::<T>::block_number();
+            let mut translated: u64 = 0;
+            IssuedEncryptionKeys::<T>::translate::<[u8; 32], _>(|_, key| {
+                translated = translated.saturating_add(1);
+                Some(EncryptionKey { key_type: EncryptionKeyType::X25519, key, created })
+            });
+            T::DbWeight::get().reads_writes(translated.saturating_add(1), translated)
+        }
+    }
+
+    /// Migrates pallet-keystore from storage version 0 to 1.
+    pub type MigrateV0ToV1<T> = VersionedMigration<
+        0,
+        1,
+        InnerMigrateV0ToV1<T>,
+        Pallet<T>,
+        <T as frame_system::Config>::DbWeight,
+    >;
+}
+
+/// Migrates the pallet from storage version 1 to 2.
+///
+/// Version 2 adds `KeyOwners`, a reverse index from fingerprints to the accounts that announced
+/// them, which this migration fills in from `IssuedKeys`.
+pub mod v2 {
+    use crate::{Config, IssuedKeys, KeyOwners, Pallet};
+    use core::marker::PhantomData;
+    use frame_support::{
+        migrations::VersionedMigration,
+        traits::{Get, UncheckedOnRuntimeUpgrade},
+        weights::Weight,
+    };
+
+    /// Backfills `KeyOwners` from `IssuedKeys`. Use [`MigrateV1ToV2`] instead, which only runs
+    /// this once and bumps the storage version.
+    pub struct InnerMigrateV1ToV2<T>(PhantomData<T>);
+
+    impl<T: Config> UncheckedOnRuntimeUpgrade for InnerMigrateV1ToV2<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let mut indexed: u64 = 0;
+            for (who, fingerprint) in IssuedKeys::<T>::iter_keys() {
+                KeyOwners::<T>::insert(fingerprint, who, ());
+                indexed = indexed.saturating_add(1);
+            }
+            T::DbWeight::get().reads_writes(indexed, indexed)
+        }
+    }
+
+    /// Migrates pallet-keystore from storage version 1 to 2.
+    pub type MigrateV1ToV2<T> = VersionedMigration<
+        1,
+        2,
+        InnerMigrateV1ToV2<T>,
+        Pallet<T>,
+        <T as frame_system::Config>::DbWeight,
+    >;
+}
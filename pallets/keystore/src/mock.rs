@@ -1,6 +1,7 @@
 use crate as pallet_keystore;
 use frame_support::derive_impl;
 use sp_core::ConstU32;
+use sp_keystore::{testing::MemoryKeystore, KeystoreExt};
 use sp_runtime::BuildStorage;
 
 type Block = frame_system::mocking::MockBlock<Test>;
@@ -37,12 +38,18 @@ impl pallet_keystore::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type WeightInfo = ();
 	type MaxSize = ConstU32<1024>;
+	type MaxExpiriesPerBlock = ConstU32<2>;
+	type MaxRevokedKeys = ConstU32<3>;
+	type MaxCompromisedKeys = ConstU32<2>;
 }
 
 // Build genesis storage according to the mock runtime.
 pub fn new_test_ext() -> sp_io::TestExternalities {
-    frame_system::GenesisConfig::<Test>::default()
+    let mut ext: sp_io::TestExternalities = frame_system::GenesisConfig::<Test>::default()
 		.build_storage()
 		.unwrap()
-		.into()
+		.into();
+    // Proofs of possession are signed with keys from the keystore.
+    ext.register_extension(KeystoreExt::new(MemoryKeystore::new()));
+    ext
 }
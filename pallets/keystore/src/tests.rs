@@ -1,6 +1,24 @@
-use crate::{mock::*, Error, Event, IssuedKeys, IssuedEncryptionKeys};
-use frame_support::{assert_ok, assert_noop};
-use sp_core::ConstU32;
+use crate::{
+    mock::*, CompromisedKeyCount, EncryptionKey, EncryptionKeyType, Error, Event, ExpiryCursor, IssuedKeys, IssuedEncryptionKeys,
+    KeyExpiries, KeyProof, KeyValidity, PendingExpiries, ProvenKeys, Revocation, RevocationReason, RevokedKeyCount,
+    RevokedKeys, ScheduledExpiries, WeightInfo,
+};
+use fennel_expiry::MAX_EXPIRY_DELAY;
+use frame_support::{
+    assert_ok, assert_noop,
+    traits::{Get, GetStorageVersion, Hooks, OnRuntimeUpgrade, StorageVersion},
+    weights::Weight,
+};
+use sp_core::{crypto::KeyTypeId, ed25519, sr25519, ConstU32, Pair};
+
+const KEY_TYPE: KeyTypeId = KeyTypeId(*b"test");
+
+/// The Curve25519 base point, a valid X25519 public key.
+fn x25519_key() -> [u8; 32] {
+    let mut key = [0u8; 32];
+    key[0] = 9;
+    key
+}
 use frame_support::BoundedVec;
 
 #[test]
@@ -56,11 +74,750 @@ fn cannot_revoke_nonexistent_key() {
 fn issue_encryption_key_works_and_emits_event() {
     new_test_ext().execute_with(|| {
         System::set_block_number(1);
-        let key = [0u8; 32];
-        assert_ok!(Keystore::issue_encryption_key(RuntimeOrigin::signed(1), key));
+        let key = x25519_key();
+        assert_ok!(Keystore::issue_encryption_key(RuntimeOrigin::signed(1), EncryptionKeyType::X25519, key));
         // Storage check
-        assert_eq!(IssuedEncryptionKeys::<Test>::get(1), Some(key));
+        assert_eq!(
+            IssuedEncryptionKeys::<Test>::get(1),
+            Some(EncryptionKey { key_type: EncryptionKeyType::X25519, key, created: 1 })
+        );
         // Event check
         System::assert_last_event(Event::EncryptionKeyIssued { who: 1 }.into());
 	});
 }
+
+#[test]
+fn announce_key_with_validity_tracks_period() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let fingerprint = BoundedVec::<u8, ConstU32<1024>>::try_from(b"Luke".to_vec()).unwrap();
+        let location = BoundedVec::<u8, ConstU32<1024>>::try_from(b"Skywalker".to_vec()).unwrap();
+        assert_ok!(Keystore::announce_key_with_validity(
+            RuntimeOrigin::signed(1),
+            fingerprint.clone(),
+            location.clone(),
+            Some(3),
+            Some(5)
+        ));
+        assert_eq!(IssuedKeys::<Test>::get(1, &fingerprint), Some(location));
+        assert_eq!(KeyExpiries::<Test>::get(5).into_inner(), vec![(1, fingerprint.clone())]);
+        System::assert_last_event(Event::KeyAnnounced { key: fingerprint.clone(), who: 1 }.into());
+        assert!(!Keystore::is_key_valid(&1, &fingerprint, 2));
+        assert!(Keystore::is_key_valid(&1, &fingerprint, 3));
+        assert!(!Keystore::is_key_valid(&1, &fingerprint, 5));
+    });
+}
+
+#[test]
+fn announce_key_with_invalid_validity_fails() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(5);
+        let fingerprint = BoundedVec::<u8, ConstU32<1024>>::try_from(b"Luke".to_vec()).unwrap();
+        let location = BoundedVec::<u8, ConstU32<1024>>::try_from(b"Skywalker".to_vec()).unwrap();
+        assert_noop!(
+            Keystore::announce_key_with_validity(RuntimeOrigin::signed(1), fingerprint.clone(), location.clone(), None, Some(5)),
+            Error::<Test>::InvalidValidityPeriod
+        );
+        assert_noop!(
+            Keystore::announce_key_with_validity(RuntimeOrigin::signed(1), fingerprint, location, Some(8), Some(7)),
+            Error::<Test>::InvalidValidityPeriod
+        );
+    });
+}
+
+#[test]
+fn announce_key_with_validity_overflows_into_later_blocks() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let location = BoundedVec::<u8, ConstU32<1024>>::try_from(b"Skywalker".to_vec()).unwrap();
+        for name in [b"Luke", b"Leia"] {
+            let fingerprint = BoundedVec::<u8, ConstU32<1024>>::try_from(name.to_vec()).unwrap();
+            assert_ok!(Keystore::announce_key_with_validity(RuntimeOrigin::signed(1), fingerprint, location.clone(), None, Some(5)));
+        }
+        let han = BoundedVec::<u8, ConstU32<1024>>::try_from(b"Han".to_vec()).unwrap();
+        assert_ok!(Keystore::announce_key_with_validity(RuntimeOrigin::signed(1), han.clone(), location, None, Some(5)));
+        assert_eq!(KeyExpiries::<Test>::get(5).len(), 2);
+        assert_eq!(KeyExpiries::<Test>::get(6).into_inner(), vec![(1, han.clone())]);
+        assert_eq!(ScheduledExpiries::<Test>::get(1, &han), Some(6));
+        assert_eq!(PendingExpiries::<Test>::get(), 3);
+        // The key stops being valid on time, even though its removal is pushed back.
+        assert!(!Keystore::is_key_valid(&1, &han, 5));
+
+        System::set_block_number(5);
+        Keystore::on_idle(5, Weight::MAX);
+        assert!(IssuedKeys::<Test>::contains_key(1, &han));
+
+        System::set_block_number(6);
+        Keystore::on_idle(6, Weight::MAX);
+        assert!(!IssuedKeys::<Test>::contains_key(1, &han));
+        System::assert_last_event(Event::KeyExpired { key: han, who: 1 }.into());
+    });
+}
+
+#[test]
+fn announce_key_with_validity_fails_once_later_blocks_are_full() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let location = BoundedVec::<u8, ConstU32<1024>>::try_from(b"Skywalker".to_vec()).unwrap();
+        let capacity: u32 = MAX_EXPIRY_DELAY * <<Test as crate::Config>::MaxExpiriesPerBlock as Get<u32>>::get();
+        for i in 0..capacity {
+            let fingerprint = BoundedVec::<u8, ConstU32<1024>>::try_from(i.to_le_bytes().to_vec()).unwrap();
+            assert_ok!(Keystore::announce_key_with_validity(RuntimeOrigin::signed(1), fingerprint, location.clone(), None, Some(5)));
+        }
+        let fingerprint = BoundedVec::<u8, ConstU32<1024>>::try_from(b"Han".to_vec()).unwrap();
+        assert_noop!(
+            Keystore::announce_key_with_validity(RuntimeOrigin::signed(1), fingerprint, location, None, Some(5)),
+            Error::<Test>::TooManyExpiries
+        );
+    });
+}
+
+#[test]
+fn on_idle_removes_expired_keys() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let fingerprint = BoundedVec::<u8, ConstU32<1024>>::try_from(b"Luke".to_vec()).unwrap();
+        let location = BoundedVec::<u8, ConstU32<1024>>::try_from(b"Skywalker".to_vec()).unwrap();
+        assert_ok!(Keystore::announce_key_with_validity(RuntimeOrigin::signed(1), fingerprint.clone(), location, None, Some(3)));
+
+        Keystore::on_idle(2, Weight::MAX);
+        assert!(IssuedKeys::<Test>::contains_key(1, &fingerprint));
+
+        System::set_block_number(4);
+        Keystore::on_idle(4, Weight::MAX);
+        assert!(!IssuedKeys::<Test>::contains_key(1, &fingerprint));
+        assert!(KeyValidity::<Test>::get(1, &fingerprint).is_none());
+        assert!(KeyExpiries::<Test>::get(3).is_empty());
+        assert_eq!(ScheduledExpiries::<Test>::get(1, &fingerprint), None);
+        assert_eq!(ExpiryCursor::<Test>::get(), None);
+        assert_eq!(PendingExpiries::<Test>::get(), 0);
+        System::assert_last_event(Event::KeyExpired { key: fingerprint, who: 1 }.into());
+    });
+}
+
+#[test]
+fn on_idle_sweeps_within_weight_budget() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let location = BoundedVec::<u8, ConstU32<1024>>::try_from(b"Skywalker".to_vec()).unwrap();
+        let luke = BoundedVec::<u8, ConstU32<1024>>::try_from(b"Luke".to_vec()).unwrap();
+        let leia = BoundedVec::<u8, ConstU32<1024>>::try_from(b"Leia".to_vec()).unwrap();
+        assert_ok!(Keystore::announce_key_with_validity(RuntimeOrigin::signed(1), luke.clone(), location.clone(), None, Some(2)));
+        assert_ok!(Keystore::announce_key_with_validity(RuntimeOrigin::signed(1), leia.clone(), location, None, Some(2)));
+
+        // Enough weight to reach block 2 and expire a single key.
+        let db: frame_support::weights::RuntimeDbWeight = <Test as frame_system::Config>::DbWeight::get();
+        let budget = db.reads(1) + db.reads_writes(1, 2) + db.reads_writes(1, 1) + <() as WeightInfo>::expire_key();
+        System::set_block_number(2);
+        assert_eq!(Keystore::on_idle(2, budget), budget);
+        assert!(!IssuedKeys::<Test>::contains_key(1, &leia));
+        assert!(IssuedKeys::<Test>::contains_key(1, &luke));
+        assert_eq!(ExpiryCursor::<Test>::get(), Some(2));
+        assert_eq!(PendingExpiries::<Test>::get(), 1);
+
+        Keystore::on_idle(2, Weight::MAX);
+        assert!(!IssuedKeys::<Test>::contains_key(1, &luke));
+        assert_eq!(ExpiryCursor::<Test>::get(), None);
+    });
+}
+
+#[test]
+fn revoked_key_is_not_expired() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let fingerprint = BoundedVec::<u8, ConstU32<1024>>::try_from(b"Luke".to_vec()).unwrap();
+        let location = BoundedVec::<u8, ConstU32<1024>>::try_from(b"Skywalker".to_vec()).unwrap();
+        assert_ok!(Keystore::announce_key_with_validity(RuntimeOrigin::signed(1), fingerprint.clone(), location, None, Some(3)));
+        assert_ok!(Keystore::revoke_key(RuntimeOrigin::signed(1), fingerprint.clone()));
+        assert!(KeyExpiries::<Test>::get(3).is_empty());
+        assert_eq!(ScheduledExpiries::<Test>::get(1, &fingerprint), None);
+        assert_eq!(ExpiryCursor::<Test>::get(), None);
+
+        System::set_block_number(3);
+        Keystore::on_idle(3, Weight::MAX);
+        assert!(RevokedKeys::<Test>::contains_key(1, &fingerprint));
+        System::assert_last_event(Event::KeyRevoked { key: fingerprint, who: 1 }.into());
+    });
+}
+
+#[test]
+fn revoke_key_records_retirement() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(4);
+        let fingerprint = BoundedVec::<u8, ConstU32<1024>>::try_from(b"Luke".to_vec()).unwrap();
+        let location = BoundedVec::<u8, ConstU32<1024>>::try_from(b"Skywalker".to_vec()).unwrap();
+        assert_ok!(Keystore::announce_key(RuntimeOrigin::signed(1), fingerprint.clone(), location.clone()));
+        assert_ok!(Keystore::revoke_key(RuntimeOrigin::signed(1), fingerprint.clone()));
+        assert_eq!(
+            RevokedKeys::<Test>::get(1, &fingerprint),
+            Some(Revocation { reason: RevocationReason::Retired, successor: None, block: 4 })
+        );
+        // A revoked fingerprint stays revoked.
+        assert_noop!(
+            Keystore::announce_key(RuntimeOrigin::signed(1), fingerprint.clone(), location.clone()),
+            Error::<Test>::KeyPreviouslyRevoked
+        );
+        assert_noop!(
+            Keystore::announce_key_with_validity(RuntimeOrigin::signed(1), fingerprint.clone(), location.clone(), None, None),
+            Error::<Test>::KeyPreviouslyRevoked
+        );
+        // Other accounts are unaffected.
+        assert_ok!(Keystore::announce_key(RuntimeOrigin::signed(2), fingerprint, location));
+    });
+}
+
+#[test]
+fn rotate_key_works_and_links_successor() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(2);
+        let fingerprint = BoundedVec::<u8, ConstU32<1024>>::try_from(b"Luke".to_vec()).unwrap();
+        let new_fingerprint = BoundedVec::<u8, ConstU32<1024>>::try_from(b"Leia".to_vec()).unwrap();
+        let location = BoundedVec::<u8, ConstU32<1024>>::try_from(b"Skywalker".to_vec()).unwrap();
+        let new_location = BoundedVec::<u8, ConstU32<1024>>::try_from(b"Organa".to_vec()).unwrap();
+        assert_ok!(Keystore::announce_key_with_validity(RuntimeOrigin::signed(1), fingerprint.clone(), location, None, Some(9)));
+        assert_ok!(Keystore::rotate_key(
+            RuntimeOrigin::signed(1),
+            fingerprint.clone(),
+            new_fingerprint.clone(),
+            new_location.clone()
+        ));
+
+        assert_eq!(IssuedKeys::<Test>::get(1, &fingerprint), None);
+        assert!(KeyValidity::<Test>::get(1, &fingerprint).is_none());
+        assert_eq!(IssuedKeys::<Test>::get(1, &new_fingerprint), Some(new_location));
+        assert_eq!(
+            RevokedKeys::<Test>::get(1, &fingerprint),
+            Some(Revocation {
+                reason: RevocationReason::Superseded,
+                successor: Some(new_fingerprint.clone()),
+                block: 2,
+            })
+        );
+        System::assert_last_event(Event::KeyRotated { old_key: fingerprint, new_key: new_fingerprint, who: 1 }.into());
+    });
+}
+
+#[test]
+fn rotate_key_checks_both_keys() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let luke = BoundedVec::<u8, ConstU32<1024>>::try_from(b"Luke".to_vec()).unwrap();
+        let leia = BoundedVec::<u8, ConstU32<1024>>::try_from(b"Leia".to_vec()).unwrap();
+        let han = BoundedVec::<u8, ConstU32<1024>>::try_from(b"Han".to_vec()).unwrap();
+        let location = BoundedVec::<u8, ConstU32<1024>>::try_from(b"Skywalker".to_vec()).unwrap();
+        assert_noop!(
+            Keystore::rotate_key(RuntimeOrigin::signed(1), luke.clone(), leia.clone(), location.clone()),
+            Error::<Test>::KeyDoesNotExist
+        );
+        assert_ok!(Keystore::announce_key(RuntimeOrigin::signed(1), luke.clone(), location.clone()));
+        assert_ok!(Keystore::announce_key(RuntimeOrigin::signed(1), leia.clone(), location.clone()));
+        assert_noop!(
+            Keystore::rotate_key(RuntimeOrigin::signed(1), luke.clone(), luke.clone(), location.clone()),
+            Error::<Test>::RotationToSameKey
+        );
+        assert_noop!(
+            Keystore::rotate_key(RuntimeOrigin::signed(1), luke.clone(), leia.clone(), location.clone()),
+            Error::<Test>::KeyExists
+        );
+        assert_ok!(Keystore::announce_key(RuntimeOrigin::signed(1), han.clone(), location.clone()));
+        assert_ok!(Keystore::revoke_key(RuntimeOrigin::signed(1), han.clone()));
+        assert_noop!(
+            Keystore::rotate_key(RuntimeOrigin::signed(1), luke, han, location),
+            Error::<Test>::KeyPreviouslyRevoked
+        );
+    });
+}
+
+#[test]
+fn report_compromised_key_revokes_active_key() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(3);
+        let fingerprint = BoundedVec::<u8, ConstU32<1024>>::try_from(b"Luke".to_vec()).unwrap();
+        let location = BoundedVec::<u8, ConstU32<1024>>::try_from(b"Skywalker".to_vec()).unwrap();
+        assert_noop!(
+            Keystore::report_compromised_key(RuntimeOrigin::signed(1), fingerprint.clone()),
+            Error::<Test>::KeyDoesNotExist
+        );
+        assert_ok!(Keystore::announce_key(RuntimeOrigin::signed(1), fingerprint.clone(), location));
+        assert_ok!(Keystore::report_compromised_key(RuntimeOrigin::signed(1), fingerprint.clone()));
+
+        assert_eq!(IssuedKeys::<Test>::get(1, &fingerprint), None);
+        assert_eq!(
+            RevokedKeys::<Test>::get(1, &fingerprint),
+            Some(Revocation { reason: RevocationReason::Compromised, successor: None, block: 3 })
+        );
+        System::assert_has_event(Event::KeyRevoked { key: fingerprint.clone(), who: 1 }.into());
+        System::assert_last_event(Event::KeyCompromised { key: fingerprint.clone(), who: 1 }.into());
+        assert_noop!(
+            Keystore::report_compromised_key(RuntimeOrigin::signed(1), fingerprint),
+            Error::<Test>::AlreadyCompromised
+        );
+    });
+}
+
+#[test]
+fn report_compromised_key_updates_rotated_key() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(2);
+        let fingerprint = BoundedVec::<u8, ConstU32<1024>>::try_from(b"Luke".to_vec()).unwrap();
+        let new_fingerprint = BoundedVec::<u8, ConstU32<1024>>::try_from(b"Leia".to_vec()).unwrap();
+        let location = BoundedVec::<u8, ConstU32<1024>>::try_from(b"Skywalker".to_vec()).unwrap();
+        assert_ok!(Keystore::announce_key(RuntimeOrigin::signed(1), fingerprint.clone(), location.clone()));
+        assert_ok!(Keystore::rotate_key(RuntimeOrigin::signed(1), fingerprint.clone(), new_fingerprint.clone(), location));
+
+        System::set_block_number(7);
+        assert_ok!(Keystore::report_compromised_key(RuntimeOrigin::signed(1), fingerprint.clone()));
+        assert_eq!(
+            RevokedKeys::<Test>::get(1, &fingerprint),
+            Some(Revocation {
+                reason: RevocationReason::Compromised,
+                successor: Some(new_fingerprint.clone()),
+                block: 2,
+            })
+        );
+        assert!(IssuedKeys::<Test>::contains_key(1, &new_fingerprint));
+        System::assert_last_event(Event::KeyCompromised { key: fingerprint, who: 1 }.into());
+    });
+}
+
+#[test]
+fn on_idle_only_reads_the_cursor_once_nothing_is_pending() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let fingerprint = BoundedVec::<u8, ConstU32<1024>>::try_from(b"Luke".to_vec()).unwrap();
+        let location = BoundedVec::<u8, ConstU32<1024>>::try_from(b"Skywalker".to_vec()).unwrap();
+        assert_ok!(Keystore::announce_key_with_validity(RuntimeOrigin::signed(1), fingerprint, location, None, Some(2)));
+        System::set_block_number(2);
+        Keystore::on_idle(2, Weight::MAX);
+        assert_eq!(ExpiryCursor::<Test>::get(), None);
+
+        let db: frame_support::weights::RuntimeDbWeight = <Test as frame_system::Config>::DbWeight::get();
+        for n in 3..6 {
+            System::set_block_number(n);
+            assert_eq!(Keystore::on_idle(n, Weight::MAX), db.reads(1));
+            assert_eq!(ExpiryCursor::<Test>::get(), None);
+        }
+    });
+}
+
+#[test]
+fn revoked_fingerprint_cannot_be_announced_again_until_forgotten() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let public = sp_io::crypto::sr25519_generate(KEY_TYPE, None);
+        let fingerprint = BoundedVec::<u8, ConstU32<1024>>::try_from(public.0.to_vec()).unwrap();
+        let other = BoundedVec::<u8, ConstU32<1024>>::try_from(b"Leia".to_vec()).unwrap();
+        let location = BoundedVec::<u8, ConstU32<1024>>::try_from(b"Skywalker".to_vec()).unwrap();
+        assert_ok!(Keystore::announce_key(RuntimeOrigin::signed(1), fingerprint.clone(), location.clone()));
+        assert_ok!(Keystore::revoke_key(RuntimeOrigin::signed(1), fingerprint.clone()));
+        assert_ok!(Keystore::announce_key(RuntimeOrigin::signed(1), other.clone(), location.clone()));
+
+        // Every way of announcing the fingerprint is blocked.
+        assert_noop!(
+            Keystore::announce_key(RuntimeOrigin::signed(1), fingerprint.clone(), location.clone()),
+            Error::<Test>::KeyPreviouslyRevoked
+        );
+        assert_noop!(
+            Keystore::announce_key_with_validity(RuntimeOrigin::signed(1), fingerprint.clone(), location.clone(), None, Some(9)),
+            Error::<Test>::KeyPreviouslyRevoked
+        );
+        assert_noop!(
+            Keystore::announce_key_with_proof(RuntimeOrigin::signed(1), fingerprint.clone(), location.clone(), sign_proof(1, public)),
+            Error::<Test>::KeyPreviouslyRevoked
+        );
+        assert_noop!(
+            Keystore::rotate_key(RuntimeOrigin::signed(1), other, fingerprint.clone(), location.clone()),
+            Error::<Test>::KeyPreviouslyRevoked
+        );
+
+        assert_ok!(Keystore::forget_revoked_key(RuntimeOrigin::signed(1), fingerprint.clone()));
+        System::assert_last_event(Event::RevocationForgotten { key: fingerprint.clone(), who: 1 }.into());
+        assert_eq!(RevokedKeys::<Test>::get(1, &fingerprint), None);
+        assert_eq!(RevokedKeyCount::<Test>::get(1), 0);
+        assert_ok!(Keystore::announce_key_with_proof(RuntimeOrigin::signed(1), fingerprint.clone(), location, sign_proof(1, public)));
+        assert!(Keystore::is_key_proven(&1, &fingerprint));
+    });
+}
+
+#[test]
+fn forget_revoked_key_requires_a_record() {
+    new_test_ext().execute_with(|| {
+        let fingerprint = BoundedVec::<u8, ConstU32<1024>>::try_from(b"Luke".to_vec()).unwrap();
+        let location = BoundedVec::<u8, ConstU32<1024>>::try_from(b"Skywalker".to_vec()).unwrap();
+        assert_noop!(
+            Keystore::forget_revoked_key(RuntimeOrigin::signed(1), fingerprint.clone()),
+            Error::<Test>::KeyNotRevoked
+        );
+        assert_ok!(Keystore::announce_key(RuntimeOrigin::signed(1), fingerprint.clone(), location));
+        assert_noop!(Keystore::forget_revoked_key(RuntimeOrigin::signed(1), fingerprint), Error::<Test>::KeyNotRevoked);
+    });
+}
+
+#[test]
+fn revocation_records_are_capped_per_account() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let location = BoundedVec::<u8, ConstU32<1024>>::try_from(b"Skywalker".to_vec()).unwrap();
+        let key = |name: &[u8]| BoundedVec::<u8, ConstU32<1024>>::try_from(name.to_vec()).unwrap();
+        for name in [&b"Luke"[..], b"Leia", b"Han", b"Chewie", b"Obi-Wan"] {
+            assert_ok!(Keystore::announce_key(RuntimeOrigin::signed(1), key(name), location.clone()));
+        }
+        assert_ok!(Keystore::revoke_key(RuntimeOrigin::signed(1), key(b"Luke")));
+        assert_ok!(Keystore::rotate_key(RuntimeOrigin::signed(1), key(b"Han"), key(b"Lando"), location.clone()));
+        assert_ok!(Keystore::revoke_key(RuntimeOrigin::signed(1), key(b"Obi-Wan")));
+        assert_eq!(RevokedKeyCount::<Test>::get(1), 3);
+
+        assert_noop!(Keystore::revoke_key(RuntimeOrigin::signed(1), key(b"Chewie")), Error::<Test>::TooManyRevokedKeys);
+        assert_noop!(
+            Keystore::rotate_key(RuntimeOrigin::signed(1), key(b"Chewie"), key(b"Yoda"), location),
+            Error::<Test>::TooManyRevokedKeys
+        );
+        // Compromised keys are counted under their own limit, so they can still be reported.
+        assert_ok!(Keystore::report_compromised_key(RuntimeOrigin::signed(1), key(b"Leia")));
+        assert_eq!(RevokedKeyCount::<Test>::get(1), 3);
+        assert_ok!(Keystore::report_compromised_key(RuntimeOrigin::signed(1), key(b"Luke")));
+        assert_eq!(RevokedKeyCount::<Test>::get(1), 2);
+        assert_eq!(CompromisedKeyCount::<Test>::get(1), 2);
+
+        assert_ok!(Keystore::revoke_key(RuntimeOrigin::signed(1), key(b"Chewie")));
+        assert_eq!(RevokedKeyCount::<Test>::get(1), 3);
+    });
+}
+
+#[test]
+fn compromised_key_records_are_capped_per_account() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let location = BoundedVec::<u8, ConstU32<1024>>::try_from(b"Skywalker".to_vec()).unwrap();
+        let key = |name: &[u8]| BoundedVec::<u8, ConstU32<1024>>::try_from(name.to_vec()).unwrap();
+        for name in [&b"Luke"[..], b"Leia", b"Han", b"Chewie"] {
+            assert_ok!(Keystore::announce_key(RuntimeOrigin::signed(1), key(name), location.clone()));
+        }
+        assert_ok!(Keystore::report_compromised_key(RuntimeOrigin::signed(1), key(b"Luke")));
+        assert_ok!(Keystore::report_compromised_key(RuntimeOrigin::signed(1), key(b"Leia")));
+        assert_eq!(CompromisedKeyCount::<Test>::get(1), 2);
+
+        assert_noop!(
+            Keystore::report_compromised_key(RuntimeOrigin::signed(1), key(b"Han")),
+            Error::<Test>::TooManyCompromisedKeys
+        );
+        // Marking an existing record as compromised is bounded as well, and keeps its place
+        // under `MaxRevokedKeys` when it fails.
+        assert_ok!(Keystore::revoke_key(RuntimeOrigin::signed(1), key(b"Chewie")));
+        assert_noop!(
+            Keystore::report_compromised_key(RuntimeOrigin::signed(1), key(b"Chewie")),
+            Error::<Test>::TooManyCompromisedKeys
+        );
+        assert_eq!(RevokedKeyCount::<Test>::get(1), 1);
+        // Other accounts have their own limit.
+        assert_ok!(Keystore::announce_key(RuntimeOrigin::signed(2), key(b"Han"), location));
+        assert_ok!(Keystore::report_compromised_key(RuntimeOrigin::signed(2), key(b"Han")));
+    });
+}
+
+#[test]
+fn compromised_key_records_cannot_be_forgotten() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let fingerprint = BoundedVec::<u8, ConstU32<1024>>::try_from(b"Luke".to_vec()).unwrap();
+        let location = BoundedVec::<u8, ConstU32<1024>>::try_from(b"Skywalker".to_vec()).unwrap();
+        assert_ok!(Keystore::announce_key(RuntimeOrigin::signed(1), fingerprint.clone(), location.clone()));
+        assert_ok!(Keystore::report_compromised_key(RuntimeOrigin::signed(1), fingerprint.clone()));
+
+        assert_noop!(
+            Keystore::forget_revoked_key(RuntimeOrigin::signed(1), fingerprint.clone()),
+            Error::<Test>::CompromisedKeyRecord
+        );
+        assert_noop!(
+            Keystore::announce_key(RuntimeOrigin::signed(1), fingerprint, location),
+            Error::<Test>::KeyPreviouslyRevoked
+        );
+    });
+}
+
+#[test]
+fn issue_encryption_key_accepts_each_key_type() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let sr25519_key = sr25519::Pair::from_seed(&[1u8; 32]).public().0;
+        let ed25519_key = ed25519::Pair::from_seed(&[1u8; 32]).public().0;
+        assert_ok!(Keystore::issue_encryption_key(RuntimeOrigin::signed(1), EncryptionKeyType::X25519, x25519_key()));
+        assert_ok!(Keystore::issue_encryption_key(RuntimeOrigin::signed(2), EncryptionKeyType::Sr25519, sr25519_key));
+        assert_ok!(Keystore::issue_encryption_key(RuntimeOrigin::signed(3), EncryptionKeyType::Ed25519, ed25519_key));
+        assert_eq!(IssuedEncryptionKeys::<Test>::get(2).map(|issued| issued.key_type), Some(EncryptionKeyType::Sr25519));
+        assert_eq!(IssuedEncryptionKeys::<Test>::get(3).map(|issued| issued.key_type), Some(EncryptionKeyType::Ed25519));
+    });
+}
+
+#[test]
+fn issue_encryption_key_rejects_invalid_points() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let mut ed25519_identity = [0u8; 32];
+        ed25519_identity[0] = 1;
+        for (key_type, key) in [
+            (EncryptionKeyType::X25519, [0u8; 32]),
+            (EncryptionKeyType::Sr25519, [0u8; 32]),
+            (EncryptionKeyType::Sr25519, [0xffu8; 32]),
+            (EncryptionKeyType::Ed25519, ed25519_identity),
+            (EncryptionKeyType::Ed25519, [2u8; 32]),
+        ] {
+            assert_noop!(
+                Keystore::issue_encryption_key(RuntimeOrigin::signed(1), key_type, key),
+                Error::<Test>::InvalidEncryptionKey
+            );
+        }
+    });
+}
+
+#[test]
+fn issue_encryption_key_replaces_previous_key() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let old_key = x25519_key();
+        let new_key = ed25519::Pair::from_seed(&[1u8; 32]).public().0;
+        assert_ok!(Keystore::issue_encryption_key(RuntimeOrigin::signed(1), EncryptionKeyType::X25519, old_key));
+
+        System::set_block_number(5);
+        assert_ok!(Keystore::issue_encryption_key(RuntimeOrigin::signed(1), EncryptionKeyType::Ed25519, new_key));
+        assert_eq!(
+            IssuedEncryptionKeys::<Test>::get(1),
+            Some(EncryptionKey { key_type: EncryptionKeyType::Ed25519, key: new_key, created: 5 })
+        );
+        System::assert_has_event(Event::EncryptionKeyRevoked { who: 1, key: old_key }.into());
+        System::assert_last_event(Event::EncryptionKeyIssued { who: 1 }.into());
+    });
+}
+
+#[test]
+fn revoke_encryption_key_works_and_emits_event() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_noop!(Keystore::revoke_encryption_key(RuntimeOrigin::signed(1)), Error::<Test>::NoEncryptionKey);
+        assert_ok!(Keystore::issue_encryption_key(RuntimeOrigin::signed(1), EncryptionKeyType::X25519, x25519_key()));
+        assert_ok!(Keystore::revoke_encryption_key(RuntimeOrigin::signed(1)));
+        assert_eq!(IssuedEncryptionKeys::<Test>::get(1), None);
+        System::assert_last_event(Event::EncryptionKeyRevoked { who: 1, key: x25519_key() }.into());
+    });
+}
+
+#[test]
+fn migration_v1_tags_existing_encryption_keys() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(7);
+        StorageVersion::new(0).put::<Keystore>();
+        let key = [3u8; 32];
+        frame_support::storage::unhashed::put(&IssuedEncryptionKeys::<Test>::hashed_key_for(1), &key);
+
+        crate::migrations::v1::MigrateV0ToV1::<Test>::on_runtime_upgrade();
+
+        assert_eq!(
+            IssuedEncryptionKeys::<Test>::get(1),
+            Some(EncryptionKey { key_type: EncryptionKeyType::X25519, key, created: 7 })
+        );
+        assert_eq!(Keystore::on_chain_storage_version(), StorageVersion::new(1));
+    });
+}
+
+fn sign_proof(who: u64, public: sr25519::Public) -> KeyProof {
+    let payload = Keystore::proof_payload(&who, Keystore::proof_nonce(who));
+    let signature = sp_io::crypto::sr25519_sign(KEY_TYPE, &public, &payload).unwrap();
+    KeyProof::Sr25519 { public, signature }
+}
+
+#[test]
+fn announce_key_with_proof_marks_key_proven() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(3);
+        let public = sp_io::crypto::sr25519_generate(KEY_TYPE, None);
+        let fingerprint = BoundedVec::<u8, ConstU32<1024>>::try_from(public.0.to_vec()).unwrap();
+        let location = BoundedVec::<u8, ConstU32<1024>>::try_from(b"Skywalker".to_vec()).unwrap();
+        assert_ok!(Keystore::announce_key_with_proof(
+            RuntimeOrigin::signed(1),
+            fingerprint.clone(),
+            location.clone(),
+            sign_proof(1, public)
+        ));
+
+        assert_eq!(IssuedKeys::<Test>::get(1, &fingerprint), Some(location));
+        assert_eq!(ProvenKeys::<Test>::get(1, &fingerprint), Some(3));
+        assert!(Keystore::is_key_proven(&1, &fingerprint));
+        assert_eq!(Keystore::proof_nonce(1), 1);
+        System::assert_last_event(Event::KeyAnnounced { key: fingerprint.clone(), who: 1 }.into());
+
+        // Revoking the key drops the mark along with it.
+        assert_ok!(Keystore::revoke_key(RuntimeOrigin::signed(1), fingerprint.clone()));
+        assert!(!Keystore::is_key_proven(&1, &fingerprint));
+    });
+}
+
+#[test]
+fn announce_key_with_proof_supports_ed25519_and_ecdsa() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let location = BoundedVec::<u8, ConstU32<1024>>::try_from(b"Skywalker".to_vec()).unwrap();
+
+        let public = sp_io::crypto::ed25519_generate(KEY_TYPE, None);
+        let signature = sp_io::crypto::ed25519_sign(KEY_TYPE, &public, &Keystore::proof_payload(&1, 0)).unwrap();
+        let fingerprint = BoundedVec::<u8, ConstU32<1024>>::try_from(public.0.to_vec()).unwrap();
+        assert_ok!(Keystore::announce_key_with_proof(
+            RuntimeOrigin::signed(1),
+            fingerprint.clone(),
+            location.clone(),
+            KeyProof::Ed25519 { public, signature }
+        ));
+        assert!(Keystore::is_key_proven(&1, &fingerprint));
+
+        let public = sp_io::crypto::ecdsa_generate(KEY_TYPE, None);
+        let signature = sp_io::crypto::ecdsa_sign(KEY_TYPE, &public, &Keystore::proof_payload(&1, 1)).unwrap();
+        let fingerprint = BoundedVec::<u8, ConstU32<1024>>::try_from(public.0.to_vec()).unwrap();
+        assert_ok!(Keystore::announce_key_with_proof(
+            RuntimeOrigin::signed(1),
+            fingerprint.clone(),
+            location,
+            KeyProof::Ecdsa { public, signature }
+        ));
+        assert!(Keystore::is_key_proven(&1, &fingerprint));
+    });
+}
+
+#[test]
+fn announce_key_with_proof_rejects_foreign_fingerprint() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let public = sp_io::crypto::sr25519_generate(KEY_TYPE, None);
+        let fingerprint = BoundedVec::<u8, ConstU32<1024>>::try_from(b"Luke".to_vec()).unwrap();
+        let location = BoundedVec::<u8, ConstU32<1024>>::try_from(b"Skywalker".to_vec()).unwrap();
+        assert_noop!(
+            Keystore::announce_key_with_proof(RuntimeOrigin::signed(1), fingerprint, location, sign_proof(1, public)),
+            Error::<Test>::FingerprintMismatch
+        );
+    });
+}
+
+#[test]
+fn announce_key_with_proof_rejects_invalid_signatures() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let public = sp_io::crypto::sr25519_generate(KEY_TYPE, None);
+        let fingerprint = BoundedVec::<u8, ConstU32<1024>>::try_from(public.0.to_vec()).unwrap();
+        let location = BoundedVec::<u8, ConstU32<1024>>::try_from(b"Skywalker".to_vec()).unwrap();
+
+        // Signed for another account.
+        assert_noop!(
+            Keystore::announce_key_with_proof(
+                RuntimeOrigin::signed(1),
+                fingerprint.clone(),
+                location.clone(),
+                sign_proof(2, public)
+            ),
+            Error::<Test>::InvalidProof
+        );
+
+        // Signed for a stale nonce.
+        let proof = sign_proof(1, public);
+        assert_ok!(Keystore::announce_key_with_proof(
+            RuntimeOrigin::signed(1),
+            fingerprint.clone(),
+            location.clone(),
+            proof.clone()
+        ));
+        assert_ok!(Keystore::rotate_key(
+            RuntimeOrigin::signed(1),
+            fingerprint,
+            BoundedVec::<u8, ConstU32<1024>>::try_from(b"Leia".to_vec()).unwrap(),
+            location.clone()
+        ));
+        let other = sp_io::crypto::sr25519_generate(KEY_TYPE, None);
+        let other_fingerprint = BoundedVec::<u8, ConstU32<1024>>::try_from(other.0.to_vec()).unwrap();
+        let payload = Keystore::proof_payload(&1, 0);
+        let stale = KeyProof::Sr25519 {
+            public: other,
+            signature: sp_io::crypto::sr25519_sign(KEY_TYPE, &other, &payload).unwrap(),
+        };
+        assert_noop!(
+            Keystore::announce_key_with_proof(RuntimeOrigin::signed(1), other_fingerprint, location, stale),
+            Error::<Test>::InvalidProof
+        );
+    });
+}
+
+#[test]
+fn key_owners_index_follows_announcements() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let fingerprint = BoundedVec::<u8, ConstU32<1024>>::try_from(b"Luke".to_vec()).unwrap();
+        let new_fingerprint = BoundedVec::<u8, ConstU32<1024>>::try_from(b"Leia".to_vec()).unwrap();
+        let location = BoundedVec::<u8, ConstU32<1024>>::try_from(b"Skywalker".to_vec()).unwrap();
+        assert_ok!(Keystore::announce_key(RuntimeOrigin::signed(1), fingerprint.clone(), location.clone()));
+        assert_ok!(Keystore::announce_key(RuntimeOrigin::signed(2), fingerprint.clone(), location.clone()));
+        let mut owners = Keystore::owners_of(b"Luke");
+        owners.sort();
+        assert_eq!(owners, vec![1, 2]);
+        assert_eq!(Keystore::keys_of(&1), vec![(b"Luke".to_vec(), b"Skywalker".to_vec())]);
+
+        assert_ok!(Keystore::rotate_key(RuntimeOrigin::signed(1), fingerprint.clone(), new_fingerprint, location));
+        assert_eq!(Keystore::owners_of(b"Luke"), vec![2]);
+        assert_eq!(Keystore::owners_of(b"Leia"), vec![1]);
+        assert_eq!(Keystore::keys_of(&1), vec![(b"Leia".to_vec(), b"Skywalker".to_vec())]);
+
+        assert_ok!(Keystore::revoke_key(RuntimeOrigin::signed(2), fingerprint));
+        assert!(Keystore::owners_of(b"Luke").is_empty());
+        assert!(Keystore::keys_of(&2).is_empty());
+    });
+}
+
+#[test]
+fn expired_key_leaves_key_owners_index() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let fingerprint = BoundedVec::<u8, ConstU32<1024>>::try_from(b"Luke".to_vec()).unwrap();
+        let location = BoundedVec::<u8, ConstU32<1024>>::try_from(b"Skywalker".to_vec()).unwrap();
+        assert_ok!(Keystore::announce_key_with_validity(RuntimeOrigin::signed(1), fingerprint, location, None, Some(2)));
+        assert_eq!(Keystore::owners_of(b"Luke"), vec![1]);
+
+        System::set_block_number(2);
+        Keystore::on_idle(2, Weight::MAX);
+        assert!(Keystore::owners_of(b"Luke").is_empty());
+    });
+}
+
+#[test]
+fn keys_outside_their_validity_are_not_listed() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let key = |name: &[u8]| BoundedVec::<u8, ConstU32<1024>>::try_from(name.to_vec()).unwrap();
+        let location = key(b"Skywalker");
+        assert_ok!(Keystore::announce_key_with_validity(RuntimeOrigin::signed(1), key(b"Luke"), location.clone(), None, Some(3)));
+        assert_ok!(Keystore::announce_key_with_validity(RuntimeOrigin::signed(1), key(b"Leia"), location, Some(3), None));
+        assert_eq!(Keystore::keys_of(&1), vec![(b"Luke".to_vec(), b"Skywalker".to_vec())]);
+        assert!(Keystore::owners_of(b"Leia").is_empty());
+
+        // Luke has expired but is not swept yet; Leia has become valid.
+        System::set_block_number(3);
+        assert!(IssuedKeys::<Test>::contains_key(1, key(b"Luke")));
+        assert_eq!(Keystore::keys_of(&1), vec![(b"Leia".to_vec(), b"Skywalker".to_vec())]);
+        assert!(Keystore::owners_of(b"Luke").is_empty());
+        assert_eq!(Keystore::owners_of(b"Leia"), vec![1]);
+    });
+}
+
+#[test]
+fn migration_v2_backfills_key_owners() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        StorageVersion::new(1).put::<Keystore>();
+        let fingerprint = BoundedVec::<u8, ConstU32<1024>>::try_from(b"Luke".to_vec()).unwrap();
+        let location = BoundedVec::<u8, ConstU32<1024>>::try_from(b"Skywalker".to_vec()).unwrap();
+        IssuedKeys::<Test>::insert(1, &fingerprint, &location);
+        IssuedKeys::<Test>::insert(2, &fingerprint, &location);
+        assert!(Keystore::owners_of(b"Luke").is_empty());
+
+        crate::migrations::v2::MigrateV1ToV2::<Test>::on_runtime_upgrade();
+
+        let mut owners = Keystore::owners_of(b"Luke");
+        owners.sort();
+        assert_eq!(owners, vec![1, 2]);
+        assert_eq!(Keystore::on_chain_storage_version(), StorageVersion::new(2));
+    });
+}
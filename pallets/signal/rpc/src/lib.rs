@@ -0,0 +1,96 @@
+//! RPC interface for the Fennel signal pallet.
+
+use std::sync::Arc;
+
+use codec::Codec;
+use jsonrpsee::{
+    core::RpcResult,
+    proc_macros::rpc,
+    types::{error::ErrorObject, ErrorObjectOwned},
+};
+use pallet_signal_runtime_api::ServiceEndpoint;
+use serde::{Deserialize, Serialize};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_core::Bytes;
+use sp_runtime::traits::Block as BlockT;
+
+pub use pallet_signal_runtime_api::SignalApi as SignalRuntimeApi;
+
+/// A live service endpoint as returned over RPC.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ServiceEndpointInfo<AccountId, BlockNumber> {
+    /// The account providing the service.
+    pub provider: AccountId,
+    /// Where the service can be reached.
+    pub urls: Vec<Bytes>,
+    /// The block in which the provider last registered, updated or confirmed the service.
+    pub last_heartbeat: BlockNumber,
+}
+
+impl<AccountId, BlockNumber> From<ServiceEndpoint<AccountId, BlockNumber>>
+    for ServiceEndpointInfo<AccountId, BlockNumber>
+{
+    fn from(endpoint: ServiceEndpoint<AccountId, BlockNumber>) -> Self {
+        Self {
+            provider: endpoint.provider,
+            urls: endpoint.urls.into_iter().map(Into::into).collect(),
+            last_heartbeat: endpoint.last_heartbeat,
+        }
+    }
+}
+
+/// Signal RPC methods.
+#[rpc(client, server)]
+pub trait SignalApi<BlockHash, AccountId, BlockNumber> {
+    /// Returns the live registered endpoints of a service.
+    #[method(name = "signal_liveEndpoints")]
+    fn live_endpoints(
+        &self,
+        service_identifier: Bytes,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<ServiceEndpointInfo<AccountId, BlockNumber>>>;
+}
+
+/// Provides RPC methods to query signals and registered services.
+pub struct Signals<C, B> {
+    /// Shared reference to the client.
+    client: Arc<C>,
+    _marker: std::marker::PhantomData<B>,
+}
+
+impl<C, B> Signals<C, B> {
+    /// Creates a new instance of the Signals RPC helper.
+    pub fn new(client: Arc<C>) -> Self {
+        Self { client, _marker: Default::default() }
+    }
+}
+
+/// Error code returned when the runtime API call fails.
+const RUNTIME_ERROR: i32 = 1;
+
+fn runtime_error(error: impl ToString) -> ErrorObjectOwned {
+    ErrorObject::owned(RUNTIME_ERROR, "Unable to query services.", Some(error.to_string()))
+}
+
+impl<C, Block, AccountId, BlockNumber> SignalApiServer<<Block as BlockT>::Hash, AccountId, BlockNumber>
+    for Signals<C, Block>
+where
+    Block: BlockT,
+    C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+    C::Api: SignalRuntimeApi<Block, AccountId, BlockNumber>,
+    AccountId: Codec + Serialize + Send + Sync + 'static,
+    BlockNumber: Codec + Serialize + Send + Sync + 'static,
+{
+    fn live_endpoints(
+        &self,
+        service_identifier: Bytes,
+        at: Option<Block::Hash>,
+    ) -> RpcResult<Vec<ServiceEndpointInfo<AccountId, BlockNumber>>> {
+        let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+        let endpoints =
+            self.client.runtime_api().live_endpoints(at_hash, service_identifier.to_vec()).map_err(runtime_error)?;
+        Ok(endpoints.into_iter().map(Into::into).collect())
+    }
+}
@@ -0,0 +1,27 @@
+//! Runtime API definition for the Fennel signal pallet.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+use codec::Codec;
+
+pub use pallet_signal::{RatingAggregate, ServiceEndpoint, ServiceSignal};
+
+sp_api::decl_runtime_apis! {
+    /// Read access to rating signals, persisted service signals and registered services without
+    /// building raw storage keys.
+    pub trait SignalApi<AccountId, BlockNumber>
+    where
+        AccountId: Codec,
+        BlockNumber: Codec,
+    {
+        /// Returns the running totals over the ratings `target` has received.
+        fn rating_aggregate(target: Vec<u8>) -> RatingAggregate;
+        /// Returns the live persisted service signals announcing `service_identifier`.
+        fn service_signals(service_identifier: Vec<u8>) -> Vec<ServiceSignal<AccountId, BlockNumber>>;
+        /// Returns the live registered endpoints of `service_identifier`.
+        fn live_endpoints(service_identifier: Vec<u8>) -> Vec<ServiceEndpoint<AccountId, BlockNumber>>;
+    }
+}
@@ -5,16 +5,49 @@ use super::*;
 use crate::Pallet as Signal;
 
 use frame_benchmarking::{v2::*};
-use frame_support::{traits::Currency, BoundedVec};
-use frame_support::traits::Get;
+use frame_support::{traits::{fungible::Mutate, Currency}, BoundedVec};
+use frame_support::traits::{EnsureOrigin, Get};
 use frame_system::RawOrigin;
-use frame_support::sp_runtime::traits::Bounded;
+use frame_support::sp_runtime::traits::{Bounded, One, Saturating};
 use scale_info::prelude::{format, vec};
 
 pub fn assert_last_event<T: Config>(generic_event: <T as Config>::RuntimeEvent) {
 	frame_system::Pallet::<T>::assert_last_event(generic_event.into());
 }
 
+/// Gives `who` enough funds to cover the deposits of several persisted signals of the largest
+/// size.
+fn fund_signal_deposits<T: Config>(who: &T::AccountId) {
+	let deposit = Signal::<T>::signal_deposit(2 * T::MaxSize::get() as usize);
+	T::DepositCurrency::set_balance(who, deposit.saturating_mul(10u32.into()));
+}
+
+/// A bounded vector of the largest size allowed for signal topics and contents.
+fn max_size_bytes<T: Config>() -> BoundedVec<u8, <T as pallet::Config>::MaxSize> {
+	BoundedVec::truncate_from(vec![0u8; T::MaxSize::get() as usize])
+}
+
+/// The largest set of service URLs allowed, each of the largest size.
+fn max_service_urls<T: Config>() -> ServiceUrlsOf<T> {
+	BoundedVec::truncate_from(vec![max_size_bytes::<T>(); T::MaxServiceUrls::get() as usize])
+}
+
+/// Gives `who` enough funds to cover the deposit of the largest service registration.
+fn fund_service_deposit<T: Config>(who: &T::AccountId) {
+	let bytes = (T::MaxServiceUrls::get() as usize).saturating_add(1).saturating_mul(T::MaxSize::get() as usize);
+	let deposit = Signal::<T>::signal_deposit(bytes);
+	T::DepositCurrency::set_balance(who, deposit.saturating_mul(10u32.into()));
+}
+
+/// A review reference of the largest size allowed.
+fn max_review<T: Config>() -> RatingAttachmentOf<T> {
+	RatingAttachment::Review(BoundedVec::truncate_from(vec![0u8; T::MaxReviewLength::get() as usize]))
+}
+
+fn integer_schema() -> ParameterSchema {
+	ParameterSchema { value_type: ParameterType::Integer, min: 0, max: u64::MAX }
+}
+
 #[benchmarks]
 mod benchmarks {
 	use super::*;
@@ -28,12 +61,12 @@ mod benchmarks {
             .map_err(|_| BenchmarkError::Stop("Failed to create parameter name"))?;
 		let caller: T::AccountId = whitelisted_caller();
         T::Currency::make_free_balance_be(&caller, DepositBalanceOf::<T>::from(10_000u32));
+        SignalParameterSchemas::<T>::insert(&name, integer_schema());
 
 		#[extrinsic_call]
-        _(RawOrigin::Signed(caller.clone()), name.clone(), 0);
+        _(RawOrigin::Signed(caller.clone()), name.clone(), ParameterValue::Integer(0));
 
-        assert!(SignalParameterList::<T>::contains_key(caller.clone(), name.clone()));
-        assert_eq!(SignalParameterList::<T>::get(caller.clone(), name.clone()), 0);
+        assert_eq!(SignalParameterList::<T>::get(caller.clone(), name.clone()), Some(ParameterValue::Integer(0)));
         assert_last_event::<T>(Event::SignalParameterSet { who: caller }.into());
 		Ok(())
 	}
@@ -46,16 +79,45 @@ mod benchmarks {
             .map_err(|_| BenchmarkError::Stop("Failed to create large parameter name - MaxSize might be too small"))?;
         let caller: T::AccountId = whitelisted_caller();
         T::Currency::make_free_balance_be(&caller, DepositBalanceOf::<T>::from(10_000u32));
+        SignalParameterSchemas::<T>::insert(&name, integer_schema());
 
         #[extrinsic_call]
-        set_signal_parameter(RawOrigin::Signed(caller.clone()), name.clone(), 42);
+        set_signal_parameter(RawOrigin::Signed(caller.clone()), name.clone(), ParameterValue::Integer(42));
 
-        assert!(SignalParameterList::<T>::contains_key(caller.clone(), name.clone()));
-        assert_eq!(SignalParameterList::<T>::get(caller.clone(), name.clone()), 42);
+        assert_eq!(SignalParameterList::<T>::get(caller.clone(), name.clone()), Some(ParameterValue::Integer(42)));
         assert_last_event::<T>(Event::SignalParameterSet { who: caller }.into());
         Ok(())
     }
 
+    #[benchmark]
+    fn set_parameter_schema() -> Result<(), BenchmarkError> {
+        let name = BoundedVec::<u8, <T as pallet::Config>::MaxSize>::try_from(b"TEST".to_vec())
+            .map_err(|_| BenchmarkError::Stop("Failed to create parameter name"))?;
+        let origin = T::SchemaOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, name.clone(), integer_schema());
+
+        assert_eq!(SignalParameterSchemas::<T>::get(&name), Some(integer_schema()));
+        assert_last_event::<T>(Event::SignalParameterSchemaSet { name, schema: integer_schema() }.into());
+        Ok(())
+    }
+
+    #[benchmark]
+    fn remove_parameter_schema() -> Result<(), BenchmarkError> {
+        let name = BoundedVec::<u8, <T as pallet::Config>::MaxSize>::try_from(b"TEST".to_vec())
+            .map_err(|_| BenchmarkError::Stop("Failed to create parameter name"))?;
+        let origin = T::SchemaOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        SignalParameterSchemas::<T>::insert(&name, integer_schema());
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, name.clone());
+
+        assert!(!SignalParameterSchemas::<T>::contains_key(&name));
+        assert_last_event::<T>(Event::SignalParameterSchemaRemoved { name }.into());
+        Ok(())
+    }
+
 	#[benchmark]
 	fn send_rating_signal() -> Result<(), BenchmarkError> {
         let target = BoundedVec::<u8, <T as pallet::Config>::MaxSize>::try_from(b"TEST".to_vec())
@@ -72,16 +134,16 @@ mod benchmarks {
             Signal::<T>::send_rating_signal(
                 RawOrigin::Signed(caller.clone()).into(),
                 loop_target,
-                3
+                3,
+                None
             )?;
         }
 
 		#[extrinsic_call]
-        _(RawOrigin::Signed(caller.clone()), target.clone(), 0);
+        _(RawOrigin::Signed(caller.clone()), target.clone(), 0, Some(max_review::<T>()));
 
-		assert!(RatingSignalList::<T>::contains_key(caller.clone(), target.clone()));
-        assert_eq!(RatingSignalList::<T>::get(caller.clone(), target.clone()), 0);
-        frame_system::Pallet::<T>::assert_has_event(<T as pallet::Config>::RuntimeEvent::from(Event::<T>::SignalLock { account: caller.clone(), amount: T::LockPrice::get().into() }).into());
+        assert_eq!(RatingSignalList::<T>::get(caller.clone(), target.clone()).map(|signal| signal.rating), Some(0));
+        frame_system::Pallet::<T>::assert_has_event(<T as pallet::Config>::RuntimeEvent::from(Event::<T>::SignalLock { account: caller.clone(), total: Signal::<T>::lock_amount(RatingCount::<T>::get(&caller)) }).into());
         frame_system::Pallet::<T>::assert_last_event(<T as pallet::Config>::RuntimeEvent::from(Event::<T>::RatingSignalSent { who: caller.clone() }).into());
 		Ok(())
 	}
@@ -96,11 +158,10 @@ mod benchmarks {
         T::Currency::make_free_balance_be(&caller, DepositBalanceOf::<T>::max_value());
 
         #[extrinsic_call]
-        send_rating_signal(RawOrigin::Signed(caller.clone()), target.clone(), 5);
+        send_rating_signal(RawOrigin::Signed(caller.clone()), target.clone(), 5, Some(max_review::<T>()));
 
-        assert!(RatingSignalList::<T>::contains_key(caller.clone(), target.clone()));
-        assert_eq!(RatingSignalList::<T>::get(caller.clone(), target.clone()), 5);
-        frame_system::Pallet::<T>::assert_has_event(<T as pallet::Config>::RuntimeEvent::from(Event::<T>::SignalLock { account: caller.clone(), amount: T::LockPrice::get().into() }).into());
+        assert_eq!(RatingSignalList::<T>::get(caller.clone(), target.clone()).map(|signal| signal.rating), Some(5));
+        frame_system::Pallet::<T>::assert_has_event(<T as pallet::Config>::RuntimeEvent::from(Event::<T>::SignalLock { account: caller.clone(), total: Signal::<T>::lock_amount(RatingCount::<T>::get(&caller)) }).into());
         frame_system::Pallet::<T>::assert_last_event(<T as pallet::Config>::RuntimeEvent::from(Event::<T>::RatingSignalSent { who: caller.clone() }).into());
         Ok(())
     }
@@ -121,7 +182,8 @@ mod benchmarks {
             Signal::<T>::send_rating_signal(
                 RawOrigin::Signed(caller.clone()).into(),
                 loop_target,
-                3
+                3,
+                None
             )?;
         }
         
@@ -129,14 +191,14 @@ mod benchmarks {
         Signal::<T>::send_rating_signal(
             RawOrigin::Signed(caller.clone()).into(), 
             target.clone(), 
-            3
+            3,
+                None
         )?;
 
 		#[extrinsic_call]
-        _(RawOrigin::Signed(caller.clone()), target.clone(), 1);
+        _(RawOrigin::Signed(caller.clone()), target.clone(), 1, Some(max_review::<T>()));
 
-        assert_eq!(RatingSignalList::<T>::get(caller.clone(), target.clone()), 1);
-        frame_system::Pallet::<T>::assert_has_event(<T as pallet::Config>::RuntimeEvent::from(Event::<T>::SignalLockExtended { account: caller.clone(), amount: T::LockPrice::get().into() }).into());
+        assert_eq!(RatingSignalList::<T>::get(caller.clone(), target.clone()).map(|signal| signal.rating), Some(1));
         frame_system::Pallet::<T>::assert_last_event(<T as pallet::Config>::RuntimeEvent::from(Event::<T>::RatingSignalUpdated { who: caller.clone() }).into());
 		Ok(())
 	}
@@ -157,7 +219,8 @@ mod benchmarks {
             Signal::<T>::send_rating_signal(
                 RawOrigin::Signed(caller.clone()).into(),
                 loop_target,
-                2
+                2,
+                None
             )?;
         }
         
@@ -165,7 +228,8 @@ mod benchmarks {
         Signal::<T>::send_rating_signal(
             RawOrigin::Signed(caller.clone()).into(), 
             target.clone(), 
-            2
+            2,
+                None
         )?;
 
 		#[extrinsic_call]
@@ -173,7 +237,7 @@ mod benchmarks {
 
         // Check for full removal, not just zeroing
         assert!(!RatingSignalList::<T>::contains_key(caller.clone(), target.clone()));
-        frame_system::Pallet::<T>::assert_has_event(<T as pallet::Config>::RuntimeEvent::from(Event::<T>::SignalUnlock { account: caller.clone() }).into());
+        frame_system::Pallet::<T>::assert_has_event(<T as pallet::Config>::RuntimeEvent::from(Event::<T>::SignalUnlock { account: caller.clone(), total: Signal::<T>::lock_amount(RatingCount::<T>::get(&caller)) }).into());
         frame_system::Pallet::<T>::assert_last_event(<T as pallet::Config>::RuntimeEvent::from(Event::<T>::RatingSignalRevoked { who: caller.clone() }).into());
 		Ok(())
 	}
@@ -267,5 +331,160 @@ mod benchmarks {
         Ok(())
     }
 
+	#[benchmark]
+	fn persist_signal() -> Result<(), BenchmarkError> {
+		let caller: T::AccountId = whitelisted_caller();
+		fund_signal_deposits::<T>(&caller);
+		let topic = max_size_bytes::<T>();
+		let ttl = T::MaxSignalTtl::get();
+		// Replacing a smaller signal that expires in another block frees its place there and
+		// grows the deposit.
+		Signal::<T>::persist_signal(RawOrigin::Signed(caller.clone()).into(), topic.clone(), BoundedVec::new(), One::one())?;
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), topic.clone(), max_size_bytes::<T>(), ttl);
+
+		let expires_at = frame_system::Pallet::<T>::block_number().saturating_add(ttl);
+		assert!(PersistedSignals::<T>::contains_key(&caller, &topic));
+		assert_last_event::<T>(Event::SignalPersisted { who: caller, topic, expires_at }.into());
+		Ok(())
+	}
+
+	#[benchmark]
+	fn persist_service_signal() -> Result<(), BenchmarkError> {
+		let caller: T::AccountId = whitelisted_caller();
+		fund_signal_deposits::<T>(&caller);
+		let service_identifier = max_size_bytes::<T>();
+		let ttl = T::MaxSignalTtl::get();
+		Signal::<T>::persist_service_signal(
+			RawOrigin::Signed(caller.clone()).into(),
+			service_identifier.clone(),
+			BoundedVec::new(),
+			One::one(),
+		)?;
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), service_identifier.clone(), max_size_bytes::<T>(), ttl);
+
+		let expires_at = frame_system::Pallet::<T>::block_number().saturating_add(ttl);
+		assert!(ServiceSignals::<T>::contains_key(&service_identifier, &caller));
+		assert_last_event::<T>(Event::SignalPersisted { who: caller, topic: service_identifier, expires_at }.into());
+		Ok(())
+	}
+
+	#[benchmark]
+	fn remove_persisted_signal() -> Result<(), BenchmarkError> {
+		let caller: T::AccountId = whitelisted_caller();
+		fund_signal_deposits::<T>(&caller);
+		let topic = max_size_bytes::<T>();
+		Signal::<T>::persist_service_signal(
+			RawOrigin::Signed(caller.clone()).into(),
+			topic.clone(),
+			max_size_bytes::<T>(),
+			T::MaxSignalTtl::get(),
+		)?;
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), topic.clone());
+
+		assert!(!PersistedSignals::<T>::contains_key(&caller, &topic));
+		assert_last_event::<T>(Event::PersistedSignalRemoved { who: caller, topic }.into());
+		Ok(())
+	}
+
+	#[benchmark]
+	fn expire_signal() -> Result<(), BenchmarkError> {
+		let caller: T::AccountId = whitelisted_caller();
+		fund_signal_deposits::<T>(&caller);
+		let topic = max_size_bytes::<T>();
+		let ttl = T::MaxSignalTtl::get();
+		Signal::<T>::persist_service_signal(
+			RawOrigin::Signed(caller.clone()).into(),
+			topic.clone(),
+			max_size_bytes::<T>(),
+			ttl,
+		)?;
+		let expires_at = frame_system::Pallet::<T>::block_number().saturating_add(ttl);
+
+		#[block]
+		{
+			Signal::<T>::expire_signal(&caller, &topic, expires_at);
+		}
+
+		assert!(!PersistedSignals::<T>::contains_key(&caller, &topic));
+		assert_last_event::<T>(Event::PersistedSignalExpired { who: caller, topic }.into());
+		Ok(())
+	}
+
+	#[benchmark]
+	fn register_service() -> Result<(), BenchmarkError> {
+		let caller: T::AccountId = whitelisted_caller();
+		fund_service_deposit::<T>(&caller);
+		let service_identifier = max_size_bytes::<T>();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), service_identifier.clone(), max_service_urls::<T>());
+
+		assert!(ServiceRegistry::<T>::contains_key(&service_identifier, &caller));
+		assert_last_event::<T>(Event::ServiceRegistered { service_identifier, provider: caller }.into());
+		Ok(())
+	}
+
+	#[benchmark]
+	fn update_service() -> Result<(), BenchmarkError> {
+		let caller: T::AccountId = whitelisted_caller();
+		fund_service_deposit::<T>(&caller);
+		let service_identifier = max_size_bytes::<T>();
+		let url = BoundedVec::truncate_from(b"https://fennellabs.com".to_vec());
+		Signal::<T>::register_service(
+			RawOrigin::Signed(caller.clone()).into(),
+			service_identifier.clone(),
+			BoundedVec::truncate_from(vec![url]),
+		)?;
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), service_identifier.clone(), max_service_urls::<T>());
+
+		assert_last_event::<T>(Event::ServiceUpdated { service_identifier, provider: caller }.into());
+		Ok(())
+	}
+
+	#[benchmark]
+	fn service_heartbeat() -> Result<(), BenchmarkError> {
+		let caller: T::AccountId = whitelisted_caller();
+		fund_service_deposit::<T>(&caller);
+		let service_identifier = max_size_bytes::<T>();
+		Signal::<T>::register_service(
+			RawOrigin::Signed(caller.clone()).into(),
+			service_identifier.clone(),
+			max_service_urls::<T>(),
+		)?;
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), service_identifier.clone());
+
+		assert_last_event::<T>(Event::ServiceHeartbeat { service_identifier, provider: caller }.into());
+		Ok(())
+	}
+
+	#[benchmark]
+	fn deregister_service() -> Result<(), BenchmarkError> {
+		let caller: T::AccountId = whitelisted_caller();
+		fund_service_deposit::<T>(&caller);
+		let service_identifier = max_size_bytes::<T>();
+		Signal::<T>::register_service(
+			RawOrigin::Signed(caller.clone()).into(),
+			service_identifier.clone(),
+			max_service_urls::<T>(),
+		)?;
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), service_identifier.clone());
+
+		assert!(!ServiceRegistry::<T>::contains_key(&service_identifier, &caller));
+		assert_last_event::<T>(Event::ServiceDeregistered { service_identifier, provider: caller }.into());
+		Ok(())
+	}
+
 	impl_benchmark_test_suite!(Signal, crate::mock::new_test_ext(), crate::mock::Test);
 }
@@ -11,22 +11,49 @@ mod tests;
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
 
+pub mod migrations;
 pub mod weights;
+
+extern crate alloc;
 pub use weights::*;
 
+/// Supplies the weight a rater's ratings carry in the trust-weighted rating aggregates.
+pub trait RatingWeight<AccountId> {
+	/// The weight ratings from `rater` carry, or `None` if they should not count towards the
+	/// weighted aggregates.
+	fn rating_weight(rater: &AccountId) -> Option<u32>;
+}
+
+impl<AccountId> RatingWeight<AccountId> for () {
+	fn rating_weight(_: &AccountId) -> Option<u32> {
+		None
+	}
+}
+
 #[frame_support::pallet]
 pub mod pallet {
 	use frame_support::{
         dispatch::DispatchResultWithPostInfo,
 		pallet_prelude::*,
-		traits::{Currency, LockIdentifier, LockableCurrency, WithdrawReasons},
+		traits::{
+			fungible::{Inspect, Mutate, MutateHold},
+			tokens::Precision,
+			Currency, LockIdentifier, LockableCurrency, WithdrawReasons,
+		},
 	};
 	use frame_system::pallet_prelude::*;
+	use fennel_expiry::ExpiryQueue;
 
-	use crate::weights::WeightInfo;
+	use crate::{weights::WeightInfo, RatingWeight};
+	use alloc::vec::Vec;
+	use core::cmp::Ordering;
+	use frame_support::sp_runtime::traits::{Saturating, Zero};
 
 	type BalanceOf<T> =
 		<<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+	pub(crate) type DepositOf<T> =
+		<<T as Config>::DepositCurrency as Inspect<<T as frame_system::Config>::AccountId>>::Balance;
+	type SignalKeyOf<T> = (<T as frame_system::Config>::AccountId, BoundedVec<u8, <T as Config>::MaxSize>);
 
 	#[pallet::config]
 	pub trait Config: frame_system::Config {
@@ -44,50 +71,382 @@ pub mod pallet {
 		/// The identifier for the lock used to store signal deposits.
 		// type LockId: Parameter + Member + MaxEncodedLen + Ord + Copy;
 		type LockId: Get<LockIdentifier>;
-		/// The price of a signal lock.
+		/// The amount locked for each active rating signal an account has sent.
 		type LockPrice: Get<BalanceOf<Self>>;
+		/// The weight each rater's ratings carry in the trust-weighted rating aggregates. Use
+		/// `()` to only keep unweighted aggregates.
+		type RatingWeight: RatingWeight<Self::AccountId>;
+		/// The origin allowed to register and remove signal parameter schemas.
+		type SchemaOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+		/// The maximum number of integers in a list-valued signal parameter.
+		#[pallet::constant]
+		type MaxParameterListLength: Get<u32>;
+		/// The overarching hold reason.
+		type RuntimeHoldReason: From<HoldReason>;
+		/// The currency used to hold persisted signal deposits.
+		type DepositCurrency: Mutate<Self::AccountId>
+			+ MutateHold<Self::AccountId, Reason = Self::RuntimeHoldReason>;
+		/// The deposit held for every persisted signal.
+		#[pallet::constant]
+		type SignalBaseDeposit: Get<DepositOf<Self>>;
+		/// The deposit held per byte of topic and content stored in a persisted signal.
+		#[pallet::constant]
+		type SignalByteDeposit: Get<DepositOf<Self>>;
+		/// The longest time to live, in blocks, a persisted signal can be given.
+		#[pallet::constant]
+		type MaxSignalTtl: Get<BlockNumberFor<Self>>;
+		/// The maximum number of persisted signals that can be scheduled to expire in the same
+		/// block.
+		#[pallet::constant]
+		type MaxSignalExpiriesPerBlock: Get<u32>;
+		/// The maximum number of URLs a provider can register for one service.
+		#[pallet::constant]
+		type MaxServiceUrls: Get<u32>;
+		/// The number of blocks after its last heartbeat that a registered service is still
+		/// considered live.
+		#[pallet::constant]
+		type ServiceLivenessPeriod: Get<BlockNumberFor<Self>>;
+		/// The highest rating allowed. Ratings range from zero up to and including this value.
+		#[pallet::constant]
+		type MaxRating: Get<u8>;
+		/// The maximum length of a review reference attached to a rating.
+		#[pallet::constant]
+		type MaxReviewLength: Get<u32>;
+	}
+
+	/// A reason for the pallet placing a hold on funds.
+	#[pallet::composite_enum]
+	pub enum HoldReason {
+		/// Funds are held as the storage deposit for a persisted signal.
+		SignalDeposit,
+		/// Funds are held as the storage deposit for a service registration.
+		ServiceDeposit,
+	}
+
+	/// The kind of value a signal parameter holds.
+	#[derive(
+		Encode, Decode, DecodeWithMemTracking, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen,
+	)]
+	pub enum ParameterType {
+		/// An unsigned integer.
+		Integer,
+		/// A string of bytes, such as UTF-8 text.
+		Text,
+		/// A list of unsigned integers.
+		IntegerList,
+	}
+
+	/// The shared definition of a signal parameter name.
+	#[derive(
+		Encode, Decode, DecodeWithMemTracking, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen,
+	)]
+	pub struct ParameterSchema {
+		/// The kind of value the parameter holds.
+		pub value_type: ParameterType,
+		/// The smallest value allowed. Applies to each element of an integer list, and to the
+		/// length of text.
+		pub min: u64,
+		/// The largest value allowed, in the same terms as `min`.
+		pub max: u64,
+	}
+
+	/// A signal parameter value.
+	#[derive(Encode, Decode, DecodeWithMemTracking, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub enum ParameterValue<Text, List> {
+		/// An unsigned integer.
+		Integer(u64),
+		/// A string of bytes.
+		Text(Text),
+		/// A list of unsigned integers.
+		IntegerList(List),
+	}
+
+	/// The signal parameter value of runtime `T`.
+	pub type ParameterValueOf<T> = ParameterValue<
+		BoundedVec<u8, <T as Config>::MaxSize>,
+		BoundedVec<u64, <T as Config>::MaxParameterListLength>,
+	>;
+
+	/// What a persisted signal carries.
+	#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub enum SignalContent<Bytes> {
+		/// A generic signal payload.
+		Signal(Bytes),
+		/// A service announcement. The signal's topic is the service identifier, and `url` is
+		/// where the service can be reached.
+		Service { url: Bytes },
+	}
+
+	/// A signal kept in storage until it expires.
+	#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub struct PersistedSignal<Bytes, Balance, BlockNumber> {
+		/// What the signal carries.
+		pub content: SignalContent<Bytes>,
+		/// The deposit held from the sender while the signal is stored.
+		pub deposit: Balance,
+		/// The block in which the signal expires and is removed.
+		pub expires_at: BlockNumber,
+	}
+
+	/// The persisted signal of runtime `T`.
+	pub type PersistedSignalOf<T> =
+		PersistedSignal<BoundedVec<u8, <T as Config>::MaxSize>, DepositOf<T>, BlockNumberFor<T>>;
+
+	/// A live persisted service signal, as returned by queries.
+	#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+	pub struct ServiceSignal<AccountId, BlockNumber> {
+		/// The account announcing the service.
+		pub provider: AccountId,
+		/// Where the service can be reached.
+		pub url: Vec<u8>,
+		/// The block in which the announcement expires.
+		pub expires_at: BlockNumber,
+	}
+
+	/// The service signal query result of runtime `T`.
+	pub type ServiceSignalOf<T> = ServiceSignal<<T as frame_system::Config>::AccountId, BlockNumberFor<T>>;
+
+	/// A provider's registration of a service.
+	#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub struct ServiceRegistration<Urls, Balance, BlockNumber> {
+		/// Where the service can be reached.
+		pub urls: Urls,
+		/// The deposit held from the provider while the service is registered.
+		pub deposit: Balance,
+		/// The block in which the provider last registered, updated or confirmed the service.
+		pub last_heartbeat: BlockNumber,
+	}
+
+	/// The service URLs of runtime `T`.
+	pub type ServiceUrlsOf<T> =
+		BoundedVec<BoundedVec<u8, <T as Config>::MaxSize>, <T as Config>::MaxServiceUrls>;
+
+	/// The service registration of runtime `T`.
+	pub type ServiceRegistrationOf<T> = ServiceRegistration<ServiceUrlsOf<T>, DepositOf<T>, BlockNumberFor<T>>;
+
+	/// Whether a registered service is considered reachable.
+	#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub enum ServiceStatus {
+		/// The provider sent a heartbeat within the last `ServiceLivenessPeriod` blocks.
+		Live,
+		/// The provider has not sent a heartbeat for `ServiceLivenessPeriod` blocks or more.
+		Stale,
+	}
+
+	/// A live service endpoint, as returned by queries.
+	#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+	pub struct ServiceEndpoint<AccountId, BlockNumber> {
+		/// The account providing the service.
+		pub provider: AccountId,
+		/// Where the service can be reached.
+		pub urls: Vec<Vec<u8>>,
+		/// The block in which the provider last registered, updated or confirmed the service.
+		pub last_heartbeat: BlockNumber,
+	}
+
+	/// The service endpoint query result of runtime `T`.
+	pub type ServiceEndpointOf<T> = ServiceEndpoint<<T as frame_system::Config>::AccountId, BlockNumberFor<T>>;
+
+	/// Evidence attached to a rating signal.
+	#[derive(Encode, Decode, DecodeWithMemTracking, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub enum RatingAttachment<Reference> {
+		/// The hash of the content the rating is about.
+		ContentHash([u8; 32]),
+		/// A short reference to a review, such as an identifier or a URI.
+		Review(Reference),
+	}
+
+	/// The rating attachment of runtime `T`.
+	pub type RatingAttachmentOf<T> = RatingAttachment<BoundedVec<u8, <T as Config>::MaxReviewLength>>;
+
+	/// A rating an account has given a target.
+	#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub struct RatingSignal<Attachment, BlockNumber> {
+		/// The rating, between zero and `MaxRating`.
+		pub rating: u8,
+		/// Evidence backing the rating, if any was given.
+		pub attachment: Option<Attachment>,
+		/// The block in which the rating was sent or last updated.
+		pub updated_at: BlockNumber,
+	}
+
+	/// The rating signal of runtime `T`.
+	pub type RatingSignalOf<T> = RatingSignal<RatingAttachmentOf<T>, BlockNumberFor<T>>;
+
+	/// Running totals over the ratings a target has received.
+	#[derive(
+		Encode, Decode, Clone, Copy, Default, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen,
+	)]
+	pub struct RatingAggregate {
+		/// The number of ratings.
+		pub count: u32,
+		/// The sum of all ratings.
+		pub sum: u64,
+		/// The sum of the weights of the ratings that carry one. Zero if no rater had a weight.
+		pub total_weight: u64,
+		/// The sum of each weighted rating multiplied by its weight. Dividing by `total_weight`
+		/// gives the trust-weighted average.
+		pub weighted_sum: u64,
+	}
+
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(4);
+
+	/// The `SignalExpiries` queue swept in idle time.
+	pub struct SignalExpiryQueue<T>(PhantomData<T>);
+
+	impl<T: Config> ExpiryQueue for SignalExpiryQueue<T> {
+		type BlockNumber = BlockNumberFor<T>;
+		type Item = SignalKeyOf<T>;
+		type MaxPerBlock = T::MaxSignalExpiriesPerBlock;
+		type Buckets = SignalExpiries<T>;
+		type Pending = PendingSignalExpiries<T>;
+		type Cursor = SignalExpiryCursor<T>;
+		type DbWeight = T::DbWeight;
 	}
 
 	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
 	#[pallet::storage]
 	#[pallet::getter(fn rating_signal_list)]
-	/// Maps identity numbers to a signal transaction hash and a rating number.
+	/// Maps raters and targets, usually transaction hashes, to the rating the rater gave.
 	pub type RatingSignalList<T: Config> = StorageDoubleMap<
 		_,
 		Blake2_128Concat,
 		T::AccountId,
 		Blake2_128Concat,
 		BoundedVec<u8, T::MaxSize>,
-		u8,
-		ValueQuery,
+		RatingSignalOf<T>,
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn rating_count)]
+	/// The number of active rating signals each account has sent. The account's signal lock is
+	/// `LockPrice` times this number.
+	pub type RatingCount<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, u32, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn rating_aggregate)]
+	/// Maps rating targets to running totals over the ratings they have received.
+	pub type RatingAggregates<T: Config> =
+		StorageMap<_, Blake2_128Concat, BoundedVec<u8, T::MaxSize>, RatingAggregate, ValueQuery>;
+
+	#[pallet::storage]
+	/// Maps raters and targets to the weight the rating counted with in `RatingAggregates`, for
+	/// ratings that carry one.
+	pub type RatingWeights<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		Blake2_128Concat,
+		BoundedVec<u8, T::MaxSize>,
+		u32,
 	>;
 
+	#[pallet::storage]
+	#[pallet::getter(fn signal_parameter_schema)]
+	/// Maps signal parameter names to the schema their values must follow.
+	pub type SignalParameterSchemas<T: Config> =
+		StorageMap<_, Blake2_128Concat, BoundedVec<u8, T::MaxSize>, ParameterSchema>;
+
 	#[pallet::storage]
 	#[pallet::getter(fn signal_paramter_list)]
-	/// Maps identity numbers to a signal transaction hash and a rating number.
+	/// Maps accounts and signal parameter names to the value the account has set.
 	pub type SignalParameterList<T: Config> = StorageDoubleMap<
 		_,
 		Blake2_128Concat,
 		T::AccountId,
 		Blake2_128Concat,
 		BoundedVec<u8, T::MaxSize>,
-		u8,
+		ParameterValueOf<T>,
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn persisted_signal)]
+	/// Maps senders and topics to the signal the sender has persisted under that topic.
+	pub type PersistedSignals<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		Blake2_128Concat,
+		BoundedVec<u8, T::MaxSize>,
+		PersistedSignalOf<T>,
+	>;
+
+	#[pallet::storage]
+	/// Maps service identifiers and senders to `()` for every persisted service signal, so the
+	/// announcements for a service can be listed without scanning `PersistedSignals`.
+	pub type ServiceSignals<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		BoundedVec<u8, T::MaxSize>,
+		Blake2_128Concat,
+		T::AccountId,
+		(),
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn signal_expiries)]
+	/// Maps blocks to the persisted signals that expire in them.
+	pub type SignalExpiries<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		BlockNumberFor<T>,
+		BoundedVec<SignalKeyOf<T>, T::MaxSignalExpiriesPerBlock>,
 		ValueQuery,
 	>;
 
+	#[pallet::storage]
+	#[pallet::getter(fn scheduled_signal_expiry)]
+	/// Maps senders and topics to the block whose `SignalExpiries` entry holds their persisted
+	/// signal. Each persisted signal is scheduled exactly once.
+	pub type ScheduledSignalExpiries<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		Blake2_128Concat,
+		BoundedVec<u8, T::MaxSize>,
+		BlockNumberFor<T>,
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn pending_signal_expiries)]
+	/// The number of persisted signals held in `SignalExpiries` that have not been swept yet.
+	pub type PendingSignalExpiries<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn signal_expiry_cursor)]
+	/// The next block whose expiring persisted signals have not been swept yet, while any are
+	/// pending.
+	pub type SignalExpiryCursor<T: Config> = StorageValue<_, BlockNumberFor<T>>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn service_registration)]
+	/// Maps service identifiers and providers to the provider's registration of the service.
+	pub type ServiceRegistry<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		BoundedVec<u8, T::MaxSize>,
+		Blake2_128Concat,
+		T::AccountId,
+		ServiceRegistrationOf<T>,
+	>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
 		/// Indicates that a signal parameter has been set.
         SignalParameterSet { who: T::AccountId },
-		/// Indicates that a signal lock has been created.
-        SignalLock { account: <T as frame_system::Config>::AccountId, amount: BalanceOf<T> },
-		/// Indicates that a signal lock has been extended.
-        SignalLockExtended { account: <T as frame_system::Config>::AccountId, amount: BalanceOf<T> },
-		/// Indicates that a signal lock has been removed.
-        SignalUnlock { account: <T as frame_system::Config>::AccountId },
+		/// Indicates that the schema for a signal parameter name has been registered or changed.
+        SignalParameterSchemaSet { name: BoundedVec<u8, T::MaxSize>, schema: ParameterSchema },
+		/// Indicates that the schema for a signal parameter name has been removed.
+        SignalParameterSchemaRemoved { name: BoundedVec<u8, T::MaxSize> },
+		/// Indicates that the signal lock of `account` grew to `total` for a new rating signal.
+        SignalLock { account: <T as frame_system::Config>::AccountId, total: BalanceOf<T> },
+		/// Indicates that a rating signal's share of the signal lock has been released, leaving
+		/// `total` locked.
+        SignalUnlock { account: <T as frame_system::Config>::AccountId, total: BalanceOf<T> },
 		/// Represents a signal sent by an identity.
         SignalSent { signal: BoundedVec<u8, T::MaxSize>, who: T::AccountId },
 		/// Represents a signal sent by an identity for a particular application or service.
@@ -98,6 +457,20 @@ pub mod pallet {
         RatingSignalUpdated { who: T::AccountId },
 		/// Indicates that an identity revoked a rating signal.
         RatingSignalRevoked { who: T::AccountId },
+		/// Indicates that a signal was stored under `topic` until `expires_at`.
+        SignalPersisted { who: T::AccountId, topic: BoundedVec<u8, T::MaxSize>, expires_at: BlockNumberFor<T> },
+		/// Indicates that a persisted signal was removed by its sender.
+        PersistedSignalRemoved { who: T::AccountId, topic: BoundedVec<u8, T::MaxSize> },
+		/// Indicates that a persisted signal reached its expiry block and was removed.
+        PersistedSignalExpired { who: T::AccountId, topic: BoundedVec<u8, T::MaxSize> },
+		/// Indicates that a provider registered a service.
+        ServiceRegistered { service_identifier: BoundedVec<u8, T::MaxSize>, provider: T::AccountId },
+		/// Indicates that a provider replaced the URLs of a registered service.
+        ServiceUpdated { service_identifier: BoundedVec<u8, T::MaxSize>, provider: T::AccountId },
+		/// Indicates that a provider confirmed a registered service is still reachable.
+        ServiceHeartbeat { service_identifier: BoundedVec<u8, T::MaxSize>, provider: T::AccountId },
+		/// Indicates that a provider withdrew a service registration.
+        ServiceDeregistered { service_identifier: BoundedVec<u8, T::MaxSize>, provider: T::AccountId },
 	}
 
 	#[pallet::error]
@@ -108,90 +481,366 @@ pub mod pallet {
 		RatingSignalAlreadyExists,
 		/// Requested rating signal does not exist.
 		RatingSignalDoesNotExist,
+		/// The rating is higher than `MaxRating`.
+		InvalidRating,
+		/// No schema is registered for the signal parameter name.
+		UnknownSignalParameter,
+		/// The value does not have the type or lie in the range the parameter's schema allows.
+		InvalidSignalParameter,
+		/// The schema's minimum is larger than its maximum.
+		InvalidParameterSchema,
+		/// The time to live is zero or longer than `MaxSignalTtl`.
+		InvalidSignalTtl,
+		/// Too many persisted signals are already scheduled to expire in the requested block and
+		/// the `MAX_EXPIRY_DELAY` blocks after it.
+		TooManySignalExpiries,
+		/// The sender has no signal persisted under the topic.
+		PersistedSignalDoesNotExist,
+		/// The origin has already registered the service.
+		ServiceAlreadyRegistered,
+		/// The origin has not registered the service.
+		ServiceNotRegistered,
+		/// A service must be registered with at least one URL.
+		NoServiceUrls,
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_idle(n: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+			Self::sweep_expired_signals(n, remaining_weight)
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// The amount locked for an account while it has `count` active rating signals.
+		pub fn lock_amount(count: u32) -> BalanceOf<T> {
+			T::LockPrice::get().saturating_mul(count.into())
+		}
+
+		/// Sets the signal lock of `who` to cover `count` active rating signals.
+		pub(crate) fn set_rating_lock(who: &T::AccountId, count: u32) {
+			if count == 0 {
+				T::Currency::remove_lock(T::LockId::get(), who);
+			} else {
+				T::Currency::set_lock(T::LockId::get(), who, Self::lock_amount(count), WithdrawReasons::all());
+			}
+		}
+
+		/// Whether `value` has the type `schema` requires and lies within its range.
+		pub fn schema_accepts(schema: &ParameterSchema, value: &ParameterValueOf<T>) -> bool {
+			let in_range = |value: u64| schema.min <= value && value <= schema.max;
+			match (schema.value_type, value) {
+				(ParameterType::Integer, ParameterValue::Integer(value)) => in_range(*value),
+				(ParameterType::Text, ParameterValue::Text(text)) => in_range(text.len() as u64),
+				(ParameterType::IntegerList, ParameterValue::IntegerList(list)) =>
+					list.iter().all(|value| in_range(*value)),
+				_ => false,
+			}
+		}
+
+		/// Returns the rating totals for `target`, or empty totals if it has no ratings.
+		pub fn rating_aggregate_of(target: Vec<u8>) -> RatingAggregate {
+			BoundedVec::<u8, T::MaxSize>::try_from(target).map(<RatingAggregates<T>>::get).unwrap_or_default()
+		}
+
+		/// Adds a rating from `who` to the totals for `target`, weighted by `who`'s current weight.
+		fn add_to_aggregate(who: &T::AccountId, target: &BoundedVec<u8, T::MaxSize>, rating: u8) {
+			let weight = T::RatingWeight::rating_weight(who);
+			<RatingAggregates<T>>::mutate(target, |aggregate| {
+				aggregate.count.saturating_inc();
+				aggregate.sum.saturating_accrue(rating.into());
+				if let Some(weight) = weight {
+					aggregate.total_weight.saturating_accrue(weight.into());
+					aggregate.weighted_sum.saturating_accrue(u64::from(weight).saturating_mul(rating.into()));
+				}
+			});
+			if let Some(weight) = weight {
+				<RatingWeights<T>>::insert(who, target, weight);
+			}
+		}
+
+		/// Takes a rating from `who` out of the totals for `target`, with the weight it was added
+		/// with.
+		fn remove_from_aggregate(who: &T::AccountId, target: &BoundedVec<u8, T::MaxSize>, rating: u8) {
+			let weight = <RatingWeights<T>>::take(who, target);
+			<RatingAggregates<T>>::mutate_exists(target, |maybe_aggregate| {
+				let Some(aggregate) = maybe_aggregate else { return };
+				aggregate.count.saturating_dec();
+				aggregate.sum.saturating_reduce(rating.into());
+				if let Some(weight) = weight {
+					aggregate.total_weight.saturating_reduce(weight.into());
+					aggregate.weighted_sum.saturating_reduce(u64::from(weight).saturating_mul(rating.into()));
+				}
+				if aggregate.count == 0 {
+					*maybe_aggregate = None;
+				}
+			});
+		}
+
+		/// The deposit held for a persisted signal storing `bytes` bytes of topic and content.
+		pub fn signal_deposit(bytes: usize) -> DepositOf<T> {
+			T::SignalByteDeposit::get().saturating_mul((bytes as u32).into()).saturating_add(T::SignalBaseDeposit::get())
+		}
+
+		/// Returns the live persisted service signals announcing `service_identifier`.
+		pub fn live_service_signals(service_identifier: Vec<u8>) -> Vec<ServiceSignalOf<T>> {
+			let Ok(service_identifier) = BoundedVec::<u8, T::MaxSize>::try_from(service_identifier) else {
+				return Vec::new();
+			};
+			let now = frame_system::Pallet::<T>::block_number();
+			<ServiceSignals<T>>::iter_key_prefix(&service_identifier)
+				.filter_map(|provider| {
+					let signal = <PersistedSignals<T>>::get(&provider, &service_identifier)?;
+					let SignalContent::Service { url } = signal.content else { return None };
+					(now < signal.expires_at).then(|| ServiceSignal {
+						provider,
+						url: url.into_inner(),
+						expires_at: signal.expires_at,
+					})
+				})
+				.collect()
+		}
+
+		/// Stores `content` under (`who`, `topic`) for `ttl` blocks, replacing any signal already
+		/// there and holding or releasing the difference in deposit. The replaced signal's place in
+		/// `SignalExpiries` is freed. Returns the expiry block.
+		fn persist(
+			who: &T::AccountId,
+			topic: &BoundedVec<u8, T::MaxSize>,
+			content: SignalContent<BoundedVec<u8, T::MaxSize>>,
+			ttl: BlockNumberFor<T>,
+		) -> Result<BlockNumberFor<T>, DispatchError> {
+			ensure!(!ttl.is_zero() && ttl <= T::MaxSignalTtl::get(), Error::<T>::InvalidSignalTtl);
+			let now = frame_system::Pallet::<T>::block_number();
+			let expires_at = now.saturating_add(ttl);
+			let previous = <PersistedSignals<T>>::get(who, topic);
+			if previous.is_some() {
+				Self::unschedule_signal_expiry(who, topic);
+			}
+			Self::schedule_signal_expiry(who, topic, expires_at, now)?;
+
+			let content_len = match &content {
+				SignalContent::Signal(signal) => signal.len(),
+				SignalContent::Service { url } => url.len(),
+			};
+			let required = Self::signal_deposit(topic.len().saturating_add(content_len));
+			let held = previous.as_ref().map_or(Zero::zero(), |signal| signal.deposit);
+			let deposit = Self::resize_deposit(who, HoldReason::SignalDeposit, held, required)?;
+
+			if previous.is_some_and(|signal| matches!(signal.content, SignalContent::Service { .. })) {
+				<ServiceSignals<T>>::remove(topic, who);
+			}
+			if matches!(content, SignalContent::Service { .. }) {
+				<ServiceSignals<T>>::insert(topic, who, ());
+			}
+			<PersistedSignals<T>>::insert(who, topic, PersistedSignal { content, deposit, expires_at });
+			Ok(expires_at)
+		}
+
+		/// Holds or releases the difference between the `held` and `required` deposits on `who`.
+		/// Returns the deposit now held.
+		fn resize_deposit(
+			who: &T::AccountId,
+			reason: HoldReason,
+			held: DepositOf<T>,
+			required: DepositOf<T>,
+		) -> Result<DepositOf<T>, DispatchError> {
+			let reason = reason.into();
+			match required.cmp(&held) {
+				Ordering::Greater => {
+					T::DepositCurrency::hold(&reason, who, required.saturating_sub(held))?;
+					Ok(required)
+				},
+				Ordering::Less => {
+					let released =
+						T::DepositCurrency::release(&reason, who, held.saturating_sub(required), Precision::BestEffort)?;
+					Ok(held.saturating_sub(released))
+				},
+				Ordering::Equal => Ok(held),
+			}
+		}
+
+		/// The deposit held for registering `urls` under `service_identifier`.
+		fn service_deposit(service_identifier: &BoundedVec<u8, T::MaxSize>, urls: &ServiceUrlsOf<T>) -> DepositOf<T> {
+			let bytes = urls.iter().fold(service_identifier.len(), |bytes, url| bytes.saturating_add(url.len()));
+			Self::signal_deposit(bytes)
+		}
+
+		/// Whether a service whose provider last sent a heartbeat in block `last_heartbeat` is
+		/// live in block `now`.
+		fn status_at(last_heartbeat: BlockNumberFor<T>, now: BlockNumberFor<T>) -> ServiceStatus {
+			if now < last_heartbeat.saturating_add(T::ServiceLivenessPeriod::get()) {
+				ServiceStatus::Live
+			} else {
+				ServiceStatus::Stale
+			}
+		}
+
+		/// The liveness of the registration of `service_identifier` by `provider`, if there is
+		/// one.
+		pub fn service_status(
+			service_identifier: &BoundedVec<u8, T::MaxSize>,
+			provider: &T::AccountId,
+		) -> Option<ServiceStatus> {
+			let registration = <ServiceRegistry<T>>::get(service_identifier, provider)?;
+			Some(Self::status_at(registration.last_heartbeat, frame_system::Pallet::<T>::block_number()))
+		}
+
+		/// Returns the providers and URLs of the live registrations of `service_identifier`.
+		pub fn live_service_endpoints(service_identifier: Vec<u8>) -> Vec<ServiceEndpointOf<T>> {
+			let Ok(service_identifier) = BoundedVec::<u8, T::MaxSize>::try_from(service_identifier) else {
+				return Vec::new();
+			};
+			let now = frame_system::Pallet::<T>::block_number();
+			<ServiceRegistry<T>>::iter_prefix(&service_identifier)
+				.filter(|(_, registration)| Self::status_at(registration.last_heartbeat, now) == ServiceStatus::Live)
+				.map(|(provider, registration)| ServiceEndpoint {
+					provider,
+					urls: registration.urls.into_iter().map(BoundedVec::into_inner).collect(),
+					last_heartbeat: registration.last_heartbeat,
+				})
+				.collect()
+		}
+
+		/// Removes the signal `who` persisted under `topic` and releases its deposit. The signal
+		/// is removed even if the release fails, so it cannot outlive its expiry.
+		fn remove_signal(who: &T::AccountId, topic: &BoundedVec<u8, T::MaxSize>, signal: PersistedSignalOf<T>) {
+			<PersistedSignals<T>>::remove(who, topic);
+			if matches!(signal.content, SignalContent::Service { .. }) {
+				<ServiceSignals<T>>::remove(topic, who);
+			}
+			// A best-effort release only falls short if the held funds were slashed, in which case
+			// there is nothing left to give back.
+			let _ = T::DepositCurrency::release(
+				&HoldReason::SignalDeposit.into(),
+				who,
+				signal.deposit,
+				Precision::BestEffort,
+			);
+		}
+
+		/// Schedules the signal `who` persisted under `topic` to be looked at by the sweep in
+		/// block `at`, or in the first of the `MAX_EXPIRY_DELAY` blocks after it with room.
+		fn schedule_signal_expiry(
+			who: &T::AccountId,
+			topic: &BoundedVec<u8, T::MaxSize>,
+			at: BlockNumberFor<T>,
+			now: BlockNumberFor<T>,
+		) -> DispatchResult {
+			let block = SignalExpiryQueue::<T>::schedule((who.clone(), topic.clone()), at, now)
+				.ok_or(Error::<T>::TooManySignalExpiries)?;
+			<ScheduledSignalExpiries<T>>::insert(who, topic, block);
+			Ok(())
+		}
+
+		/// Removes the signal `who` persisted under `topic` from the block it is scheduled in,
+		/// freeing its place there.
+		fn unschedule_signal_expiry(who: &T::AccountId, topic: &BoundedVec<u8, T::MaxSize>) {
+			let Some(block) = <ScheduledSignalExpiries<T>>::take(who, topic) else { return };
+			SignalExpiryQueue::<T>::unschedule(&(who.clone(), topic.clone()), block);
+		}
+
+		/// Removes persisted signals that expired in or before block `now`, oldest first, until
+		/// either none remain or `remaining_weight` is used up. Returns the weight used.
+		pub(crate) fn sweep_expired_signals(now: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+			SignalExpiryQueue::<T>::sweep(now, remaining_weight, T::WeightInfo::expire_signal(), |(who, topic), at| {
+				Self::expire_signal(&who, &topic, at)
+			})
+		}
+
+		/// Removes the signal `who` persisted under `topic` if it is still scheduled in block
+		/// `at`. Signals removed or persisted again since are left alone.
+		pub(crate) fn expire_signal(who: &T::AccountId, topic: &BoundedVec<u8, T::MaxSize>, at: BlockNumberFor<T>) {
+			if <ScheduledSignalExpiries<T>>::get(who, topic) != Some(at) {
+				return;
+			}
+			// The sweep has already taken the entry out of `SignalExpiries`.
+			<ScheduledSignalExpiries<T>>::remove(who, topic);
+			if let Some(signal) = <PersistedSignals<T>>::get(who, topic) {
+				Self::remove_signal(who, topic, signal);
+				Self::deposit_event(Event::PersistedSignalExpired { who: who.clone(), topic: topic.clone() });
+			}
+		}
 	}
 
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
-		/// Defines coefficients that participants should use to weight rating functions.
+		/// Defines coefficients that participants should use to weight rating functions. `name`
+		/// must have a registered schema, and `value` must follow it.
 		#[pallet::weight(T::WeightInfo::set_signal_parameter())]
 		#[pallet::call_index(0)]
 		pub fn set_signal_parameter(
 			origin: OriginFor<T>,
 			name: BoundedVec<u8, T::MaxSize>,
-			value: u8,
+			value: ParameterValueOf<T>,
         ) -> DispatchResultWithPostInfo {
 			let who = ensure_signed(origin)?;
 
+			let schema = <SignalParameterSchemas<T>>::get(&name).ok_or(Error::<T>::UnknownSignalParameter)?;
+			ensure!(Self::schema_accepts(&schema, &value), Error::<T>::InvalidSignalParameter);
 			<SignalParameterList<T>>::insert(who.clone(), name.clone(), value);
             Self::deposit_event(Event::SignalParameterSet { who });
             Ok(().into())
 		}
 
 		/// Creates an on-chain event with a transaction hash as a pointer and a u8 as a rating
-		/// number.
+		/// number, between zero and `MaxRating`. A content hash or review reference can be
+		/// attached as evidence.
 		#[pallet::weight(T::WeightInfo::send_rating_signal())]
 		#[pallet::call_index(1)]
 		pub fn send_rating_signal(
 			origin: OriginFor<T>,
 			target: BoundedVec<u8, T::MaxSize>,
 			rating: u8,
+			attachment: Option<RatingAttachmentOf<T>>,
         ) -> DispatchResultWithPostInfo {
 			let who = ensure_signed(origin)?;
 
+			ensure!(rating <= T::MaxRating::get(), Error::<T>::InvalidRating);
 			ensure!(
 				!<RatingSignalList<T>>::contains_key(who.clone(), target.clone()),
 				Error::<T>::RatingSignalAlreadyExists
 			);
+			let count = <RatingCount<T>>::get(&who).saturating_add(1);
 			ensure!(
-				!(T::Currency::free_balance(&who) <= T::LockPrice::get().into()),
+				T::Currency::free_balance(&who) >= Self::lock_amount(count),
 				Error::<T>::InsufficientBalance
 			);
-			T::Currency::ensure_can_withdraw(
-				&who,
-				T::LockPrice::get().into(),
-				WithdrawReasons::all(),
-				<T as Config>::Currency::free_balance(&who),
-			)?;
 
-			<RatingSignalList<T>>::insert(who.clone(), target.clone(), rating);
-			T::Currency::set_lock(
-				T::LockId::get(),
-				&who,
-				T::LockPrice::get(),
-				WithdrawReasons::all(),
-			);
-            Self::deposit_event(Event::SignalLock { account: who.clone(), amount: T::LockPrice::get() });
+			let updated_at = frame_system::Pallet::<T>::block_number();
+			<RatingSignalList<T>>::insert(who.clone(), target.clone(), RatingSignal { rating, attachment, updated_at });
+			Self::add_to_aggregate(&who, &target, rating);
+			<RatingCount<T>>::insert(&who, count);
+			Self::set_rating_lock(&who, count);
+            Self::deposit_event(Event::SignalLock { account: who.clone(), total: Self::lock_amount(count) });
             Self::deposit_event(Event::RatingSignalSent { who });
             Ok(().into())
 		}
 
-		/// Updates an existing rating signal.
+		/// Updates an existing rating signal, replacing its rating and attachment.
 		#[pallet::weight(T::WeightInfo::update_rating_signal())]
 		#[pallet::call_index(4)]
 		pub fn update_rating_signal(
 			origin: OriginFor<T>,
 			target: BoundedVec<u8, T::MaxSize>,
 			new_rating: u8,
+			attachment: Option<RatingAttachmentOf<T>>,
         ) -> DispatchResultWithPostInfo {
 			let who = ensure_signed(origin)?;
 
-			ensure!(
-				!(T::Currency::free_balance(&who) <= T::LockPrice::get().into()),
-				Error::<T>::InsufficientBalance
-			);
-			ensure!(
-				<RatingSignalList<T>>::contains_key(who.clone(), target.clone()),
-				Error::<T>::RatingSignalDoesNotExist
-			);
+			ensure!(new_rating <= T::MaxRating::get(), Error::<T>::InvalidRating);
+			let old = <RatingSignalList<T>>::get(who.clone(), target.clone())
+				.ok_or(Error::<T>::RatingSignalDoesNotExist)?;
 
-			<RatingSignalList<T>>::insert(who.clone(), target.clone(), new_rating);
-			T::Currency::extend_lock(
-				T::LockId::get(),
-				&who,
-				T::LockPrice::get(),
-				WithdrawReasons::all(),
+			Self::remove_from_aggregate(&who, &target, old.rating);
+			let updated_at = frame_system::Pallet::<T>::block_number();
+			<RatingSignalList<T>>::insert(
+				who.clone(),
+				target.clone(),
+				RatingSignal { rating: new_rating, attachment, updated_at },
 			);
-            Self::deposit_event(Event::SignalLockExtended { account: who.clone(), amount: T::LockPrice::get() });
+			// The signal already holds its share of the lock, so the lock stays as it is.
+			Self::add_to_aggregate(&who, &target, new_rating);
             Self::deposit_event(Event::RatingSignalUpdated { who });
             Ok(().into())
 		}
@@ -205,14 +854,16 @@ pub mod pallet {
         ) -> DispatchResultWithPostInfo {
 			let who = ensure_signed(origin)?;
 
-			ensure!(
-				<RatingSignalList<T>>::contains_key(who.clone(), target.clone()),
-				Error::<T>::RatingSignalDoesNotExist
-			);
-
-			<RatingSignalList<T>>::remove(who.clone(), target.clone());
-			T::Currency::remove_lock(T::LockId::get(), &who);
-            Self::deposit_event(Event::SignalUnlock { account: who.clone() });
+			let signal = <RatingSignalList<T>>::take(who.clone(), target.clone())
+				.ok_or(Error::<T>::RatingSignalDoesNotExist)?;
+			Self::remove_from_aggregate(&who, &target, signal.rating);
+			let count = <RatingCount<T>>::mutate_exists(&who, |count| {
+				let remaining = count.unwrap_or_default().saturating_sub(1);
+				*count = (remaining > 0).then_some(remaining);
+				remaining
+			});
+			Self::set_rating_lock(&who, count);
+            Self::deposit_event(Event::SignalUnlock { account: who.clone(), total: Self::lock_amount(count) });
             Self::deposit_event(Event::RatingSignalRevoked { who });
             Ok(().into())
 		}
@@ -247,5 +898,187 @@ pub mod pallet {
             Self::deposit_event(Event::ServiceSignalSent { service_identifier, url, who });
             Ok(().into())
 		}
+
+		/// Registers or replaces the schema that values of the signal parameter `name` must
+		/// follow. Values set under an earlier schema are kept as they are.
+		#[pallet::weight(T::WeightInfo::set_parameter_schema())]
+		#[pallet::call_index(9)]
+		pub fn set_parameter_schema(
+			origin: OriginFor<T>,
+			name: BoundedVec<u8, T::MaxSize>,
+			schema: ParameterSchema,
+        ) -> DispatchResultWithPostInfo {
+			T::SchemaOrigin::ensure_origin(origin)?;
+			ensure!(schema.min <= schema.max, Error::<T>::InvalidParameterSchema);
+
+			<SignalParameterSchemas<T>>::insert(&name, schema);
+            Self::deposit_event(Event::SignalParameterSchemaSet { name, schema });
+            Ok(().into())
+		}
+
+		/// Removes the schema for the signal parameter `name`, so no new values can be set for
+		/// it. Values already set are kept.
+		#[pallet::weight(T::WeightInfo::remove_parameter_schema())]
+		#[pallet::call_index(10)]
+		pub fn remove_parameter_schema(
+			origin: OriginFor<T>,
+			name: BoundedVec<u8, T::MaxSize>,
+        ) -> DispatchResultWithPostInfo {
+			T::SchemaOrigin::ensure_origin(origin)?;
+			ensure!(<SignalParameterSchemas<T>>::contains_key(&name), Error::<T>::UnknownSignalParameter);
+
+			<SignalParameterSchemas<T>>::remove(&name);
+            Self::deposit_event(Event::SignalParameterSchemaRemoved { name });
+            Ok(().into())
+		}
+
+		/// Sends a signal like `send_signal` and also keeps it in storage under `topic` for `ttl`
+		/// blocks, so it can be read after events are pruned. A deposit covering the stored bytes
+		/// is held until the signal expires or is removed. Persisting under a topic already in use
+		/// replaces that signal.
+		#[pallet::weight(T::WeightInfo::persist_signal())]
+		#[pallet::call_index(11)]
+		pub fn persist_signal(
+			origin: OriginFor<T>,
+			topic: BoundedVec<u8, T::MaxSize>,
+			signal: BoundedVec<u8, T::MaxSize>,
+			ttl: BlockNumberFor<T>,
+        ) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+
+			let expires_at = Self::persist(&who, &topic, SignalContent::Signal(signal.clone()), ttl)?;
+            Self::deposit_event(Event::SignalSent { signal, who: who.clone() });
+            Self::deposit_event(Event::SignalPersisted { who, topic, expires_at });
+            Ok(().into())
+		}
+
+		/// Sends a service signal like `send_service_signal` and also keeps it in storage under
+		/// `service_identifier` for `ttl` blocks, so the services announced can be listed after
+		/// events are pruned. Deposits and replacement work as in `persist_signal`.
+		#[pallet::weight(T::WeightInfo::persist_service_signal())]
+		#[pallet::call_index(12)]
+		pub fn persist_service_signal(
+			origin: OriginFor<T>,
+			service_identifier: BoundedVec<u8, T::MaxSize>,
+			url: BoundedVec<u8, T::MaxSize>,
+			ttl: BlockNumberFor<T>,
+        ) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+
+			let expires_at =
+				Self::persist(&who, &service_identifier, SignalContent::Service { url: url.clone() }, ttl)?;
+            Self::deposit_event(Event::ServiceSignalSent {
+				service_identifier: service_identifier.clone(),
+				url,
+				who: who.clone(),
+			});
+            Self::deposit_event(Event::SignalPersisted { who, topic: service_identifier, expires_at });
+            Ok(().into())
+		}
+
+		/// Removes the signal origin persisted under `topic` before it expires and releases its
+		/// deposit.
+		#[pallet::weight(T::WeightInfo::remove_persisted_signal())]
+		#[pallet::call_index(13)]
+		pub fn remove_persisted_signal(
+			origin: OriginFor<T>,
+			topic: BoundedVec<u8, T::MaxSize>,
+        ) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+
+			let signal = <PersistedSignals<T>>::get(&who, &topic).ok_or(Error::<T>::PersistedSignalDoesNotExist)?;
+			Self::unschedule_signal_expiry(&who, &topic);
+			Self::remove_signal(&who, &topic, signal);
+            Self::deposit_event(Event::PersistedSignalRemoved { who, topic });
+            Ok(().into())
+		}
+
+		/// Registers origin as a provider of `service_identifier`, reachable at `urls`. A deposit
+		/// covering the stored bytes is held until the service is deregistered. The service is
+		/// live for `ServiceLivenessPeriod` blocks after registering, updating or sending a
+		/// heartbeat.
+		#[pallet::weight(T::WeightInfo::register_service())]
+		#[pallet::call_index(14)]
+		pub fn register_service(
+			origin: OriginFor<T>,
+			service_identifier: BoundedVec<u8, T::MaxSize>,
+			urls: ServiceUrlsOf<T>,
+        ) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			ensure!(!urls.is_empty(), Error::<T>::NoServiceUrls);
+			ensure!(
+				!<ServiceRegistry<T>>::contains_key(&service_identifier, &who),
+				Error::<T>::ServiceAlreadyRegistered
+			);
+
+			let required = Self::service_deposit(&service_identifier, &urls);
+			let deposit = Self::resize_deposit(&who, HoldReason::ServiceDeposit, Zero::zero(), required)?;
+			let last_heartbeat = frame_system::Pallet::<T>::block_number();
+			<ServiceRegistry<T>>::insert(&service_identifier, &who, ServiceRegistration { urls, deposit, last_heartbeat });
+            Self::deposit_event(Event::ServiceRegistered { service_identifier, provider: who });
+            Ok(().into())
+		}
+
+		/// Replaces the URLs origin registered for `service_identifier`, resizing the deposit.
+		/// Counts as a heartbeat.
+		#[pallet::weight(T::WeightInfo::update_service())]
+		#[pallet::call_index(15)]
+		pub fn update_service(
+			origin: OriginFor<T>,
+			service_identifier: BoundedVec<u8, T::MaxSize>,
+			urls: ServiceUrlsOf<T>,
+        ) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			ensure!(!urls.is_empty(), Error::<T>::NoServiceUrls);
+			let registration =
+				<ServiceRegistry<T>>::get(&service_identifier, &who).ok_or(Error::<T>::ServiceNotRegistered)?;
+
+			let required = Self::service_deposit(&service_identifier, &urls);
+			let deposit = Self::resize_deposit(&who, HoldReason::ServiceDeposit, registration.deposit, required)?;
+			let last_heartbeat = frame_system::Pallet::<T>::block_number();
+			<ServiceRegistry<T>>::insert(&service_identifier, &who, ServiceRegistration { urls, deposit, last_heartbeat });
+            Self::deposit_event(Event::ServiceUpdated { service_identifier, provider: who });
+            Ok(().into())
+		}
+
+		/// Confirms that the service origin registered under `service_identifier` is still
+		/// reachable, keeping it live for another `ServiceLivenessPeriod` blocks.
+		#[pallet::weight(T::WeightInfo::service_heartbeat())]
+		#[pallet::call_index(16)]
+		pub fn service_heartbeat(
+			origin: OriginFor<T>,
+			service_identifier: BoundedVec<u8, T::MaxSize>,
+        ) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+
+			<ServiceRegistry<T>>::try_mutate(&service_identifier, &who, |registration| {
+				let registration = registration.as_mut().ok_or(Error::<T>::ServiceNotRegistered)?;
+				registration.last_heartbeat = frame_system::Pallet::<T>::block_number();
+				Ok::<_, Error<T>>(())
+			})?;
+            Self::deposit_event(Event::ServiceHeartbeat { service_identifier, provider: who });
+            Ok(().into())
+		}
+
+		/// Withdraws origin's registration of `service_identifier` and releases its deposit.
+		#[pallet::weight(T::WeightInfo::deregister_service())]
+		#[pallet::call_index(17)]
+		pub fn deregister_service(
+			origin: OriginFor<T>,
+			service_identifier: BoundedVec<u8, T::MaxSize>,
+        ) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+
+			let registration =
+				<ServiceRegistry<T>>::take(&service_identifier, &who).ok_or(Error::<T>::ServiceNotRegistered)?;
+			T::DepositCurrency::release(
+				&HoldReason::ServiceDeposit.into(),
+				&who,
+				registration.deposit,
+				Precision::BestEffort,
+			)?;
+            Self::deposit_event(Event::ServiceDeregistered { service_identifier, provider: who });
+            Ok(().into())
+		}
 	}
 }
@@ -0,0 +1,213 @@
+//! Storage migrations for pallet-signal.
+
+/// Storage layouts replaced by later versions, for the migrations that still read them.
+pub(crate) mod old {
+    use crate::{Config, Pallet};
+    use frame_support::{pallet_prelude::*, storage_alias};
+
+    /// `RatingSignalList` before version 4, when each rating was a bare `u8`.
+    #[storage_alias]
+    pub type RatingSignalList<T: Config> = StorageDoubleMap<
+        Pallet<T>,
+        Blake2_128Concat,
+        <T as frame_system::Config>::AccountId,
+        Blake2_128Concat,
+        BoundedVec<u8, <T as Config>::MaxSize>,
+        u8,
+        ValueQuery,
+    >;
+}
+
+/// Migrates the pallet from storage version 0 to 1.
+///
+/// Version 1 adds `RatingAggregates`, which this migration fills in from `RatingSignalList`.
+/// Rater weights at the time of the original ratings are unknown, so existing ratings only count
+/// towards the unweighted totals until they are updated.
+pub mod v1 {
+    use super::old::RatingSignalList;
+    use crate::{Config, Pallet, RatingAggregates};
+    use core::marker::PhantomData;
+    use frame_support::{
+        migrations::VersionedMigration,
+        sp_runtime::traits::Saturating,
+        traits::{Get, UncheckedOnRuntimeUpgrade},
+        weights::Weight,
+    };
+
+    /// Computes `RatingAggregates` from `RatingSignalList`. Use [`MigrateV0ToV1`] instead,
+    /// which only runs this once and bumps the storage version.
+    pub struct InnerMigrateV0ToV1<T>(PhantomData<T>);
+
+    impl<T: Config> UncheckedOnRuntimeUpgrade for InnerMigrateV0ToV1<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let mut ratings: u64 = 0;
+            for (_, target, rating) in RatingSignalList::<T>::iter() {
+                RatingAggregates::<T>::mutate(target, |aggregate| {
+                    aggregate.count.saturating_inc();
+                    aggregate.sum.saturating_accrue(rating.into());
+                });
+                ratings = ratings.saturating_add(1);
+            }
+            T::DbWeight::get().reads_writes(ratings.saturating_mul(2), ratings)
+        }
+    }
+
+    /// Migrates pallet-signal from storage version 0 to 1.
+    pub type MigrateV0ToV1<T> = VersionedMigration<
+        0,
+        1,
+        InnerMigrateV0ToV1<T>,
+        Pallet<T>,
+        <T as frame_system::Config>::DbWeight,
+    >;
+}
+
+/// Migrates the pallet from storage version 1 to 2.
+///
+/// Version 2 locks `LockPrice` for every active rating signal instead of once per account.
+/// This migration counts each rater's ratings into `RatingCount` and resizes their lock to match.
+pub mod v2 {
+    use crate::{Config, Pallet, RatingCount, RatingSignalList};
+    use alloc::collections::btree_map::BTreeMap;
+    use core::marker::PhantomData;
+    use frame_support::{
+        migrations::VersionedMigration,
+        traits::{Get, UncheckedOnRuntimeUpgrade},
+        weights::Weight,
+    };
+
+    /// Fills in `RatingCount` and resizes rater locks. Use [`MigrateV1ToV2`] instead, which only
+    /// runs this once and bumps the storage version.
+    pub struct InnerMigrateV1ToV2<T>(PhantomData<T>);
+
+    impl<T: Config> UncheckedOnRuntimeUpgrade for InnerMigrateV1ToV2<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let mut counts = BTreeMap::<T::AccountId, u32>::new();
+            let mut reads: u64 = 0;
+            for (who, _) in RatingSignalList::<T>::iter_keys() {
+                reads = reads.saturating_add(1);
+                let count = counts.entry(who).or_default();
+                *count = count.saturating_add(1);
+            }
+            let raters = counts.len() as u64;
+            for (who, count) in counts {
+                RatingCount::<T>::insert(&who, count);
+                Pallet::<T>::set_rating_lock(&who, count);
+            }
+            // Each rater's lock update reads and writes their balance locks.
+            T::DbWeight::get().reads_writes(reads.saturating_add(raters), raters.saturating_mul(2))
+        }
+    }
+
+    /// Migrates pallet-signal from storage version 1 to 2.
+    pub type MigrateV1ToV2<T> = VersionedMigration<
+        1,
+        2,
+        InnerMigrateV1ToV2<T>,
+        Pallet<T>,
+        <T as frame_system::Config>::DbWeight,
+    >;
+}
+
+/// Migrates the pallet from storage version 2 to 3.
+///
+/// Version 3 stores typed signal parameter values instead of a bare `u8`. This migration turns
+/// every existing value into a `ParameterValue::Integer`, and registers an `Integer` schema with
+/// the `u8` range `0..=255` for each name it converts that has no schema yet, so existing callers
+/// can keep setting those parameters.
+pub mod v3 {
+    use crate::{
+        Config, Pallet, ParameterSchema, ParameterType, ParameterValue, SignalParameterList,
+        SignalParameterSchemas,
+    };
+    use alloc::collections::btree_set::BTreeSet;
+    use core::marker::PhantomData;
+    use frame_support::{
+        migrations::VersionedMigration,
+        traits::{Get, UncheckedOnRuntimeUpgrade},
+        weights::Weight,
+    };
+
+    /// The schema registered for parameter names set before version 3, matching the `u8` values
+    /// they held.
+    pub const DEFAULT_SCHEMA: ParameterSchema =
+        ParameterSchema { value_type: ParameterType::Integer, min: 0, max: u8::MAX as u64 };
+
+    /// Converts `SignalParameterList` values to typed values and registers [`DEFAULT_SCHEMA`]
+    /// for their names. Use [`MigrateV2ToV3`] instead, which only runs this once and bumps the
+    /// storage version.
+    pub struct InnerMigrateV2ToV3<T>(PhantomData<T>);
+
+    impl<T: Config> UncheckedOnRuntimeUpgrade for InnerMigrateV2ToV3<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let mut translated: u64 = 0;
+            let mut names = BTreeSet::new();
+            SignalParameterList::<T>::translate::<u8, _>(|_, name, value| {
+                translated = translated.saturating_add(1);
+                names.insert(name);
+                Some(ParameterValue::Integer(value.into()))
+            });
+            let mut registered: u64 = 0;
+            for name in names.iter() {
+                if !SignalParameterSchemas::<T>::contains_key(name) {
+                    SignalParameterSchemas::<T>::insert(name, DEFAULT_SCHEMA);
+                    registered = registered.saturating_add(1);
+                }
+            }
+            T::DbWeight::get().reads_writes(
+                translated.saturating_add(names.len() as u64),
+                translated.saturating_add(registered),
+            )
+        }
+    }
+
+    /// Migrates pallet-signal from storage version 2 to 3.
+    pub type MigrateV2ToV3<T> = VersionedMigration<
+        2,
+        3,
+        InnerMigrateV2ToV3<T>,
+        Pallet<T>,
+        <T as frame_system::Config>::DbWeight,
+    >;
+}
+
+/// Migrates the pallet from storage version 3 to 4.
+///
+/// Version 4 stores each rating in `RatingSignalList` as a `RatingSignal`, with an optional
+/// attachment and the block it was last updated in, instead of a bare `u8`. Existing ratings get
+/// no attachment and are marked as updated in the upgrade block. Ratings above `MaxRating` are
+/// kept as they are until their rater updates them.
+pub mod v4 {
+    use crate::{Config, Pallet, RatingSignal, RatingSignalList};
+    use core::marker::PhantomData;
+    use frame_support::{
+        migrations::VersionedMigration,
+        traits::{Get, UncheckedOnRuntimeUpgrade},
+        weights::Weight,
+    };
+
+    /// Converts `RatingSignalList` values to `RatingSignal`s. Use [`MigrateV3ToV4`] instead,
+    /// which only runs this once and bumps the storage version.
+    pub struct InnerMigrateV3ToV4<T>(PhantomData<T>);
+
+    impl<T: Config> UncheckedOnRuntimeUpgrade for InnerMigrateV3ToV4<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let updated_at = frame_system::Pallet::<T>::block_number();
+            let mut translated: u64 = 0;
+            RatingSignalList::<T>::translate::<u8, _>(|_, _, rating| {
+                translated = translated.saturating_add(1);
+                Some(RatingSignal { rating, attachment: None, updated_at })
+            });
+            T::DbWeight::get().reads_writes(translated.saturating_add(1), translated)
+        }
+    }
+
+    /// Migrates pallet-signal from storage version 3 to 4.
+    pub type MigrateV3ToV4<T> = VersionedMigration<
+        3,
+        4,
+        InnerMigrateV3ToV4<T>,
+        Pallet<T>,
+        <T as frame_system::Config>::DbWeight,
+    >;
+}
@@ -1,6 +1,6 @@
 use crate as pallet_signal;
-use frame_support::derive_impl;
-use sp_core::{ConstU32, ConstU128};
+use frame_support::{derive_impl, parameter_types};
+use sp_core::{ConstU8, ConstU32, ConstU64, ConstU128};
 use sp_runtime::BuildStorage;
 
 pub type Balance = u128;
@@ -50,7 +50,7 @@ impl pallet_balances::Config for Test {
 	type WeightInfo = ();
 	type FreezeIdentifier = [u8; 8];
 	type MaxFreezes = ();
-	type RuntimeHoldReason = ();
+	type RuntimeHoldReason = RuntimeHoldReason;
 	type RuntimeFreezeReason = ();
     type DoneSlashHandler = ();
 }
@@ -71,6 +71,19 @@ impl frame_support::traits::Get<u128> for SignalLockPrice {
     }
 }
 
+parameter_types! {
+    /// The weight every rater's ratings carry, adjustable from tests.
+    pub static MockRatingWeight: Option<u32> = None;
+}
+
+// Weighs every rater with `MockRatingWeight`.
+pub struct MockRaterWeights;
+impl pallet_signal::RatingWeight<u64> for MockRaterWeights {
+    fn rating_weight(_: &u64) -> Option<u32> {
+        MockRatingWeight::get()
+    }
+}
+
 impl pallet_signal::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type WeightInfo = ();
@@ -78,6 +91,19 @@ impl pallet_signal::Config for Test {
 	type MaxSize = ConstU32<1024>;
     type LockId = SignalLockId;
 	type LockPrice = SignalLockPrice;
+	type RatingWeight = MockRaterWeights;
+	type SchemaOrigin = frame_system::EnsureRoot<u64>;
+	type MaxParameterListLength = ConstU32<16>;
+	type RuntimeHoldReason = RuntimeHoldReason;
+	type DepositCurrency = Balances;
+	type SignalBaseDeposit = ConstU128<5>;
+	type SignalByteDeposit = ConstU128<1>;
+	type MaxSignalTtl = ConstU64<100>;
+	type MaxSignalExpiriesPerBlock = ConstU32<3>;
+	type MaxServiceUrls = ConstU32<3>;
+	type ServiceLivenessPeriod = ConstU64<10>;
+	type MaxRating = ConstU8<10>;
+	type MaxReviewLength = ConstU32<64>;
 }
 
 // Build genesis storage according to the mock runtime.
@@ -1,33 +1,148 @@
-use crate::{mock::*, Error, Event, RatingSignalList, SignalParameterList};
+use crate::{
+    migrations::old, mock::*, Error, Event, HoldReason, ParameterSchema, ParameterType, ParameterValue, PersistedSignal,
+    PersistedSignals, RatingAggregate, RatingAggregates, RatingAttachment, RatingCount, RatingSignal, RatingSignalList,
+    RatingWeights, ScheduledSignalExpiries, ServiceEndpoint,
+    ServiceRegistry, ServiceSignal, ServiceSignals, ServiceStatus, SignalContent, SignalExpiries, SignalExpiryCursor, SignalParameterList,
+    SignalParameterSchemas,
+};
+use sp_runtime::DispatchError;
 use frame_support::{assert_ok, assert_noop};
 use sp_core::ConstU32;
 use frame_support::BoundedVec;
-use frame_support::traits::Currency;
+use frame_support::traits::{
+    fungible::InspectHold, Currency, Get, GetStorageVersion, Hooks, OnRuntimeUpgrade, StorageVersion,
+};
+use frame_support::weights::Weight;
 
 #[test]
 fn set_signal_parameter_works_and_emits_event() {
 	new_test_ext().execute_with(|| {
 		System::set_block_number(1);
         let name = BoundedVec::<u8, ConstU32<1024>>::try_from(b"PARAM".to_vec()).unwrap();
-        assert_ok!(Signal::set_signal_parameter(RuntimeOrigin::signed(1), name.clone(), 42));
+        register_schema(&name, ParameterType::Integer, 0, 100);
+        assert_ok!(Signal::set_signal_parameter(RuntimeOrigin::signed(1), name.clone(), ParameterValue::Integer(42)));
         // Storage check
-        assert_eq!(SignalParameterList::<Test>::get(1, &name), 42);
+        assert_eq!(SignalParameterList::<Test>::get(1, &name), Some(ParameterValue::Integer(42)));
         // Event check
         System::assert_last_event(Event::SignalParameterSet { who: 1 }.into());
 	});
 }
 
+fn register_schema(name: &BoundedVec<u8, ConstU32<1024>>, value_type: ParameterType, min: u64, max: u64) {
+    assert_ok!(Signal::set_parameter_schema(
+        RuntimeOrigin::root(),
+        name.clone(),
+        ParameterSchema { value_type, min, max }
+    ));
+}
+
+#[test]
+fn cannot_set_unregistered_signal_parameter() {
+    new_test_ext().execute_with(|| {
+        let name = BoundedVec::<u8, ConstU32<1024>>::try_from(b"PARAM".to_vec()).unwrap();
+        assert_noop!(
+            Signal::set_signal_parameter(RuntimeOrigin::signed(1), name, ParameterValue::Integer(42)),
+            Error::<Test>::UnknownSignalParameter
+        );
+    });
+}
+
+#[test]
+fn signal_parameter_values_are_checked_against_the_schema() {
+    new_test_ext().execute_with(|| {
+        let name = BoundedVec::<u8, ConstU32<1024>>::try_from(b"PARAM".to_vec()).unwrap();
+        register_schema(&name, ParameterType::Integer, 10, 20);
+        // Out of range
+        assert_noop!(
+            Signal::set_signal_parameter(RuntimeOrigin::signed(1), name.clone(), ParameterValue::Integer(21)),
+            Error::<Test>::InvalidSignalParameter
+        );
+        // Wrong type
+        let text = BoundedVec::try_from(b"fifteen".to_vec()).unwrap();
+        assert_noop!(
+            Signal::set_signal_parameter(RuntimeOrigin::signed(1), name.clone(), ParameterValue::Text(text)),
+            Error::<Test>::InvalidSignalParameter
+        );
+        assert_ok!(Signal::set_signal_parameter(RuntimeOrigin::signed(1), name.clone(), ParameterValue::Integer(10)));
+    });
+}
+
+#[test]
+fn text_and_list_signal_parameters_work() {
+    new_test_ext().execute_with(|| {
+        let label = BoundedVec::<u8, ConstU32<1024>>::try_from(b"LABEL".to_vec()).unwrap();
+        let ports = BoundedVec::<u8, ConstU32<1024>>::try_from(b"PORTS".to_vec()).unwrap();
+        register_schema(&label, ParameterType::Text, 1, 8);
+        register_schema(&ports, ParameterType::IntegerList, 1, 65535);
+
+        let text = BoundedVec::try_from(b"fennel".to_vec()).unwrap();
+        assert_ok!(Signal::set_signal_parameter(RuntimeOrigin::signed(1), label.clone(), ParameterValue::Text(text.clone())));
+        assert_eq!(SignalParameterList::<Test>::get(1, &label), Some(ParameterValue::Text(text)));
+        let too_long = BoundedVec::try_from(b"fennel-labs".to_vec()).unwrap();
+        assert_noop!(
+            Signal::set_signal_parameter(RuntimeOrigin::signed(1), label, ParameterValue::Text(too_long)),
+            Error::<Test>::InvalidSignalParameter
+        );
+
+        let list = BoundedVec::try_from(vec![80, 443]).unwrap();
+        assert_ok!(Signal::set_signal_parameter(RuntimeOrigin::signed(1), ports.clone(), ParameterValue::IntegerList(list.clone())));
+        assert_eq!(SignalParameterList::<Test>::get(1, &ports), Some(ParameterValue::IntegerList(list)));
+        let out_of_range = BoundedVec::try_from(vec![80, 0]).unwrap();
+        assert_noop!(
+            Signal::set_signal_parameter(RuntimeOrigin::signed(1), ports, ParameterValue::IntegerList(out_of_range)),
+            Error::<Test>::InvalidSignalParameter
+        );
+    });
+}
+
+#[test]
+fn parameter_schemas_are_managed_by_governance() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let name = BoundedVec::<u8, ConstU32<1024>>::try_from(b"PARAM".to_vec()).unwrap();
+        let schema = ParameterSchema { value_type: ParameterType::Integer, min: 0, max: 100 };
+        assert_noop!(
+            Signal::set_parameter_schema(RuntimeOrigin::signed(1), name.clone(), schema),
+            DispatchError::BadOrigin
+        );
+        assert_noop!(
+            Signal::set_parameter_schema(
+                RuntimeOrigin::root(),
+                name.clone(),
+                ParameterSchema { value_type: ParameterType::Integer, min: 5, max: 4 }
+            ),
+            Error::<Test>::InvalidParameterSchema
+        );
+
+        assert_ok!(Signal::set_parameter_schema(RuntimeOrigin::root(), name.clone(), schema));
+        assert_eq!(SignalParameterSchemas::<Test>::get(&name), Some(schema));
+        System::assert_last_event(Event::SignalParameterSchemaSet { name: name.clone(), schema }.into());
+
+        assert_noop!(Signal::remove_parameter_schema(RuntimeOrigin::signed(1), name.clone()), DispatchError::BadOrigin);
+        assert_ok!(Signal::remove_parameter_schema(RuntimeOrigin::root(), name.clone()));
+        assert!(!SignalParameterSchemas::<Test>::contains_key(&name));
+        System::assert_last_event(Event::SignalParameterSchemaRemoved { name: name.clone() }.into());
+        assert_noop!(
+            Signal::remove_parameter_schema(RuntimeOrigin::root(), name),
+            Error::<Test>::UnknownSignalParameter
+        );
+    });
+}
+
 #[test]
 fn send_rating_signal_works_and_emits_event() {
 	new_test_ext().execute_with(|| {
 		System::set_block_number(1);
         Balances::make_free_balance_be(&1, 100u32.into());
         let target = BoundedVec::<u8, ConstU32<1024>>::try_from(b"TARGET".to_vec()).unwrap();
-        assert_ok!(Signal::send_rating_signal(RuntimeOrigin::signed(1), target.clone(), 5));
+        assert_ok!(Signal::send_rating_signal(RuntimeOrigin::signed(1), target.clone(), 5, None));
         // Storage check
-        assert_eq!(RatingSignalList::<Test>::get(1, &target), 5);
+        assert_eq!(
+            RatingSignalList::<Test>::get(1, &target),
+            Some(RatingSignal { rating: 5, attachment: None, updated_at: 1 })
+        );
         // Event check
-        System::assert_has_event(Event::SignalLock { account: 1, amount: 10u32.into() }.into());
+        System::assert_has_event(Event::SignalLock { account: 1, total: 10u32.into() }.into());
         System::assert_last_event(Event::RatingSignalSent { who: 1 }.into());
 	});
 }
@@ -38,8 +153,8 @@ fn cannot_send_duplicate_rating_signal() {
         System::set_block_number(1);
         Balances::make_free_balance_be(&1, 100u32.into());
         let target = BoundedVec::<u8, ConstU32<1024>>::try_from(b"TARGET".to_vec()).unwrap();
-        assert_ok!(Signal::send_rating_signal(RuntimeOrigin::signed(1), target.clone(), 5));
-        assert_noop!(Signal::send_rating_signal(RuntimeOrigin::signed(1), target.clone(), 7), Error::<Test>::RatingSignalAlreadyExists);
+        assert_ok!(Signal::send_rating_signal(RuntimeOrigin::signed(1), target.clone(), 5, None));
+        assert_noop!(Signal::send_rating_signal(RuntimeOrigin::signed(1), target.clone(), 7, None), Error::<Test>::RatingSignalAlreadyExists);
     });
 }
 
@@ -48,7 +163,7 @@ fn cannot_send_rating_signal_with_insufficient_balance() {
 	new_test_ext().execute_with(|| {
 		System::set_block_number(1);
         let target = BoundedVec::<u8, ConstU32<1024>>::try_from(b"TARGET".to_vec()).unwrap();
-        assert_noop!(Signal::send_rating_signal(RuntimeOrigin::signed(1), target, 5), Error::<Test>::InsufficientBalance);
+        assert_noop!(Signal::send_rating_signal(RuntimeOrigin::signed(1), target, 5, None), Error::<Test>::InsufficientBalance);
 	});
 }
 
@@ -58,12 +173,16 @@ fn update_rating_signal_works_and_emits_event() {
 		System::set_block_number(1);
         Balances::make_free_balance_be(&1, 100u32.into());
         let target = BoundedVec::<u8, ConstU32<1024>>::try_from(b"TARGET".to_vec()).unwrap();
-        assert_ok!(Signal::send_rating_signal(RuntimeOrigin::signed(1), target.clone(), 5));
-        assert_ok!(Signal::update_rating_signal(RuntimeOrigin::signed(1), target.clone(), 9));
+        assert_ok!(Signal::send_rating_signal(RuntimeOrigin::signed(1), target.clone(), 5, None));
+        System::set_block_number(3);
+        let review = RatingAttachment::Review(BoundedVec::try_from(b"ipfs://review".to_vec()).unwrap());
+        assert_ok!(Signal::update_rating_signal(RuntimeOrigin::signed(1), target.clone(), 9, Some(review.clone())));
         // Storage check
-        assert_eq!(RatingSignalList::<Test>::get(1, &target), 9);
+        assert_eq!(
+            RatingSignalList::<Test>::get(1, &target),
+            Some(RatingSignal { rating: 9, attachment: Some(review), updated_at: 3 })
+        );
         // Event check
-        System::assert_has_event(Event::SignalLockExtended { account: 1, amount: 10u32.into() }.into());
         System::assert_last_event(Event::RatingSignalUpdated { who: 1 }.into());
 	});
 }
@@ -74,7 +193,7 @@ fn cannot_update_nonexistent_rating_signal() {
 		System::set_block_number(1);
         Balances::make_free_balance_be(&1, 100u32.into());
         let target = BoundedVec::<u8, ConstU32<1024>>::try_from(b"TARGET".to_vec()).unwrap();
-        assert_noop!(Signal::update_rating_signal(RuntimeOrigin::signed(1), target, 9), Error::<Test>::RatingSignalDoesNotExist);
+        assert_noop!(Signal::update_rating_signal(RuntimeOrigin::signed(1), target, 9, None), Error::<Test>::RatingSignalDoesNotExist);
 	});
 }
 
@@ -84,12 +203,12 @@ fn revoke_rating_signal_works_and_emits_event() {
 		System::set_block_number(1);
         Balances::make_free_balance_be(&1, 100u32.into());
         let target = BoundedVec::<u8, ConstU32<1024>>::try_from(b"TARGET".to_vec()).unwrap();
-        assert_ok!(Signal::send_rating_signal(RuntimeOrigin::signed(1), target.clone(), 5));
+        assert_ok!(Signal::send_rating_signal(RuntimeOrigin::signed(1), target.clone(), 5, None));
         assert_ok!(Signal::revoke_rating_signal(RuntimeOrigin::signed(1), target.clone()));
         // Storage check
-        assert_eq!(RatingSignalList::<Test>::get(1, &target), 0);
+        assert!(!RatingSignalList::<Test>::contains_key(1, &target));
         // Event check
-        System::assert_has_event(Event::SignalUnlock { account: 1 }.into());
+        System::assert_has_event(Event::SignalUnlock { account: 1, total: 0u32.into() }.into());
         System::assert_last_event(Event::RatingSignalRevoked { who: 1 }.into());
 	});
 }
@@ -103,6 +222,43 @@ fn cannot_revoke_nonexistent_rating_signal() {
     });
 }
 
+#[test]
+fn ratings_above_max_rating_are_rejected() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        Balances::make_free_balance_be(&1, 100u32.into());
+        let target = BoundedVec::<u8, ConstU32<1024>>::try_from(b"TARGET".to_vec()).unwrap();
+        assert_noop!(
+            Signal::send_rating_signal(RuntimeOrigin::signed(1), target.clone(), 11, None),
+            Error::<Test>::InvalidRating
+        );
+        assert_ok!(Signal::send_rating_signal(RuntimeOrigin::signed(1), target.clone(), 10, None));
+        assert_noop!(
+            Signal::update_rating_signal(RuntimeOrigin::signed(1), target, 11, None),
+            Error::<Test>::InvalidRating
+        );
+    });
+}
+
+#[test]
+fn rating_signals_keep_their_attachment() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(2);
+        Balances::make_free_balance_be(&1, 100u32.into());
+        let target = BoundedVec::<u8, ConstU32<1024>>::try_from(b"TARGET".to_vec()).unwrap();
+        let hash = RatingAttachment::ContentHash([7; 32]);
+        assert_ok!(Signal::send_rating_signal(RuntimeOrigin::signed(1), target.clone(), 4, Some(hash.clone())));
+        assert_eq!(
+            Signal::rating_signal_list(1, &target),
+            Some(RatingSignal { rating: 4, attachment: Some(hash), updated_at: 2 })
+        );
+
+        // Updating without an attachment drops the old one.
+        assert_ok!(Signal::update_rating_signal(RuntimeOrigin::signed(1), target.clone(), 6, None));
+        assert_eq!(Signal::rating_signal_list(1, &target).and_then(|signal| signal.attachment), None);
+    });
+}
+
 #[test]
 fn send_signal_works_and_emits_event() {
     new_test_ext().execute_with(|| {
@@ -123,3 +279,602 @@ fn send_service_signal_works_and_emits_event() {
         System::assert_last_event(Event::ServiceSignalSent { service_identifier, url, who: 1 }.into());
 	});
 }
+
+#[test]
+fn rating_aggregates_follow_send_update_and_revoke() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        Balances::make_free_balance_be(&1, 100u32.into());
+        Balances::make_free_balance_be(&2, 100u32.into());
+        let target = BoundedVec::<u8, ConstU32<1024>>::try_from(b"TARGET".to_vec()).unwrap();
+        assert_ok!(Signal::send_rating_signal(RuntimeOrigin::signed(1), target.clone(), 4, None));
+        assert_ok!(Signal::send_rating_signal(RuntimeOrigin::signed(2), target.clone(), 8, None));
+        assert_eq!(
+            RatingAggregates::<Test>::get(&target),
+            RatingAggregate { count: 2, sum: 12, total_weight: 0, weighted_sum: 0 }
+        );
+
+        assert_ok!(Signal::update_rating_signal(RuntimeOrigin::signed(1), target.clone(), 6, None));
+        assert_eq!(RatingAggregates::<Test>::get(&target).sum, 14);
+        assert_eq!(Signal::rating_aggregate_of(b"TARGET".to_vec()).count, 2);
+
+        assert_ok!(Signal::revoke_rating_signal(RuntimeOrigin::signed(2), target.clone()));
+        assert_eq!(
+            RatingAggregates::<Test>::get(&target),
+            RatingAggregate { count: 1, sum: 6, total_weight: 0, weighted_sum: 0 }
+        );
+        assert_ok!(Signal::revoke_rating_signal(RuntimeOrigin::signed(1), target.clone()));
+        assert!(!RatingAggregates::<Test>::contains_key(&target));
+    });
+}
+
+#[test]
+fn weighted_rating_aggregates_use_the_weight_at_rating_time() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        Balances::make_free_balance_be(&1, 100u32.into());
+        Balances::make_free_balance_be(&2, 100u32.into());
+        let target = BoundedVec::<u8, ConstU32<1024>>::try_from(b"TARGET".to_vec()).unwrap();
+        MockRatingWeight::set(Some(3));
+        assert_ok!(Signal::send_rating_signal(RuntimeOrigin::signed(1), target.clone(), 4, None));
+        MockRatingWeight::set(None);
+        assert_ok!(Signal::send_rating_signal(RuntimeOrigin::signed(2), target.clone(), 8, None));
+        assert_eq!(RatingWeights::<Test>::get(1, &target), Some(3));
+        assert_eq!(RatingWeights::<Test>::get(2, &target), None);
+        assert_eq!(
+            RatingAggregates::<Test>::get(&target),
+            RatingAggregate { count: 2, sum: 12, total_weight: 3, weighted_sum: 12 }
+        );
+
+        // The rater's weight has changed since, so the update swaps the old weight for the new.
+        MockRatingWeight::set(Some(5));
+        assert_ok!(Signal::update_rating_signal(RuntimeOrigin::signed(1), target.clone(), 2, None));
+        assert_eq!(
+            RatingAggregates::<Test>::get(&target),
+            RatingAggregate { count: 2, sum: 10, total_weight: 5, weighted_sum: 10 }
+        );
+
+        MockRatingWeight::set(Some(1));
+        assert_ok!(Signal::revoke_rating_signal(RuntimeOrigin::signed(1), target.clone()));
+        assert_eq!(
+            RatingAggregates::<Test>::get(&target),
+            RatingAggregate { count: 1, sum: 8, total_weight: 0, weighted_sum: 0 }
+        );
+        assert!(!RatingWeights::<Test>::contains_key(1, &target));
+    });
+}
+
+#[test]
+fn migration_v1_computes_rating_aggregates() {
+    new_test_ext().execute_with(|| {
+        StorageVersion::new(0).put::<Signal>();
+        let target = BoundedVec::<u8, ConstU32<1024>>::try_from(b"TARGET".to_vec()).unwrap();
+        old::RatingSignalList::<Test>::insert(1, &target, 3);
+        old::RatingSignalList::<Test>::insert(2, &target, 5);
+
+        crate::migrations::v1::MigrateV0ToV1::<Test>::on_runtime_upgrade();
+
+        assert_eq!(
+            RatingAggregates::<Test>::get(&target),
+            RatingAggregate { count: 2, sum: 8, total_weight: 0, weighted_sum: 0 }
+        );
+        assert_eq!(Signal::on_chain_storage_version(), StorageVersion::new(1));
+    });
+}
+
+fn locked(who: u64) -> u128 {
+    pallet_balances::Locks::<Test>::get(who).iter().map(|lock| lock.amount).sum()
+}
+
+fn rating_target(index: u8) -> BoundedVec<u8, ConstU32<1024>> {
+    BoundedVec::try_from(vec![b'T', index]).unwrap()
+}
+
+#[test]
+fn signal_lock_scales_with_active_ratings() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        Balances::make_free_balance_be(&1, 100u32.into());
+        for index in 0..5 {
+            assert_ok!(Signal::send_rating_signal(RuntimeOrigin::signed(1), rating_target(index), 5, None));
+        }
+        assert_eq!(Signal::rating_count(1), 5);
+        assert_eq!(locked(1), 50);
+
+        // Updating a rating leaves the lock as it is.
+        assert_ok!(Signal::update_rating_signal(RuntimeOrigin::signed(1), rating_target(0), 3, None));
+        assert_eq!(locked(1), 50);
+
+        // Revoking one rating only releases its own share.
+        assert_ok!(Signal::revoke_rating_signal(RuntimeOrigin::signed(1), rating_target(2)));
+        System::assert_has_event(Event::SignalUnlock { account: 1, total: 40u32.into() }.into());
+        assert_eq!(Signal::rating_count(1), 4);
+        assert_eq!(locked(1), 40);
+
+        // A revoked rating can be sent again and takes its share back.
+        assert_ok!(Signal::send_rating_signal(RuntimeOrigin::signed(1), rating_target(2), 1, None));
+        assert_eq!(locked(1), 50);
+
+        for index in 0..5 {
+            assert_ok!(Signal::revoke_rating_signal(RuntimeOrigin::signed(1), rating_target(index)));
+        }
+        assert!(!RatingCount::<Test>::contains_key(1));
+        assert_eq!(locked(1), 0);
+    });
+}
+
+#[test]
+fn updating_a_rating_leaves_the_lock_at_one_share_per_rating() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        Balances::make_free_balance_be(&1, 30u32.into());
+        for index in 0..3 {
+            assert_ok!(Signal::send_rating_signal(RuntimeOrigin::signed(1), rating_target(index), 5, None));
+        }
+        System::assert_has_event(Event::SignalLock { account: 1, total: 30u32.into() }.into());
+        System::reset_events();
+
+        // The whole balance is locked, which does not stop the ratings from being updated.
+        assert_ok!(Signal::update_rating_signal(RuntimeOrigin::signed(1), rating_target(1), 2, None));
+        assert_eq!(locked(1), 30);
+        assert!(!System::events().iter().any(|record| matches!(
+            record.event,
+            RuntimeEvent::Signal(Event::SignalLock { .. } | Event::SignalUnlock { .. })
+        )));
+    });
+}
+
+#[test]
+fn rating_signal_can_lock_the_whole_free_balance() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        Balances::make_free_balance_be(&1, 20u32.into());
+        assert_ok!(Signal::send_rating_signal(RuntimeOrigin::signed(1), rating_target(0), 5, None));
+        assert_ok!(Signal::send_rating_signal(RuntimeOrigin::signed(1), rating_target(1), 5, None));
+        assert_noop!(
+            Signal::send_rating_signal(RuntimeOrigin::signed(1), rating_target(2), 5, None),
+            Error::<Test>::InsufficientBalance
+        );
+        assert_eq!(locked(1), 20);
+    });
+}
+
+#[test]
+fn signal_lock_is_tracked_per_account() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        Balances::make_free_balance_be(&1, 100u32.into());
+        Balances::make_free_balance_be(&2, 100u32.into());
+        assert_ok!(Signal::send_rating_signal(RuntimeOrigin::signed(1), rating_target(0), 5, None));
+        assert_ok!(Signal::send_rating_signal(RuntimeOrigin::signed(1), rating_target(1), 5, None));
+        assert_ok!(Signal::send_rating_signal(RuntimeOrigin::signed(2), rating_target(0), 5, None));
+
+        assert_ok!(Signal::revoke_rating_signal(RuntimeOrigin::signed(2), rating_target(0)));
+        assert_eq!(locked(1), 20);
+        assert_eq!(locked(2), 0);
+    });
+}
+
+#[test]
+fn cannot_send_rating_signal_beyond_lockable_balance() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        Balances::make_free_balance_be(&1, 25u32.into());
+        assert_ok!(Signal::send_rating_signal(RuntimeOrigin::signed(1), rating_target(0), 5, None));
+        assert_ok!(Signal::send_rating_signal(RuntimeOrigin::signed(1), rating_target(1), 5, None));
+        assert_noop!(
+            Signal::send_rating_signal(RuntimeOrigin::signed(1), rating_target(2), 5, None),
+            Error::<Test>::InsufficientBalance
+        );
+        assert_eq!(locked(1), 20);
+    });
+}
+
+#[test]
+fn migration_v2_counts_ratings_and_resizes_locks() {
+    new_test_ext().execute_with(|| {
+        Balances::make_free_balance_be(&1, 100u32.into());
+        StorageVersion::new(1).put::<Signal>();
+        for index in 0..3 {
+            old::RatingSignalList::<Test>::insert(1, rating_target(index), 5);
+        }
+
+        crate::migrations::v2::MigrateV1ToV2::<Test>::on_runtime_upgrade();
+
+        assert_eq!(Signal::rating_count(1), 3);
+        assert_eq!(locked(1), 30);
+        assert_eq!(Signal::on_chain_storage_version(), StorageVersion::new(2));
+    });
+}
+
+#[test]
+fn migration_v3_converts_parameters_to_typed_values() {
+    new_test_ext().execute_with(|| {
+        StorageVersion::new(2).put::<Signal>();
+        let name = BoundedVec::<u8, ConstU32<1024>>::try_from(b"PARAM".to_vec()).unwrap();
+        frame_support::storage::unhashed::put(&SignalParameterList::<Test>::hashed_key_for(1, &name), &7u8);
+
+        crate::migrations::v3::MigrateV2ToV3::<Test>::on_runtime_upgrade();
+
+        assert_eq!(SignalParameterList::<Test>::get(1, &name), Some(ParameterValue::Integer(7)));
+        assert_eq!(
+            SignalParameterSchemas::<Test>::get(&name),
+            Some(crate::migrations::v3::DEFAULT_SCHEMA)
+        );
+        assert_eq!(Signal::on_chain_storage_version(), StorageVersion::new(3));
+
+        assert_ok!(Signal::set_signal_parameter(
+            RuntimeOrigin::signed(1),
+            name.clone(),
+            ParameterValue::Integer(255)
+        ));
+        assert_noop!(
+            Signal::set_signal_parameter(RuntimeOrigin::signed(1), name, ParameterValue::Integer(256)),
+            Error::<Test>::InvalidSignalParameter
+        );
+    });
+}
+
+#[test]
+fn migration_v3_keeps_registered_schemas() {
+    new_test_ext().execute_with(|| {
+        StorageVersion::new(2).put::<Signal>();
+        let name = BoundedVec::<u8, ConstU32<1024>>::try_from(b"PARAM".to_vec()).unwrap();
+        let schema = ParameterSchema { value_type: ParameterType::Integer, min: 0, max: 10 };
+        SignalParameterSchemas::<Test>::insert(&name, schema);
+        frame_support::storage::unhashed::put(&SignalParameterList::<Test>::hashed_key_for(1, &name), &7u8);
+
+        crate::migrations::v3::MigrateV2ToV3::<Test>::on_runtime_upgrade();
+
+        assert_eq!(SignalParameterSchemas::<Test>::get(&name), Some(schema));
+    });
+}
+
+fn bytes(value: &[u8]) -> BoundedVec<u8, ConstU32<1024>> {
+    BoundedVec::try_from(value.to_vec()).unwrap()
+}
+
+fn signal_deposit_held(who: u64) -> u128 {
+    Balances::balance_on_hold(&HoldReason::SignalDeposit.into(), &who)
+}
+
+#[test]
+fn persist_signal_stores_signal_and_holds_deposit() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        Balances::make_free_balance_be(&1, 100u32.into());
+        assert_ok!(Signal::persist_signal(RuntimeOrigin::signed(1), bytes(b"TOPIC"), bytes(b"HELLO"), 10));
+
+        // Base deposit of 5 plus 10 bytes of topic and content.
+        assert_eq!(
+            PersistedSignals::<Test>::get(1, bytes(b"TOPIC")),
+            Some(PersistedSignal { content: SignalContent::Signal(bytes(b"HELLO")), deposit: 15, expires_at: 11 })
+        );
+        assert_eq!(signal_deposit_held(1), 15);
+        System::assert_has_event(Event::SignalSent { signal: bytes(b"HELLO"), who: 1 }.into());
+        System::assert_last_event(Event::SignalPersisted { who: 1, topic: bytes(b"TOPIC"), expires_at: 11 }.into());
+
+        // Persisting under the same topic replaces the signal and resizes the deposit.
+        assert_ok!(Signal::persist_signal(RuntimeOrigin::signed(1), bytes(b"TOPIC"), bytes(b"HI"), 20));
+        assert_eq!(
+            PersistedSignals::<Test>::get(1, bytes(b"TOPIC")),
+            Some(PersistedSignal { content: SignalContent::Signal(bytes(b"HI")), deposit: 12, expires_at: 21 })
+        );
+        assert_eq!(signal_deposit_held(1), 12);
+    });
+}
+
+#[test]
+fn cannot_persist_signal_with_invalid_ttl_or_without_deposit() {
+    new_test_ext().execute_with(|| {
+        Balances::make_free_balance_be(&1, 100u32.into());
+        assert_noop!(
+            Signal::persist_signal(RuntimeOrigin::signed(1), bytes(b"TOPIC"), bytes(b"HELLO"), 0),
+            Error::<Test>::InvalidSignalTtl
+        );
+        assert_noop!(
+            Signal::persist_signal(RuntimeOrigin::signed(1), bytes(b"TOPIC"), bytes(b"HELLO"), 101),
+            Error::<Test>::InvalidSignalTtl
+        );
+        Balances::make_free_balance_be(&2, 10u32.into());
+        assert!(Signal::persist_signal(RuntimeOrigin::signed(2), bytes(b"TOPIC"), bytes(b"HELLO"), 10).is_err());
+        assert!(!PersistedSignals::<Test>::contains_key(2, bytes(b"TOPIC")));
+    });
+}
+
+#[test]
+fn remove_persisted_signal_releases_deposit() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        Balances::make_free_balance_be(&1, 100u32.into());
+        assert_ok!(Signal::persist_service_signal(RuntimeOrigin::signed(1), bytes(b"SVC"), bytes(b"URL"), 10));
+        assert_ok!(Signal::remove_persisted_signal(RuntimeOrigin::signed(1), bytes(b"SVC")));
+
+        assert!(!PersistedSignals::<Test>::contains_key(1, bytes(b"SVC")));
+        assert!(!ServiceSignals::<Test>::contains_key(bytes(b"SVC"), 1));
+        assert_eq!(signal_deposit_held(1), 0);
+        System::assert_last_event(Event::PersistedSignalRemoved { who: 1, topic: bytes(b"SVC") }.into());
+        assert_noop!(
+            Signal::remove_persisted_signal(RuntimeOrigin::signed(1), bytes(b"SVC")),
+            Error::<Test>::PersistedSignalDoesNotExist
+        );
+    });
+}
+
+#[test]
+fn persisted_signals_are_swept_once_expired() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        Balances::make_free_balance_be(&1, 100u32.into());
+        assert_ok!(Signal::persist_signal(RuntimeOrigin::signed(1), bytes(b"SHORT"), bytes(b"A"), 2));
+        assert_ok!(Signal::persist_signal(RuntimeOrigin::signed(1), bytes(b"LONG"), bytes(b"B"), 2));
+        // Renewed, so the sweep in block 3 must leave it alone.
+        assert_ok!(Signal::persist_signal(RuntimeOrigin::signed(1), bytes(b"LONG"), bytes(b"B"), 5));
+
+        Signal::on_idle(2, Weight::MAX);
+        assert!(PersistedSignals::<Test>::contains_key(1, bytes(b"SHORT")));
+
+        System::set_block_number(3);
+        Signal::on_idle(3, Weight::MAX);
+        assert!(!PersistedSignals::<Test>::contains_key(1, bytes(b"SHORT")));
+        assert!(PersistedSignals::<Test>::contains_key(1, bytes(b"LONG")));
+        System::assert_has_event(Event::PersistedSignalExpired { who: 1, topic: bytes(b"SHORT") }.into());
+        assert_eq!(signal_deposit_held(1), 10);
+
+        Signal::on_idle(6, Weight::MAX);
+        assert!(!PersistedSignals::<Test>::contains_key(1, bytes(b"LONG")));
+        assert_eq!(signal_deposit_held(1), 0);
+    });
+}
+
+#[test]
+fn on_idle_only_reads_the_signal_expiry_cursor_once_nothing_is_pending() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        Balances::make_free_balance_be(&1, 100u32.into());
+        assert_ok!(Signal::persist_signal(RuntimeOrigin::signed(1), bytes(b"TOPIC"), bytes(b"A"), 1));
+        System::set_block_number(2);
+        Signal::on_idle(2, Weight::MAX);
+        assert!(!PersistedSignals::<Test>::contains_key(1, bytes(b"TOPIC")));
+        assert_eq!(SignalExpiryCursor::<Test>::get(), None);
+
+        let db: frame_support::weights::RuntimeDbWeight = <Test as frame_system::Config>::DbWeight::get();
+        for n in 3..6 {
+            System::set_block_number(n);
+            assert_eq!(Signal::on_idle(n, Weight::MAX), db.reads(1));
+            assert_eq!(SignalExpiryCursor::<Test>::get(), None);
+        }
+
+        // Removing the last pending signal by hand clears the cursor as well.
+        assert_ok!(Signal::persist_signal(RuntimeOrigin::signed(1), bytes(b"TOPIC"), bytes(b"A"), 5));
+        assert!(SignalExpiryCursor::<Test>::get().is_some());
+        assert_ok!(Signal::remove_persisted_signal(RuntimeOrigin::signed(1), bytes(b"TOPIC")));
+        assert_eq!(SignalExpiryCursor::<Test>::get(), None);
+    });
+}
+
+#[test]
+fn signal_expiries_overflow_into_the_next_block_with_room() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        Balances::make_free_balance_be(&1, 100u32.into());
+        for index in 0..4 {
+            assert_ok!(Signal::persist_signal(RuntimeOrigin::signed(1), rating_target(index), bytes(b"A"), 10));
+        }
+        assert_eq!(SignalExpiries::<Test>::get(11).len(), 3);
+        assert_eq!(SignalExpiries::<Test>::get(12).into_inner(), vec![(1, rating_target(3))]);
+        assert_eq!(ScheduledSignalExpiries::<Test>::get(1, rating_target(3)), Some(12));
+
+        // The pushed-back signal still reports its own expiry and is swept a block later.
+        Signal::on_idle(11, Weight::MAX);
+        assert!(!PersistedSignals::<Test>::contains_key(1, rating_target(0)));
+        assert_eq!(PersistedSignals::<Test>::get(1, rating_target(3)).map(|signal| signal.expires_at), Some(11));
+        Signal::on_idle(12, Weight::MAX);
+        assert!(!PersistedSignals::<Test>::contains_key(1, rating_target(3)));
+        assert_eq!(ScheduledSignalExpiries::<Test>::get(1, rating_target(3)), None);
+        assert_eq!(signal_deposit_held(1), 0);
+    });
+}
+
+#[test]
+fn cannot_persist_signal_when_every_nearby_block_is_full() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        Balances::make_free_balance_be(&1, 100u32.into());
+        for block in 11..11 + fennel_expiry::MAX_EXPIRY_DELAY as u64 {
+            let full: Vec<(u64, BoundedVec<u8, ConstU32<1024>>)> =
+                (0..3).map(|index| (100 + block, rating_target(index))).collect();
+            SignalExpiries::<Test>::insert(block, BoundedVec::try_from(full).unwrap());
+        }
+        assert_noop!(
+            Signal::persist_signal(RuntimeOrigin::signed(1), bytes(b"TOPIC"), bytes(b"A"), 10),
+            Error::<Test>::TooManySignalExpiries
+        );
+    });
+}
+
+#[test]
+fn replacing_or_removing_a_persisted_signal_frees_its_expiry_slot() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        Balances::make_free_balance_be(&1, 100u32.into());
+        Balances::make_free_balance_be(&2, 100u32.into());
+        // One signal persisted again with every TTL only ever takes one place.
+        for ttl in 1..=10 {
+            assert_ok!(Signal::persist_signal(RuntimeOrigin::signed(1), bytes(b"TOPIC"), bytes(b"A"), ttl));
+        }
+        assert!((2..11).all(|block| SignalExpiries::<Test>::get(block).is_empty()));
+        assert_eq!(SignalExpiries::<Test>::get(11).into_inner(), vec![(1, bytes(b"TOPIC"))]);
+        assert_eq!(ScheduledSignalExpiries::<Test>::get(1, bytes(b"TOPIC")), Some(11));
+
+        for index in 0..2 {
+            assert_ok!(Signal::persist_signal(RuntimeOrigin::signed(2), rating_target(index), bytes(b"A"), 10));
+        }
+        assert_ok!(Signal::remove_persisted_signal(RuntimeOrigin::signed(1), bytes(b"TOPIC")));
+        assert_eq!(SignalExpiries::<Test>::get(11).len(), 2);
+        assert_eq!(ScheduledSignalExpiries::<Test>::get(1, bytes(b"TOPIC")), None);
+        assert_ok!(Signal::persist_signal(RuntimeOrigin::signed(2), rating_target(2), bytes(b"A"), 10));
+        assert_eq!(ScheduledSignalExpiries::<Test>::get(2, rating_target(2)), Some(11));
+    });
+}
+
+#[test]
+fn live_service_signals_are_listed_by_service_identifier() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        for who in 1..=3 {
+            Balances::make_free_balance_be(&who, 100u32.into());
+        }
+        assert_ok!(Signal::persist_service_signal(RuntimeOrigin::signed(1), bytes(b"WF"), bytes(b"https://a"), 10));
+        assert_ok!(Signal::persist_service_signal(RuntimeOrigin::signed(2), bytes(b"WF"), bytes(b"https://b"), 5));
+        // A generic signal under the same topic is not a service announcement.
+        assert_ok!(Signal::persist_signal(RuntimeOrigin::signed(3), bytes(b"WF"), bytes(b"https://c"), 10));
+        assert_ok!(Signal::persist_service_signal(RuntimeOrigin::signed(3), bytes(b"OTHER"), bytes(b"https://c"), 10));
+
+        let mut signals = Signal::live_service_signals(b"WF".to_vec());
+        signals.sort_by_key(|signal| signal.provider);
+        assert_eq!(
+            signals,
+            vec![
+                ServiceSignal { provider: 1, url: b"https://a".to_vec(), expires_at: 11 },
+                ServiceSignal { provider: 2, url: b"https://b".to_vec(), expires_at: 6 },
+            ]
+        );
+
+        // Expired announcements are hidden even before the sweep removes them.
+        System::set_block_number(6);
+        assert_eq!(
+            Signal::live_service_signals(b"WF".to_vec()),
+            vec![ServiceSignal { provider: 1, url: b"https://a".to_vec(), expires_at: 11 }]
+        );
+
+        // Replacing an announcement with a generic signal drops it from the list.
+        assert_ok!(Signal::persist_signal(RuntimeOrigin::signed(1), bytes(b"WF"), bytes(b"gone"), 10));
+        assert!(Signal::live_service_signals(b"WF".to_vec()).is_empty());
+    });
+}
+
+fn service_deposit_held(who: u64) -> u128 {
+    Balances::balance_on_hold(&HoldReason::ServiceDeposit.into(), &who)
+}
+
+fn urls(urls: &[&[u8]]) -> BoundedVec<BoundedVec<u8, ConstU32<1024>>, ConstU32<3>> {
+    BoundedVec::try_from(urls.iter().map(|url| bytes(url)).collect::<Vec<_>>()).unwrap()
+}
+
+#[test]
+fn register_service_holds_deposit_and_lists_endpoint() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        Balances::make_free_balance_be(&1, 100u32.into());
+        assert_ok!(Signal::register_service(RuntimeOrigin::signed(1), bytes(b"SVC"), urls(&[b"https://a", b"https://b"])));
+
+        // Base deposit of 5 plus 21 bytes of identifier and URLs.
+        assert_eq!(service_deposit_held(1), 26);
+        assert_eq!(ServiceRegistry::<Test>::get(bytes(b"SVC"), 1).map(|registration| registration.deposit), Some(26));
+        System::assert_last_event(Event::ServiceRegistered { service_identifier: bytes(b"SVC"), provider: 1 }.into());
+        assert_eq!(
+            Signal::live_service_endpoints(b"SVC".to_vec()),
+            vec![ServiceEndpoint {
+                provider: 1,
+                urls: vec![b"https://a".to_vec(), b"https://b".to_vec()],
+                last_heartbeat: 1,
+            }]
+        );
+
+        assert_noop!(
+            Signal::register_service(RuntimeOrigin::signed(1), bytes(b"SVC"), urls(&[b"https://c"])),
+            Error::<Test>::ServiceAlreadyRegistered
+        );
+        assert_noop!(
+            Signal::register_service(RuntimeOrigin::signed(1), bytes(b"OTHER"), urls(&[])),
+            Error::<Test>::NoServiceUrls
+        );
+    });
+}
+
+#[test]
+fn update_service_replaces_urls_and_resizes_deposit() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        Balances::make_free_balance_be(&1, 100u32.into());
+        assert_noop!(
+            Signal::update_service(RuntimeOrigin::signed(1), bytes(b"SVC"), urls(&[b"https://a"])),
+            Error::<Test>::ServiceNotRegistered
+        );
+        assert_ok!(Signal::register_service(RuntimeOrigin::signed(1), bytes(b"SVC"), urls(&[b"https://a", b"https://b"])));
+
+        System::set_block_number(4);
+        assert_ok!(Signal::update_service(RuntimeOrigin::signed(1), bytes(b"SVC"), urls(&[b"https://c"])));
+        assert_eq!(service_deposit_held(1), 17);
+        let registration = ServiceRegistry::<Test>::get(bytes(b"SVC"), 1).unwrap();
+        assert_eq!(registration.urls, urls(&[b"https://c"]));
+        assert_eq!(registration.last_heartbeat, 4);
+        System::assert_last_event(Event::ServiceUpdated { service_identifier: bytes(b"SVC"), provider: 1 }.into());
+    });
+}
+
+#[test]
+fn services_go_stale_without_heartbeats() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        Balances::make_free_balance_be(&1, 100u32.into());
+        Balances::make_free_balance_be(&2, 100u32.into());
+        assert_ok!(Signal::register_service(RuntimeOrigin::signed(1), bytes(b"SVC"), urls(&[b"https://a"])));
+        System::set_block_number(5);
+        assert_ok!(Signal::register_service(RuntimeOrigin::signed(2), bytes(b"SVC"), urls(&[b"https://b"])));
+
+        // The liveness period is 10 blocks, so provider 1 is stale from block 11.
+        System::set_block_number(11);
+        assert_eq!(Signal::service_status(&bytes(b"SVC"), &1), Some(ServiceStatus::Stale));
+        assert_eq!(Signal::service_status(&bytes(b"SVC"), &2), Some(ServiceStatus::Live));
+        let providers: Vec<u64> =
+            Signal::live_service_endpoints(b"SVC".to_vec()).into_iter().map(|endpoint| endpoint.provider).collect();
+        assert_eq!(providers, vec![2]);
+
+        assert_ok!(Signal::service_heartbeat(RuntimeOrigin::signed(1), bytes(b"SVC")));
+        System::assert_last_event(Event::ServiceHeartbeat { service_identifier: bytes(b"SVC"), provider: 1 }.into());
+        assert_eq!(Signal::service_status(&bytes(b"SVC"), &1), Some(ServiceStatus::Live));
+        assert_eq!(Signal::live_service_endpoints(b"SVC".to_vec()).len(), 2);
+        assert_noop!(
+            Signal::service_heartbeat(RuntimeOrigin::signed(3), bytes(b"SVC")),
+            Error::<Test>::ServiceNotRegistered
+        );
+    });
+}
+
+#[test]
+fn deregister_service_releases_deposit() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        Balances::make_free_balance_be(&1, 100u32.into());
+        assert_ok!(Signal::register_service(RuntimeOrigin::signed(1), bytes(b"SVC"), urls(&[b"https://a"])));
+        assert_ok!(Signal::deregister_service(RuntimeOrigin::signed(1), bytes(b"SVC")));
+
+        assert_eq!(service_deposit_held(1), 0);
+        assert_eq!(Signal::service_status(&bytes(b"SVC"), &1), None);
+        assert!(Signal::live_service_endpoints(b"SVC".to_vec()).is_empty());
+        System::assert_last_event(Event::ServiceDeregistered { service_identifier: bytes(b"SVC"), provider: 1 }.into());
+        assert_noop!(
+            Signal::deregister_service(RuntimeOrigin::signed(1), bytes(b"SVC")),
+            Error::<Test>::ServiceNotRegistered
+        );
+    });
+}
+
+#[test]
+fn migration_v4_wraps_ratings_in_rating_signals() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(7);
+        StorageVersion::new(3).put::<Signal>();
+        old::RatingSignalList::<Test>::insert(1, rating_target(0), 4);
+
+        crate::migrations::v4::MigrateV3ToV4::<Test>::on_runtime_upgrade();
+
+        assert_eq!(
+            RatingSignalList::<Test>::get(1, rating_target(0)),
+            Some(RatingSignal { rating: 4, attachment: None, updated_at: 7 })
+        );
+        assert_eq!(Signal::on_chain_storage_version(), StorageVersion::new(4));
+    });
+}
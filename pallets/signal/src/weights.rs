@@ -1,31 +1,10 @@
 
-//! Autogenerated weights for `pallet_signal`
+//! Estimated weights for `pallet_signal`
 //!
-//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 47.0.0
-//! DATE: 2025-05-20, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
-//! WORST CASE MAP SIZE: `1000000`
-//! HOSTNAME: `DESKTOP-AH3IE72`, CPU: `AMD Ryzen 3 3100 4-Core Processor`
-//! WASM-EXECUTION: `Compiled`, CHAIN: `Some("dev")`, DB CACHE: `1024`
-
-// Executed Command:
-// ./target/release/fennel-node
-// benchmark
-// pallet
-// --chain
-// dev
-// --wasm-execution=compiled
-// --pallet
-// pallet_signal
-// --extrinsic
-// *
-// --steps
-// 50
-// --repeat
-// 20
-// --output
-// pallets/signal/src/weights.rs
-// --template
-// ./scripts/benchmarking/frame-weight-template.hbs
+//! These figures are NOT benchmark output. They start from the benchmark run of 2025-05-20
+//! and were adjusted by hand for the storage reads and writes of the calls added or
+//! changed since. The per-call execution times are estimates. Regenerate this file with
+//! `./scripts/benchmarking/frame-weight-template.hbs` before relying on these weights.
 
 #![cfg_attr(rustfmt, rustfmt_skip)]
 #![allow(unused_parens)]
@@ -47,90 +26,124 @@ pub trait WeightInfo {
 	fn send_signal_large_input() -> Weight;
 	fn send_service_signal() -> Weight;
 	fn send_service_signal_large_input() -> Weight;
+	fn set_parameter_schema() -> Weight;
+	fn remove_parameter_schema() -> Weight;
+	fn persist_signal() -> Weight;
+	fn persist_service_signal() -> Weight;
+	fn remove_persisted_signal() -> Weight;
+	fn expire_signal() -> Weight;
+	fn register_service() -> Weight;
+	fn update_service() -> Weight;
+	fn service_heartbeat() -> Weight;
+	fn deregister_service() -> Weight;
 }
 
 /// Weights for `pallet_signal` using the Substrate node and recommended hardware.
 pub struct SubstrateWeight<T>(PhantomData<T>);
 impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: `Signal::SignalParameterSchemas` (r:1 w:0)
+	/// Proof: `Signal::SignalParameterSchemas` (`max_values`: None, `max_size`: Some(1059), added: 3534, mode: `MaxEncodedLen`)
 	/// Storage: `Signal::SignalParameterList` (r:0 w:1)
-	/// Proof: `Signal::SignalParameterList` (`max_values`: None, `max_size`: Some(1091), added: 3566, mode: `MaxEncodedLen`)
+	/// Proof: `Signal::SignalParameterList` (`max_values`: None, `max_size`: Some(2117), added: 4592, mode: `MaxEncodedLen`)
 	fn set_signal_parameter() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `0`
-		//  Estimated: `0`
+		//  Measured:  `47`
+		//  Estimated: `4524`
 		// Minimum execution time: 11_545_000 picoseconds.
-		Weight::from_parts(12_131_000, 0)
+		Weight::from_parts(12_131_000, 4524)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	/// Storage: `Signal::SignalParameterSchemas` (r:1 w:0)
+	/// Proof: `Signal::SignalParameterSchemas` (`max_values`: None, `max_size`: Some(1059), added: 3534, mode: `MaxEncodedLen`)
 	/// Storage: `Signal::SignalParameterList` (r:0 w:1)
-	/// Proof: `Signal::SignalParameterList` (`max_values`: None, `max_size`: Some(1091), added: 3566, mode: `MaxEncodedLen`)
+	/// Proof: `Signal::SignalParameterList` (`max_values`: None, `max_size`: Some(2117), added: 4592, mode: `MaxEncodedLen`)
 	fn set_signal_parameter_large_input() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `0`
-		//  Estimated: `0`
+		//  Measured:  `47`
+		//  Estimated: `4524`
 		// Minimum execution time: 14_144_000 picoseconds.
-		Weight::from_parts(14_516_000, 0)
+		Weight::from_parts(14_516_000, 4524)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
 	/// Storage: `Signal::RatingSignalList` (r:1 w:1)
-	/// Proof: `Signal::RatingSignalList` (`max_values`: None, `max_size`: Some(1091), added: 3566, mode: `MaxEncodedLen`)
+	/// Proof: `Signal::RatingSignalList` (`max_values`: None, `max_size`: Some(1355), added: 3830, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Locks` (r:1 w:1)
 	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Freezes` (r:1 w:0)
 	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `Signal::RatingAggregates` (r:1 w:1)
+	/// Proof: `Signal::RatingAggregates` (`max_values`: None, `max_size`: Some(1070), added: 3545, mode: `MaxEncodedLen`)
+	/// Storage: `Signal::RatingWeights` (r:0 w:1)
+	/// Proof: `Signal::RatingWeights` (`max_values`: None, `max_size`: Some(1094), added: 3569, mode: `MaxEncodedLen`)
+	/// Storage: `Signal::RatingCount` (r:1 w:1)
+	/// Proof: `Signal::RatingCount` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
 	fn send_rating_signal() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `2029`
-		//  Estimated: `4764`
+		//  Estimated: `4820`
 		// Minimum execution time: 101_423_000 picoseconds.
-		Weight::from_parts(117_504_000, 4764)
-			.saturating_add(T::DbWeight::get().reads(3_u64))
-			.saturating_add(T::DbWeight::get().writes(2_u64))
+		Weight::from_parts(117_504_000, 4820)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
 	}
 	/// Storage: `Signal::RatingSignalList` (r:1 w:1)
-	/// Proof: `Signal::RatingSignalList` (`max_values`: None, `max_size`: Some(1091), added: 3566, mode: `MaxEncodedLen`)
+	/// Proof: `Signal::RatingSignalList` (`max_values`: None, `max_size`: Some(1355), added: 3830, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Locks` (r:1 w:1)
 	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Freezes` (r:1 w:0)
 	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `Signal::RatingAggregates` (r:1 w:1)
+	/// Proof: `Signal::RatingAggregates` (`max_values`: None, `max_size`: Some(1070), added: 3545, mode: `MaxEncodedLen`)
+	/// Storage: `Signal::RatingWeights` (r:0 w:1)
+	/// Proof: `Signal::RatingWeights` (`max_values`: None, `max_size`: Some(1094), added: 3569, mode: `MaxEncodedLen`)
+	/// Storage: `Signal::RatingCount` (r:1 w:1)
+	/// Proof: `Signal::RatingCount` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
 	fn send_rating_signal_large_input() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `6`
-		//  Estimated: `4764`
+		//  Estimated: `4820`
 		// Minimum execution time: 54_131_000 picoseconds.
-		Weight::from_parts(56_830_000, 4764)
-			.saturating_add(T::DbWeight::get().reads(3_u64))
-			.saturating_add(T::DbWeight::get().writes(2_u64))
+		Weight::from_parts(56_830_000, 4820)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
 	}
 	/// Storage: `Signal::RatingSignalList` (r:1 w:1)
-	/// Proof: `Signal::RatingSignalList` (`max_values`: None, `max_size`: Some(1091), added: 3566, mode: `MaxEncodedLen`)
-	/// Storage: `Balances::Locks` (r:1 w:1)
-	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
-	/// Storage: `Balances::Freezes` (r:1 w:0)
-	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Proof: `Signal::RatingSignalList` (`max_values`: None, `max_size`: Some(1355), added: 3830, mode: `MaxEncodedLen`)
+	/// Storage: `Signal::RatingAggregates` (r:1 w:1)
+	/// Proof: `Signal::RatingAggregates` (`max_values`: None, `max_size`: Some(1070), added: 3545, mode: `MaxEncodedLen`)
+	/// Storage: `Signal::RatingWeights` (r:1 w:1)
+	/// Proof: `Signal::RatingWeights` (`max_values`: None, `max_size`: Some(1094), added: 3569, mode: `MaxEncodedLen`)
 	fn update_rating_signal() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `2053`
-		//  Estimated: `4764`
+		//  Estimated: `4820`
 		// Minimum execution time: 99_367_000 picoseconds.
-		Weight::from_parts(102_081_000, 4764)
+		Weight::from_parts(102_081_000, 4820)
 			.saturating_add(T::DbWeight::get().reads(3_u64))
-			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
 	/// Storage: `Signal::RatingSignalList` (r:1 w:1)
-	/// Proof: `Signal::RatingSignalList` (`max_values`: None, `max_size`: Some(1091), added: 3566, mode: `MaxEncodedLen`)
+	/// Proof: `Signal::RatingSignalList` (`max_values`: None, `max_size`: Some(1355), added: 3830, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Locks` (r:1 w:1)
 	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Freezes` (r:1 w:0)
 	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `Signal::RatingAggregates` (r:1 w:1)
+	/// Proof: `Signal::RatingAggregates` (`max_values`: None, `max_size`: Some(1070), added: 3545, mode: `MaxEncodedLen`)
+	/// Storage: `Signal::RatingWeights` (r:1 w:1)
+	/// Proof: `Signal::RatingWeights` (`max_values`: None, `max_size`: Some(1094), added: 3569, mode: `MaxEncodedLen`)
+	/// Storage: `Signal::RatingCount` (r:1 w:1)
+	/// Proof: `Signal::RatingCount` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
 	fn revoke_rating_signal() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `2053`
-		//  Estimated: `4764`
+		//  Estimated: `4820`
 		// Minimum execution time: 101_351_000 picoseconds.
-		Weight::from_parts(112_948_000, 4764)
-			.saturating_add(T::DbWeight::get().reads(3_u64))
-			.saturating_add(T::DbWeight::get().writes(2_u64))
+		Weight::from_parts(112_948_000, 4820)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
 	}
 	fn send_signal() -> Weight {
 		// Proof Size summary in bytes:
@@ -160,89 +173,274 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		// Minimum execution time: 9_102_000 picoseconds.
 		Weight::from_parts(9_367_000, 0)
 	}
+	/// Storage: `Signal::SignalParameterSchemas` (r:0 w:1)
+	/// Proof: `Signal::SignalParameterSchemas` (`max_values`: None, `max_size`: Some(1059), added: 3534, mode: `MaxEncodedLen`)
+	fn set_parameter_schema() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 10_873_000 picoseconds.
+		Weight::from_parts(11_402_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Signal::SignalParameterSchemas` (r:1 w:1)
+	/// Proof: `Signal::SignalParameterSchemas` (`max_values`: None, `max_size`: Some(1059), added: 3534, mode: `MaxEncodedLen`)
+	fn remove_parameter_schema() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `47`
+		//  Estimated: `4524`
+		// Minimum execution time: 15_219_000 picoseconds.
+		Weight::from_parts(16_008_000, 4524)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Signal::PersistedSignals` (r:1 w:1)
+	/// Proof: `Signal::PersistedSignals` (`max_values`: None, `max_size`: Some(2137), added: 4612, mode: `MaxEncodedLen`)
+	/// Storage: `Signal::SignalExpiries` (r:2 w:2)
+	/// Proof: `Signal::SignalExpiries` (`max_values`: None, `max_size`: Some(67725), added: 70200, mode: `MaxEncodedLen`)
+	/// Storage: `Signal::PendingSignalExpiries` (r:1 w:1)
+	/// Proof: `Signal::PendingSignalExpiries` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Signal::SignalExpiryCursor` (r:1 w:1)
+	/// Proof: `Signal::SignalExpiryCursor` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn persist_signal() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `6`
+		//  Estimated: `141390`
+		// Minimum execution time: 61_204_000 picoseconds.
+		Weight::from_parts(63_877_000, 141390)
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
+	}
+	/// Storage: `Signal::PersistedSignals` (r:1 w:1)
+	/// Proof: `Signal::PersistedSignals` (`max_values`: None, `max_size`: Some(2137), added: 4612, mode: `MaxEncodedLen`)
+	/// Storage: `Signal::SignalExpiries` (r:2 w:2)
+	/// Proof: `Signal::SignalExpiries` (`max_values`: None, `max_size`: Some(67725), added: 70200, mode: `MaxEncodedLen`)
+	/// Storage: `Signal::PendingSignalExpiries` (r:1 w:1)
+	/// Proof: `Signal::PendingSignalExpiries` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Signal::SignalExpiryCursor` (r:1 w:1)
+	/// Proof: `Signal::SignalExpiryCursor` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Signal::ServiceSignals` (r:0 w:1)
+	/// Proof: `Signal::ServiceSignals` (`max_values`: None, `max_size`: Some(1090), added: 3565, mode: `MaxEncodedLen`)
+	fn persist_service_signal() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `6`
+		//  Estimated: `141390`
+		// Minimum execution time: 63_592_000 picoseconds.
+		Weight::from_parts(66_418_000, 141390)
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(8_u64))
+	}
+	/// Storage: `Signal::PersistedSignals` (r:1 w:1)
+	/// Proof: `Signal::PersistedSignals` (`max_values`: None, `max_size`: Some(2137), added: 4612, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Signal::ServiceSignals` (r:0 w:1)
+	/// Proof: `Signal::ServiceSignals` (`max_values`: None, `max_size`: Some(1090), added: 3565, mode: `MaxEncodedLen`)
+	/// Storage: `Signal::SignalExpiries` (r:1 w:1)
+	/// Proof: `Signal::SignalExpiries` (`max_values`: None, `max_size`: Some(67725), added: 70200, mode: `MaxEncodedLen`)
+	/// Storage: `Signal::PendingSignalExpiries` (r:1 w:1)
+	/// Proof: `Signal::PendingSignalExpiries` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Signal::SignalExpiryCursor` (r:0 w:1)
+	/// Proof: `Signal::SignalExpiryCursor` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	fn remove_persisted_signal() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `295`
+		//  Estimated: `71190`
+		// Minimum execution time: 44_731_000 picoseconds.
+		Weight::from_parts(46_925_000, 71190)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
+	}
+	/// Storage: `Signal::PersistedSignals` (r:1 w:1)
+	/// Proof: `Signal::PersistedSignals` (`max_values`: None, `max_size`: Some(2137), added: 4612, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Signal::ServiceSignals` (r:0 w:1)
+	/// Proof: `Signal::ServiceSignals` (`max_values`: None, `max_size`: Some(1090), added: 3565, mode: `MaxEncodedLen`)
+	fn expire_signal() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `295`
+		//  Estimated: `5602`
+		// Minimum execution time: 42_108_000 picoseconds.
+		Weight::from_parts(44_060_000, 5602)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: `Signal::ServiceRegistry` (r:1 w:1)
+	/// Proof: `Signal::ServiceRegistry` (`max_values`: None, `max_size`: Some(9319), added: 11794, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn register_service() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `6`
+		//  Estimated: `12784`
+		// Minimum execution time: 52_316_000 picoseconds.
+		Weight::from_parts(54_790_000, 12784)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `Signal::ServiceRegistry` (r:1 w:1)
+	/// Proof: `Signal::ServiceRegistry` (`max_values`: None, `max_size`: Some(9319), added: 11794, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn update_service() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `8379`
+		//  Estimated: `12784`
+		// Minimum execution time: 58_942_000 picoseconds.
+		Weight::from_parts(61_505_000, 12784)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `Signal::ServiceRegistry` (r:1 w:1)
+	/// Proof: `Signal::ServiceRegistry` (`max_values`: None, `max_size`: Some(9319), added: 11794, mode: `MaxEncodedLen`)
+	fn service_heartbeat() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `8183`
+		//  Estimated: `12784`
+		// Minimum execution time: 21_407_000 picoseconds.
+		Weight::from_parts(22_613_000, 12784)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Signal::ServiceRegistry` (r:1 w:1)
+	/// Proof: `Signal::ServiceRegistry` (`max_values`: None, `max_size`: Some(9319), added: 11794, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn deregister_service() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `8379`
+		//  Estimated: `12784`
+		// Minimum execution time: 47_885_000 picoseconds.
+		Weight::from_parts(49_972_000, 12784)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
 }
 
 // For backwards compatibility and tests.
 impl WeightInfo for () {
+	/// Storage: `Signal::SignalParameterSchemas` (r:1 w:0)
+	/// Proof: `Signal::SignalParameterSchemas` (`max_values`: None, `max_size`: Some(1059), added: 3534, mode: `MaxEncodedLen`)
 	/// Storage: `Signal::SignalParameterList` (r:0 w:1)
-	/// Proof: `Signal::SignalParameterList` (`max_values`: None, `max_size`: Some(1091), added: 3566, mode: `MaxEncodedLen`)
+	/// Proof: `Signal::SignalParameterList` (`max_values`: None, `max_size`: Some(2117), added: 4592, mode: `MaxEncodedLen`)
 	fn set_signal_parameter() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `0`
-		//  Estimated: `0`
+		//  Measured:  `47`
+		//  Estimated: `4524`
 		// Minimum execution time: 11_545_000 picoseconds.
-		Weight::from_parts(12_131_000, 0)
+		Weight::from_parts(12_131_000, 4524)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
+	/// Storage: `Signal::SignalParameterSchemas` (r:1 w:0)
+	/// Proof: `Signal::SignalParameterSchemas` (`max_values`: None, `max_size`: Some(1059), added: 3534, mode: `MaxEncodedLen`)
 	/// Storage: `Signal::SignalParameterList` (r:0 w:1)
-	/// Proof: `Signal::SignalParameterList` (`max_values`: None, `max_size`: Some(1091), added: 3566, mode: `MaxEncodedLen`)
+	/// Proof: `Signal::SignalParameterList` (`max_values`: None, `max_size`: Some(2117), added: 4592, mode: `MaxEncodedLen`)
 	fn set_signal_parameter_large_input() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `0`
-		//  Estimated: `0`
+		//  Measured:  `47`
+		//  Estimated: `4524`
 		// Minimum execution time: 14_144_000 picoseconds.
-		Weight::from_parts(14_516_000, 0)
+		Weight::from_parts(14_516_000, 4524)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
 	/// Storage: `Signal::RatingSignalList` (r:1 w:1)
-	/// Proof: `Signal::RatingSignalList` (`max_values`: None, `max_size`: Some(1091), added: 3566, mode: `MaxEncodedLen`)
+	/// Proof: `Signal::RatingSignalList` (`max_values`: None, `max_size`: Some(1355), added: 3830, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Locks` (r:1 w:1)
 	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Freezes` (r:1 w:0)
 	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `Signal::RatingAggregates` (r:1 w:1)
+	/// Proof: `Signal::RatingAggregates` (`max_values`: None, `max_size`: Some(1070), added: 3545, mode: `MaxEncodedLen`)
+	/// Storage: `Signal::RatingWeights` (r:0 w:1)
+	/// Proof: `Signal::RatingWeights` (`max_values`: None, `max_size`: Some(1094), added: 3569, mode: `MaxEncodedLen`)
+	/// Storage: `Signal::RatingCount` (r:1 w:1)
+	/// Proof: `Signal::RatingCount` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
 	fn send_rating_signal() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `2029`
-		//  Estimated: `4764`
+		//  Estimated: `4820`
 		// Minimum execution time: 101_423_000 picoseconds.
-		Weight::from_parts(117_504_000, 4764)
-			.saturating_add(RocksDbWeight::get().reads(3_u64))
-			.saturating_add(RocksDbWeight::get().writes(2_u64))
+		Weight::from_parts(117_504_000, 4820)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
 	}
 	/// Storage: `Signal::RatingSignalList` (r:1 w:1)
-	/// Proof: `Signal::RatingSignalList` (`max_values`: None, `max_size`: Some(1091), added: 3566, mode: `MaxEncodedLen`)
+	/// Proof: `Signal::RatingSignalList` (`max_values`: None, `max_size`: Some(1355), added: 3830, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Locks` (r:1 w:1)
 	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Freezes` (r:1 w:0)
 	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `Signal::RatingAggregates` (r:1 w:1)
+	/// Proof: `Signal::RatingAggregates` (`max_values`: None, `max_size`: Some(1070), added: 3545, mode: `MaxEncodedLen`)
+	/// Storage: `Signal::RatingWeights` (r:0 w:1)
+	/// Proof: `Signal::RatingWeights` (`max_values`: None, `max_size`: Some(1094), added: 3569, mode: `MaxEncodedLen`)
+	/// Storage: `Signal::RatingCount` (r:1 w:1)
+	/// Proof: `Signal::RatingCount` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
 	fn send_rating_signal_large_input() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `6`
-		//  Estimated: `4764`
+		//  Estimated: `4820`
 		// Minimum execution time: 54_131_000 picoseconds.
-		Weight::from_parts(56_830_000, 4764)
-			.saturating_add(RocksDbWeight::get().reads(3_u64))
-			.saturating_add(RocksDbWeight::get().writes(2_u64))
+		Weight::from_parts(56_830_000, 4820)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
 	}
 	/// Storage: `Signal::RatingSignalList` (r:1 w:1)
-	/// Proof: `Signal::RatingSignalList` (`max_values`: None, `max_size`: Some(1091), added: 3566, mode: `MaxEncodedLen`)
-	/// Storage: `Balances::Locks` (r:1 w:1)
-	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
-	/// Storage: `Balances::Freezes` (r:1 w:0)
-	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Proof: `Signal::RatingSignalList` (`max_values`: None, `max_size`: Some(1355), added: 3830, mode: `MaxEncodedLen`)
+	/// Storage: `Signal::RatingAggregates` (r:1 w:1)
+	/// Proof: `Signal::RatingAggregates` (`max_values`: None, `max_size`: Some(1070), added: 3545, mode: `MaxEncodedLen`)
+	/// Storage: `Signal::RatingWeights` (r:1 w:1)
+	/// Proof: `Signal::RatingWeights` (`max_values`: None, `max_size`: Some(1094), added: 3569, mode: `MaxEncodedLen`)
 	fn update_rating_signal() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `2053`
-		//  Estimated: `4764`
+		//  Estimated: `4820`
 		// Minimum execution time: 99_367_000 picoseconds.
-		Weight::from_parts(102_081_000, 4764)
+		Weight::from_parts(102_081_000, 4820)
 			.saturating_add(RocksDbWeight::get().reads(3_u64))
-			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
 	}
 	/// Storage: `Signal::RatingSignalList` (r:1 w:1)
-	/// Proof: `Signal::RatingSignalList` (`max_values`: None, `max_size`: Some(1091), added: 3566, mode: `MaxEncodedLen`)
+	/// Proof: `Signal::RatingSignalList` (`max_values`: None, `max_size`: Some(1355), added: 3830, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Locks` (r:1 w:1)
 	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Freezes` (r:1 w:0)
 	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `Signal::RatingAggregates` (r:1 w:1)
+	/// Proof: `Signal::RatingAggregates` (`max_values`: None, `max_size`: Some(1070), added: 3545, mode: `MaxEncodedLen`)
+	/// Storage: `Signal::RatingWeights` (r:1 w:1)
+	/// Proof: `Signal::RatingWeights` (`max_values`: None, `max_size`: Some(1094), added: 3569, mode: `MaxEncodedLen`)
+	/// Storage: `Signal::RatingCount` (r:1 w:1)
+	/// Proof: `Signal::RatingCount` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
 	fn revoke_rating_signal() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `2053`
-		//  Estimated: `4764`
+		//  Estimated: `4820`
 		// Minimum execution time: 101_351_000 picoseconds.
-		Weight::from_parts(112_948_000, 4764)
-			.saturating_add(RocksDbWeight::get().reads(3_u64))
-			.saturating_add(RocksDbWeight::get().writes(2_u64))
+		Weight::from_parts(112_948_000, 4820)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
 	}
 	fn send_signal() -> Weight {
 		// Proof Size summary in bytes:
@@ -272,4 +470,165 @@ impl WeightInfo for () {
 		// Minimum execution time: 9_102_000 picoseconds.
 		Weight::from_parts(9_367_000, 0)
 	}
+	/// Storage: `Signal::SignalParameterSchemas` (r:0 w:1)
+	/// Proof: `Signal::SignalParameterSchemas` (`max_values`: None, `max_size`: Some(1059), added: 3534, mode: `MaxEncodedLen`)
+	fn set_parameter_schema() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 10_873_000 picoseconds.
+		Weight::from_parts(11_402_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Signal::SignalParameterSchemas` (r:1 w:1)
+	/// Proof: `Signal::SignalParameterSchemas` (`max_values`: None, `max_size`: Some(1059), added: 3534, mode: `MaxEncodedLen`)
+	fn remove_parameter_schema() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `47`
+		//  Estimated: `4524`
+		// Minimum execution time: 15_219_000 picoseconds.
+		Weight::from_parts(16_008_000, 4524)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Signal::PersistedSignals` (r:1 w:1)
+	/// Proof: `Signal::PersistedSignals` (`max_values`: None, `max_size`: Some(2137), added: 4612, mode: `MaxEncodedLen`)
+	/// Storage: `Signal::SignalExpiries` (r:2 w:2)
+	/// Proof: `Signal::SignalExpiries` (`max_values`: None, `max_size`: Some(67725), added: 70200, mode: `MaxEncodedLen`)
+	/// Storage: `Signal::PendingSignalExpiries` (r:1 w:1)
+	/// Proof: `Signal::PendingSignalExpiries` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Signal::SignalExpiryCursor` (r:1 w:1)
+	/// Proof: `Signal::SignalExpiryCursor` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn persist_signal() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `6`
+		//  Estimated: `141390`
+		// Minimum execution time: 61_204_000 picoseconds.
+		Weight::from_parts(63_877_000, 141390)
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
+	}
+	/// Storage: `Signal::PersistedSignals` (r:1 w:1)
+	/// Proof: `Signal::PersistedSignals` (`max_values`: None, `max_size`: Some(2137), added: 4612, mode: `MaxEncodedLen`)
+	/// Storage: `Signal::SignalExpiries` (r:2 w:2)
+	/// Proof: `Signal::SignalExpiries` (`max_values`: None, `max_size`: Some(67725), added: 70200, mode: `MaxEncodedLen`)
+	/// Storage: `Signal::PendingSignalExpiries` (r:1 w:1)
+	/// Proof: `Signal::PendingSignalExpiries` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Signal::SignalExpiryCursor` (r:1 w:1)
+	/// Proof: `Signal::SignalExpiryCursor` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Signal::ServiceSignals` (r:0 w:1)
+	/// Proof: `Signal::ServiceSignals` (`max_values`: None, `max_size`: Some(1090), added: 3565, mode: `MaxEncodedLen`)
+	fn persist_service_signal() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `6`
+		//  Estimated: `141390`
+		// Minimum execution time: 63_592_000 picoseconds.
+		Weight::from_parts(66_418_000, 141390)
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(8_u64))
+	}
+	/// Storage: `Signal::PersistedSignals` (r:1 w:1)
+	/// Proof: `Signal::PersistedSignals` (`max_values`: None, `max_size`: Some(2137), added: 4612, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Signal::ServiceSignals` (r:0 w:1)
+	/// Proof: `Signal::ServiceSignals` (`max_values`: None, `max_size`: Some(1090), added: 3565, mode: `MaxEncodedLen`)
+	/// Storage: `Signal::SignalExpiries` (r:1 w:1)
+	/// Proof: `Signal::SignalExpiries` (`max_values`: None, `max_size`: Some(67725), added: 70200, mode: `MaxEncodedLen`)
+	/// Storage: `Signal::PendingSignalExpiries` (r:1 w:1)
+	/// Proof: `Signal::PendingSignalExpiries` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Signal::SignalExpiryCursor` (r:0 w:1)
+	/// Proof: `Signal::SignalExpiryCursor` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	fn remove_persisted_signal() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `295`
+		//  Estimated: `71190`
+		// Minimum execution time: 44_731_000 picoseconds.
+		Weight::from_parts(46_925_000, 71190)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
+	}
+	/// Storage: `Signal::PersistedSignals` (r:1 w:1)
+	/// Proof: `Signal::PersistedSignals` (`max_values`: None, `max_size`: Some(2137), added: 4612, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Signal::ServiceSignals` (r:0 w:1)
+	/// Proof: `Signal::ServiceSignals` (`max_values`: None, `max_size`: Some(1090), added: 3565, mode: `MaxEncodedLen`)
+	fn expire_signal() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `295`
+		//  Estimated: `5602`
+		// Minimum execution time: 42_108_000 picoseconds.
+		Weight::from_parts(44_060_000, 5602)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: `Signal::ServiceRegistry` (r:1 w:1)
+	/// Proof: `Signal::ServiceRegistry` (`max_values`: None, `max_size`: Some(9319), added: 11794, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn register_service() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `6`
+		//  Estimated: `12784`
+		// Minimum execution time: 52_316_000 picoseconds.
+		Weight::from_parts(54_790_000, 12784)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `Signal::ServiceRegistry` (r:1 w:1)
+	/// Proof: `Signal::ServiceRegistry` (`max_values`: None, `max_size`: Some(9319), added: 11794, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn update_service() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `8379`
+		//  Estimated: `12784`
+		// Minimum execution time: 58_942_000 picoseconds.
+		Weight::from_parts(61_505_000, 12784)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `Signal::ServiceRegistry` (r:1 w:1)
+	/// Proof: `Signal::ServiceRegistry` (`max_values`: None, `max_size`: Some(9319), added: 11794, mode: `MaxEncodedLen`)
+	fn service_heartbeat() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `8183`
+		//  Estimated: `12784`
+		// Minimum execution time: 21_407_000 picoseconds.
+		Weight::from_parts(22_613_000, 12784)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Signal::ServiceRegistry` (r:1 w:1)
+	/// Proof: `Signal::ServiceRegistry` (`max_values`: None, `max_size`: Some(9319), added: 11794, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn deregister_service() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `8379`
+		//  Estimated: `12784`
+		// Minimum execution time: 47_885_000 picoseconds.
+		Weight::from_parts(49_972_000, 12784)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
 }
@@ -4,10 +4,16 @@
 use super::*;
 use crate::Pallet as Trust;
 
+use alloc::vec;
 use frame_benchmarking::{account as benchmark_account, v2::*};
-use frame_support::BoundedVec;
+use frame_support::{traits::Get, BoundedVec};
 use frame_system::RawOrigin;
 
+/// A scope tag of the maximum length.
+fn max_scope<T: Config>() -> TrustScopeOf<T> {
+	BoundedVec::try_from(vec![0u8; T::MaxTrustScopeSize::get() as usize]).unwrap()
+}
+
 #[benchmarks]
 mod benchmarks {
 	use super::*;
@@ -29,12 +35,15 @@ mod benchmarks {
 	fn issue_trust() -> Result<(), BenchmarkError> {
 		let caller: T::AccountId = whitelisted_caller();
         let target: T::AccountId = benchmark_account("target", 0, 0);
+		let level = T::MaxTrustLevel::get();
+		// Unscoped trust also adds to the target's `TrustReceived`.
 		#[extrinsic_call]
-		_(RawOrigin::Signed(caller.clone()), target.clone());
+		_(RawOrigin::Signed(caller.clone()), target.clone(), level, None);
 		assert_eq!(CurrentIssued::<T>::get(), 1);
-        assert_eq!(TrustIssuance::<T>::get(caller.clone(), target.clone()), Some(0));
+        assert_eq!(TrustIssuance::<T>::get(caller.clone(), target.clone()), Some(TrustEdge { level, scope: None }));
+        assert_eq!(TrustReceived::<T>::get(&target), u32::from(level));
         frame_system::Pallet::<T>::assert_last_event(
-            <T as pallet::Config>::RuntimeEvent::from(Event::<T>::TrustIssued { issuer: caller, target }).into()
+            <T as pallet::Config>::RuntimeEvent::from(Event::<T>::TrustIssued { issuer: caller, target, level }).into()
         );
 		Ok(())
 	}
@@ -44,14 +53,14 @@ mod benchmarks {
 		for i in 0..m {
 			let target: T::AccountId = benchmark_account("target", i, 0);
 			let caller: T::AccountId = benchmark_account("caller", i, 0);
-			Trust::<T>::issue_trust(RawOrigin::Signed(caller.clone()).into(), target.clone())?;
+			Trust::<T>::issue_trust(RawOrigin::Signed(caller.clone()).into(), target.clone(), T::MaxTrustLevel::get(), None)?;
 		}
 
 		let target: T::AccountId = whitelisted_caller();
 		let caller: T::AccountId = whitelisted_caller();
 
 		#[extrinsic_call]
-		issue_trust(RawOrigin::Signed(caller.clone()), target.clone());
+		issue_trust(RawOrigin::Signed(caller.clone()), target.clone(), T::MaxTrustLevel::get(), Some(max_scope::<T>()));
 
 		assert_eq!(CurrentIssued::<T>::get(), m + 1);
 		assert!(TrustIssuance::<T>::contains_key(caller.clone(), target.clone()));
@@ -78,7 +87,7 @@ mod benchmarks {
 		for i in 0..m {
 			let target: T::AccountId = benchmark_account("target", i, 0);
 			let caller: T::AccountId = benchmark_account("caller", i, 0);
-			Trust::<T>::issue_trust(RawOrigin::Signed(caller.clone()).into(), target.clone())?;
+			Trust::<T>::issue_trust(RawOrigin::Signed(caller.clone()).into(), target.clone(), T::MaxTrustLevel::get(), None)?;
 		}
 
 		for i in 0..m {
@@ -103,13 +112,14 @@ mod benchmarks {
 	fn remove_trust() -> Result<(), BenchmarkError> {
 		let caller: T::AccountId = whitelisted_caller();
         let target: T::AccountId = benchmark_account("target", 0, 0);
-		Trust::<T>::issue_trust(RawOrigin::Signed(caller.clone()).into(), target.clone())?;
+		Trust::<T>::issue_trust(RawOrigin::Signed(caller.clone()).into(), target.clone(), T::MaxTrustLevel::get(), None)?;
 
 		#[extrinsic_call]
 		_(RawOrigin::Signed(caller.clone()), target.clone());
 
 		assert_eq!(CurrentIssued::<T>::get(), 0);
         assert_eq!(TrustIssuance::<T>::get(caller.clone(), target.clone()), None);
+        assert_eq!(TrustReceived::<T>::get(&target), 0);
         frame_system::Pallet::<T>::assert_last_event(
             <T as pallet::Config>::RuntimeEvent::from(Event::<T>::TrustIssuanceRemoved { issuer: caller, target }).into()
         );
@@ -121,12 +131,12 @@ mod benchmarks {
 		for i in 0..m {
 			let target: T::AccountId = benchmark_account("target", i, 0);
 			let caller: T::AccountId = benchmark_account("caller", i, 0);
-			Trust::<T>::issue_trust(RawOrigin::Signed(caller.clone()).into(), target.clone())?;
+			Trust::<T>::issue_trust(RawOrigin::Signed(caller.clone()).into(), target.clone(), T::MaxTrustLevel::get(), None)?;
 		}
 
 		let target: T::AccountId = whitelisted_caller();
 		let caller: T::AccountId = whitelisted_caller();
-		Trust::<T>::issue_trust(RawOrigin::Signed(caller.clone()).into(), target.clone())?;
+		Trust::<T>::issue_trust(RawOrigin::Signed(caller.clone()).into(), target.clone(), T::MaxTrustLevel::get(), None)?;
 
 		#[extrinsic_call]
 		remove_trust(RawOrigin::Signed(caller.clone()), target.clone());
@@ -248,5 +258,28 @@ mod benchmarks {
 		Ok(())
 	}
 
+	#[benchmark]
+	fn update_trust() -> Result<(), BenchmarkError> {
+		let caller: T::AccountId = whitelisted_caller();
+		let target: T::AccountId = benchmark_account("target", 0, 0);
+		// Scoping unscoped trust takes it out of the target's `TrustReceived`.
+		Trust::<T>::issue_trust(RawOrigin::Signed(caller.clone()).into(), target.clone(), T::MaxTrustLevel::get(), None)?;
+		let level = T::MaxTrustLevel::get();
+		let scope = max_scope::<T>();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), target.clone(), level, Some(scope.clone()));
+
+		assert_eq!(
+			TrustIssuance::<T>::get(caller.clone(), target.clone()),
+			Some(TrustEdge { level, scope: Some(scope) })
+		);
+		assert_eq!(TrustReceived::<T>::get(&target), 0);
+		frame_system::Pallet::<T>::assert_last_event(
+			<T as pallet::Config>::RuntimeEvent::from(Event::<T>::TrustUpdated { issuer: caller, target, level }).into()
+		);
+		Ok(())
+	}
+
 	impl_benchmark_test_suite!(Trust, crate::mock::new_test_ext(), crate::mock::Test);
 }
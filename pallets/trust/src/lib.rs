@@ -11,7 +11,10 @@ mod tests;
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
 
+pub mod migrations;
 pub mod weights;
+
+extern crate alloc;
 pub use weights::*;
 
 #[frame_support::pallet]
@@ -30,9 +33,34 @@ pub mod pallet {
 		type WeightInfo: WeightInfo;
 		/// The maximum size of a trust parameter string
 		type MaxTrustParameterSize: Get<u32>;
+		/// The highest level an account can place in another account's trust.
+		#[pallet::constant]
+		type MaxTrustLevel: Get<u8>;
+		/// The maximum size of the scope tag attached to a trust edge.
+		#[pallet::constant]
+		type MaxTrustScopeSize: Get<u32>;
+	}
+
+	/// The trust an issuer has placed in a target.
+	#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub struct TrustEdge<Scope> {
+		/// How much the issuer trusts the target, between zero and `MaxTrustLevel`.
+		pub level: u8,
+		/// What the trust applies to, such as a topic or an application, if it is limited to one.
+		pub scope: Option<Scope>,
 	}
 
+	/// The trust scope tag of runtime `T`.
+	pub type TrustScopeOf<T> = BoundedVec<u8, <T as Config>::MaxTrustScopeSize>;
+
+	/// The trust edge of runtime `T`.
+	pub type TrustEdgeOf<T> = TrustEdge<TrustScopeOf<T>>;
+
+	/// The in-code storage version.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
 	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
 	#[pallet::type_value]
@@ -47,9 +75,22 @@ pub mod pallet {
 
 	#[pallet::storage]
 	#[pallet::getter(fn get_trust_issuance)]
-	/// A Map of lists of all addresses that each address has issued trust for
-	pub type TrustIssuance<T: Config> =
-		StorageDoubleMap<_, Blake2_128Concat, T::AccountId, Blake2_128Concat, T::AccountId, u32>;
+	/// A Map of lists of all addresses that each address has issued trust for, with the level and
+	/// scope of that trust
+	pub type TrustIssuance<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		Blake2_128Concat,
+		T::AccountId,
+		TrustEdgeOf<T>,
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn trust_received)]
+	/// The sum of the levels of the unscoped trust each account has been issued by other
+	/// accounts, a general measure of how far the network trusts it
+	pub type TrustReceived<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, u32, ValueQuery>;
 
 	#[pallet::storage]
 	#[pallet::getter(fn get_current_non_trust_count)]
@@ -94,7 +135,9 @@ pub mod pallet {
 		/// Announce that a trust parameter has been set
 		TrustParameterSet { who: T::AccountId },
 		/// Announce that an account has issued trust to another account
-		TrustIssued { issuer: T::AccountId, target: T::AccountId },
+		TrustIssued { issuer: T::AccountId, target: T::AccountId, level: u8 },
+		/// Announce that an account has changed the level or scope of the trust it issued to another account
+		TrustUpdated { issuer: T::AccountId, target: T::AccountId, level: u8 },
 		/// Announce that an account has revoked trust from another account
 		TrustRevoked { issuer: T::AccountId, target: T::AccountId },
 		/// Announce that an account has requested trust from another account
@@ -123,24 +166,58 @@ pub mod pallet {
 		TrustRevocationExists,
 		/// The requested trust revocation does not exist
 		TrustRevocationNotFound,
+		/// The trust level is above `MaxTrustLevel`
+		InvalidTrustLevel,
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// What the trust `issuer` placed in `target` adds to the target's `TrustReceived`: its
+		/// level, if it is unscoped and not placed by the target in itself.
+		pub(crate) fn general_trust(issuer: &T::AccountId, target: &T::AccountId, edge: &TrustEdgeOf<T>) -> u32 {
+			if edge.scope.is_none() && issuer != target {
+				edge.level.into()
+			} else {
+				0
+			}
+		}
+
+		/// Replaces `removed` with `added` in the `TrustReceived` total of `target`.
+		pub(crate) fn adjust_trust_received(target: &T::AccountId, removed: u32, added: u32) {
+			if removed == added {
+				return;
+			}
+			<TrustReceived<T>>::mutate_exists(target, |total| {
+				let new_total = total.unwrap_or_default().saturating_sub(removed).saturating_add(added);
+				*total = Some(new_total).filter(|total| *total > 0);
+			});
+		}
 	}
 
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
-		/// Fully give `origin`'s trust to account `address`
+		/// Give `origin`'s trust to account `address` at `level`, between zero and
+		/// `MaxTrustLevel`, optionally limited to `scope`.
 		#[pallet::weight(T::WeightInfo::issue_trust())]
 		#[pallet::call_index(0)]
-		pub fn issue_trust(origin: OriginFor<T>, address: T::AccountId) -> DispatchResultWithPostInfo {
+		pub fn issue_trust(
+			origin: OriginFor<T>,
+			address: T::AccountId,
+			level: u8,
+			scope: Option<TrustScopeOf<T>>,
+		) -> DispatchResultWithPostInfo {
 			let who = ensure_signed(origin)?;
 
+			ensure!(level <= T::MaxTrustLevel::get(), Error::<T>::InvalidTrustLevel);
 			ensure!(!<TrustIssuance<T>>::contains_key(&who, &address), Error::<T>::TrustExists);
 
 			let total: u32 = <CurrentIssued<T>>::get();
 			let new_total: u32 =
 				total.checked_add(One::one()).ok_or(Error::<T>::StorageOverflow)?;
-			<TrustIssuance<T>>::insert(&who, &address, total);
+			let edge = TrustEdge { level, scope };
+			Self::adjust_trust_received(&address, 0, Self::general_trust(&who, &address, &edge));
+			<TrustIssuance<T>>::insert(&who, &address, edge);
 			<CurrentIssued<T>>::put(new_total);
-			Self::deposit_event(Event::TrustIssued { issuer: who.clone(), target: address.clone() });
+			Self::deposit_event(Event::TrustIssued { issuer: who.clone(), target: address.clone(), level });
 
 			Ok(().into())
 		}
@@ -151,10 +228,11 @@ pub mod pallet {
 		pub fn remove_trust(origin: OriginFor<T>, address: T::AccountId) -> DispatchResultWithPostInfo {
 			let who = ensure_signed(origin)?;
 
-			ensure!(<TrustIssuance<T>>::contains_key(&who, &address), Error::<T>::TrustNotFound);
+			let edge = <TrustIssuance<T>>::get(&who, &address).ok_or(Error::<T>::TrustNotFound)?;
 
 			let key = <CurrentIssued<T>>::get();
 			let new_key: u32 = key.checked_sub(One::one()).ok_or(Error::<T>::StorageOverflow)?;
+			Self::adjust_trust_received(&address, Self::general_trust(&who, &address, &edge), 0);
 			<TrustIssuance<T>>::remove(&who, &address);
 			<CurrentIssued<T>>::put(new_key);
 			Self::deposit_event(Event::TrustIssuanceRemoved { issuer: who.clone(), target: address.clone() });
@@ -261,5 +339,30 @@ pub mod pallet {
 
 			Ok(().into())
 		}
+
+		/// Change the level and scope of the trust `origin` has already issued to `address`.
+		#[pallet::weight(T::WeightInfo::update_trust())]
+		#[pallet::call_index(7)]
+		pub fn update_trust(
+			origin: OriginFor<T>,
+			address: T::AccountId,
+			level: u8,
+			scope: Option<TrustScopeOf<T>>,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+
+			ensure!(level <= T::MaxTrustLevel::get(), Error::<T>::InvalidTrustLevel);
+			let (previous, updated) = <TrustIssuance<T>>::try_mutate(&who, &address, |edge| {
+				let edge = edge.as_mut().ok_or(Error::<T>::TrustNotFound)?;
+				let previous = Self::general_trust(&who, &address, edge);
+				edge.level = level;
+				edge.scope = scope;
+				Ok::<_, Error<T>>((previous, Self::general_trust(&who, &address, edge)))
+			})?;
+			Self::adjust_trust_received(&address, previous, updated);
+			Self::deposit_event(Event::TrustUpdated { issuer: who, target: address, level });
+
+			Ok(().into())
+		}
 	}
 }
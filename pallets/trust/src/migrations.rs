@@ -0,0 +1,50 @@
+//! Storage migrations for pallet-trust.
+
+/// Migrates the pallet from storage version 0 to 1.
+///
+/// Version 1 stores each issued trust as a `TrustEdge` with a level and an optional scope,
+/// instead of the value of the issuance counter at the time. Trust issued before the upgrade was
+/// full trust, so existing edges get `MaxTrustLevel` and no scope, and each target's
+/// `TrustReceived` total is filled in from them.
+pub mod v1 {
+    use crate::{Config, Pallet, TrustEdge, TrustIssuance, TrustReceived};
+    use core::marker::PhantomData;
+    use frame_support::{
+        migrations::VersionedMigration,
+        traits::{Get, UncheckedOnRuntimeUpgrade},
+        weights::Weight,
+    };
+
+    /// Converts `TrustIssuance` values to `TrustEdge`s and totals them in `TrustReceived`. Use [`MigrateV0ToV1`] instead, which
+    /// only runs this once and bumps the storage version.
+    pub struct InnerMigrateV0ToV1<T>(PhantomData<T>);
+
+    impl<T: Config> UncheckedOnRuntimeUpgrade for InnerMigrateV0ToV1<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let level = T::MaxTrustLevel::get();
+            let mut translated: u64 = 0;
+            let mut counted: u64 = 0;
+            TrustIssuance::<T>::translate::<u32, _>(|issuer, target, _| {
+                translated = translated.saturating_add(1);
+                let edge = TrustEdge { level, scope: None };
+                let received = Pallet::<T>::general_trust(&issuer, &target, &edge);
+                if received > 0 {
+                    counted = counted.saturating_add(1);
+                    TrustReceived::<T>::mutate(&target, |total| *total = total.saturating_add(received));
+                }
+                Some(edge)
+            });
+            let touched = translated.saturating_add(counted);
+            T::DbWeight::get().reads_writes(touched, touched)
+        }
+    }
+
+    /// Migrates pallet-trust from storage version 0 to 1.
+    pub type MigrateV0ToV1<T> = VersionedMigration<
+        0,
+        1,
+        InnerMigrateV0ToV1<T>,
+        Pallet<T>,
+        <T as frame_system::Config>::DbWeight,
+    >;
+}
@@ -1,6 +1,6 @@
 use crate as pallet_trust;
 use frame_support::derive_impl;
-use sp_core::ConstU32;
+use sp_core::{ConstU32, ConstU8};
 use sp_runtime::BuildStorage;
 
 pub type AccountId = u64;
@@ -39,6 +39,8 @@ impl pallet_trust::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type WeightInfo = ();
 	type MaxTrustParameterSize = ConstU32<1024>;
+	type MaxTrustLevel = ConstU8<100>;
+	type MaxTrustScopeSize = ConstU32<16>;
 }
 
 pub fn new_test_ext() -> sp_io::TestExternalities {
@@ -1,10 +1,20 @@
-use crate::{mock::*, Error, Event, TrustParameterList, TrustIssuance, TrustRevocation, TrustRequestList};
-use frame_support::{assert_ok, assert_noop};
+use crate::{
+    mock::*, Error, Event, TrustEdge, TrustParameterList, TrustIssuance, TrustReceived, TrustRevocation,
+    TrustRequestList,
+};
+use frame_support::{
+    assert_noop, assert_ok,
+    traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion},
+};
 use sp_core::ConstU32;
 use sp_runtime::BoundedVec;
 
 type TrustModule = crate::Pallet<Test>;
 
+fn scope(tag: &[u8]) -> BoundedVec<u8, ConstU32<16>> {
+    BoundedVec::try_from(tag.to_vec()).unwrap()
+}
+
 #[test]
 fn test_set_trust_parameter() {
 	new_test_ext().execute_with(|| {
@@ -20,10 +30,10 @@ fn test_set_trust_parameter() {
 fn test_issue_trust() {
 	new_test_ext().execute_with(|| {
 		System::set_block_number(1);
-        assert_ok!(TrustModule::issue_trust(RuntimeOrigin::signed(1), 2));
-        assert_eq!(TrustIssuance::<Test>::get(1, 2), Some(0));
+        assert_ok!(TrustModule::issue_trust(RuntimeOrigin::signed(1), 2, 60, Some(scope(b"code-review"))));
+        assert_eq!(TrustIssuance::<Test>::get(1, 2), Some(TrustEdge { level: 60, scope: Some(scope(b"code-review")) }));
 		assert_eq!(TrustModule::get_current_trust_count(), 1);
-        System::assert_last_event(Event::TrustIssued { issuer: 1, target: 2 }.into());
+        System::assert_last_event(Event::TrustIssued { issuer: 1, target: 2, level: 60 }.into());
 	});
 }
 
@@ -31,8 +41,8 @@ fn test_issue_trust() {
 fn test_issue_trust_error() {
 	new_test_ext().execute_with(|| {
 		System::set_block_number(1);
-        assert_ok!(TrustModule::issue_trust(RuntimeOrigin::signed(1), 2));
-        assert_noop!(TrustModule::issue_trust(RuntimeOrigin::signed(1), 2), Error::<Test>::TrustExists);
+        assert_ok!(TrustModule::issue_trust(RuntimeOrigin::signed(1), 2, 100, None));
+        assert_noop!(TrustModule::issue_trust(RuntimeOrigin::signed(1), 2, 100, None), Error::<Test>::TrustExists);
 	});
 }
 
@@ -40,7 +50,7 @@ fn test_issue_trust_error() {
 fn test_remove_trust() {
 	new_test_ext().execute_with(|| {
 		System::set_block_number(1);
-        assert_ok!(TrustModule::issue_trust(RuntimeOrigin::signed(1), 2));
+        assert_ok!(TrustModule::issue_trust(RuntimeOrigin::signed(1), 2, 100, None));
         assert_ok!(TrustModule::remove_trust(RuntimeOrigin::signed(1), 2));
         assert_eq!(TrustIssuance::<Test>::get(1, 2), None); // Should be removed
         assert_eq!(TrustModule::get_current_trust_count(), 0);
@@ -97,3 +107,77 @@ fn test_remove_revoked_trust_error() {
         assert_noop!(TrustModule::remove_revoked_trust(RuntimeOrigin::signed(1), 2), Error::<Test>::TrustRevocationNotFound);
 	});
 }
+
+#[test]
+fn trust_levels_above_max_trust_level_are_rejected() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+        assert_noop!(TrustModule::issue_trust(RuntimeOrigin::signed(1), 2, 101, None), Error::<Test>::InvalidTrustLevel);
+        assert_ok!(TrustModule::issue_trust(RuntimeOrigin::signed(1), 2, 100, None));
+        assert_noop!(TrustModule::update_trust(RuntimeOrigin::signed(1), 2, 101, None), Error::<Test>::InvalidTrustLevel);
+	});
+}
+
+#[test]
+fn test_update_trust() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+        assert_ok!(TrustModule::issue_trust(RuntimeOrigin::signed(1), 2, 100, None));
+        assert_ok!(TrustModule::update_trust(RuntimeOrigin::signed(1), 2, 25, Some(scope(b"payments"))));
+        assert_eq!(TrustIssuance::<Test>::get(1, 2), Some(TrustEdge { level: 25, scope: Some(scope(b"payments")) }));
+        assert_eq!(TrustModule::get_current_trust_count(), 1);
+        System::assert_last_event(Event::TrustUpdated { issuer: 1, target: 2, level: 25 }.into());
+        assert_ok!(TrustModule::update_trust(RuntimeOrigin::signed(1), 2, 0, None));
+        assert_eq!(TrustIssuance::<Test>::get(1, 2), Some(TrustEdge { level: 0, scope: None }));
+	});
+}
+
+#[test]
+fn test_update_trust_error() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+        assert_noop!(TrustModule::update_trust(RuntimeOrigin::signed(1), 2, 50, None), Error::<Test>::TrustNotFound);
+	});
+}
+
+#[test]
+fn migration_v1_gives_existing_trust_the_max_level() {
+	new_test_ext().execute_with(|| {
+        StorageVersion::new(0).put::<TrustModule>();
+        for (issuer, target) in [(1, 2), (3, 2), (2, 2)] {
+            let key = TrustIssuance::<Test>::hashed_key_for(issuer, target);
+            frame_support::storage::unhashed::put(&key, &7u32);
+        }
+
+        crate::migrations::v1::MigrateV0ToV1::<Test>::on_runtime_upgrade();
+
+        assert_eq!(TrustIssuance::<Test>::get(1, 2), Some(TrustEdge { level: 100, scope: None }));
+        // Trust an account placed in itself is not counted.
+        assert_eq!(TrustModule::trust_received(2), 200);
+        assert_eq!(TrustModule::on_chain_storage_version(), StorageVersion::new(1));
+	});
+}
+
+#[test]
+fn trust_received_sums_unscoped_trust() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+        assert_ok!(TrustModule::issue_trust(RuntimeOrigin::signed(1), 3, 60, None));
+        assert_ok!(TrustModule::issue_trust(RuntimeOrigin::signed(2), 3, 30, None));
+        assert_ok!(TrustModule::issue_trust(RuntimeOrigin::signed(4), 3, 90, Some(scope(b"payments"))));
+        // Trust an account places in itself is not counted.
+        assert_ok!(TrustModule::issue_trust(RuntimeOrigin::signed(3), 3, 100, None));
+        assert_eq!(TrustModule::trust_received(3), 90);
+
+        // Scoping trust takes it out of the total, and unscoping it puts it back at the new level.
+        assert_ok!(TrustModule::update_trust(RuntimeOrigin::signed(1), 3, 60, Some(scope(b"code-review"))));
+        assert_eq!(TrustModule::trust_received(3), 30);
+        assert_ok!(TrustModule::update_trust(RuntimeOrigin::signed(4), 3, 10, None));
+        assert_eq!(TrustModule::trust_received(3), 40);
+
+        assert_ok!(TrustModule::remove_trust(RuntimeOrigin::signed(2), 3));
+        assert_ok!(TrustModule::remove_trust(RuntimeOrigin::signed(4), 3));
+        assert_eq!(TrustModule::trust_received(3), 0);
+        assert!(!TrustReceived::<Test>::contains_key(3));
+	});
+}
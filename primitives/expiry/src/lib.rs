@@ -0,0 +1,147 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+//! A queue of items to expire in later blocks, shared by the Fennel pallets.
+//!
+//! A pallet implements [`ExpiryQueue`] over three of its own storage items: a map from blocks to
+//! the items due in them, the number of items not swept yet, and the next block to sweep while
+//! any are pending. An item is placed in the block it is due in, or in the first of the
+//! [`MAX_EXPIRY_DELAY`] blocks after it with room, and the pallet's `on_idle` hook sweeps the
+//! queue oldest block first. The pallet remembers which block each item went to, so it can take
+//! the item out again when it goes away before it is due.
+
+use codec::FullCodec;
+use frame_support::{
+	storage::{StorageMap, StorageValue},
+	traits::Get,
+	weights::{RuntimeDbWeight, Weight},
+	BoundedVec,
+};
+use sp_runtime::traits::{One, Saturating};
+
+/// How many blocks past its due block an item can be pushed back when the blocks before it are
+/// already full.
+pub const MAX_EXPIRY_DELAY: u32 = 16;
+
+/// The items due in one block.
+pub type BucketOf<Q> = BoundedVec<<Q as ExpiryQueue>::Item, <Q as ExpiryQueue>::MaxPerBlock>;
+
+/// Per-block buckets of items to expire, swept in idle time.
+pub trait ExpiryQueue {
+	/// The block number type of the runtime.
+	type BlockNumber: FullCodec + Copy + Ord + One + Saturating;
+	/// What is scheduled to expire.
+	type Item: FullCodec + Clone + PartialEq;
+	/// The maximum number of items a block can hold.
+	type MaxPerBlock: Get<u32>;
+	/// Maps blocks to the items due in them.
+	type Buckets: StorageMap<Self::BlockNumber, BucketOf<Self>, Query = BucketOf<Self>>;
+	/// The number of items held in `Buckets` that have not been swept yet.
+	type Pending: StorageValue<u32, Query = u32>;
+	/// The next block whose items have not been swept yet, while any are pending.
+	type Cursor: StorageValue<Self::BlockNumber, Query = Option<Self::BlockNumber>>;
+	/// The weight of database reads and writes.
+	type DbWeight: Get<RuntimeDbWeight>;
+
+	/// Places `item` in block `at`, or in the first of the `MAX_EXPIRY_DELAY` blocks after it
+	/// with room. Returns the block it was placed in, or `None` if all of them are full.
+	fn schedule(item: Self::Item, at: Self::BlockNumber, now: Self::BlockNumber) -> Option<Self::BlockNumber> {
+		let mut block = at;
+		for _ in 0..MAX_EXPIRY_DELAY {
+			if Self::Buckets::try_mutate(block, |bucket| bucket.try_push(item.clone())).is_ok() {
+				Self::Pending::mutate(|pending| pending.saturating_inc());
+				// Nothing scheduled before now can still be waiting, so start sweeping from here.
+				if Self::Cursor::get().is_none() {
+					Self::Cursor::put(now.saturating_add(One::one()));
+				}
+				return Some(block);
+			}
+			block.saturating_inc();
+		}
+		None
+	}
+
+	/// Takes `item` out of `block`, freeing its place there. The sweep stops once no items are
+	/// pending.
+	fn unschedule(item: &Self::Item, block: Self::BlockNumber) {
+		let mut removed = false;
+		Self::Buckets::mutate_exists(block, |bucket| {
+			if let Some(items) = bucket {
+				let before = items.len();
+				items.retain(|scheduled| scheduled != item);
+				removed = items.len() < before;
+				if items.is_empty() {
+					*bucket = None;
+				}
+			}
+		});
+		if removed {
+			let pending = Self::Pending::get().saturating_sub(1);
+			if pending == 0 {
+				Self::Pending::kill();
+				Self::Cursor::kill();
+			} else {
+				Self::Pending::put(pending);
+			}
+		}
+	}
+
+	/// Hands the items due in or before block `now` to `expire`, oldest block first, until
+	/// either none remain or `remaining_weight` is used up, charging `item_weight` per item.
+	/// Returns the weight used. Once no items are pending the cursor is cleared, so idle blocks
+	/// only read it.
+	fn sweep(
+		now: Self::BlockNumber,
+		remaining_weight: Weight,
+		item_weight: Weight,
+		mut expire: impl FnMut(Self::Item, Self::BlockNumber),
+	) -> Weight {
+		let db_weight = Self::DbWeight::get();
+		let block_weight = db_weight.reads_writes(1, 1);
+		let mut used = db_weight.reads(1);
+		if !remaining_weight.all_gte(used) {
+			return Weight::zero();
+		}
+		let Some(mut cursor) = Self::Cursor::get() else { return used };
+		// Reading the pending count, and writing it back along with the cursor.
+		let bookkeeping_weight = db_weight.reads_writes(1, 2);
+		if !remaining_weight.all_gte(used.saturating_add(bookkeeping_weight)) {
+			return used;
+		}
+		used.saturating_accrue(bookkeeping_weight);
+		let initial_pending = Self::Pending::get();
+		let mut pending = initial_pending;
+		while cursor <= now && pending > 0 {
+			if !remaining_weight.all_gte(used.saturating_add(block_weight)) {
+				break;
+			}
+			used.saturating_accrue(block_weight);
+			let mut bucket = Self::Buckets::take(cursor);
+			while let Some(item) = bucket.last().cloned() {
+				if !remaining_weight.all_gte(used.saturating_add(item_weight)) {
+					break;
+				}
+				used.saturating_accrue(item_weight);
+				bucket.pop();
+				pending.saturating_dec();
+				expire(item, cursor);
+			}
+			if !bucket.is_empty() {
+				Self::Buckets::insert(cursor, bucket);
+				break;
+			}
+			cursor.saturating_inc();
+		}
+		if pending == 0 {
+			Self::Pending::kill();
+			Self::Cursor::kill();
+		} else {
+			if pending != initial_pending {
+				Self::Pending::put(pending);
+			}
+			if Self::Cursor::get() != Some(cursor) {
+				Self::Cursor::put(cursor);
+			}
+		}
+		used
+	}
+}
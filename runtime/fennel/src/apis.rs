@@ -42,8 +42,9 @@ use sp_version::RuntimeVersion;
 
 // Local module imports
 use super::{
-	AccountId, Aura, Balance, Block, Executive, Grandpa, InherentDataExt, Nonce, Runtime,
-	RuntimeCall, RuntimeGenesisConfig, SessionKeys, System, TransactionPayment, VERSION,
+	AccountId, Aura, Balance, Block, BlockNumber, Certificate, Executive, Grandpa, InherentDataExt, Nonce,
+	Runtime, Identity, Keystore, RuntimeCall, RuntimeGenesisConfig, SessionKeys, Signal, System,
+	TransactionPayment, VERSION,
 };
 
 impl_runtime_apis! {
@@ -220,6 +221,56 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl pallet_identity_runtime_api::IdentityApi<Block, AccountId> for Runtime {
+		fn identity(identity_id: u32) -> Option<AccountId> {
+			Identity::identity_list(identity_id)
+		}
+		fn identity_traits(identity_id: u32) -> Vec<(Vec<u8>, Vec<u8>)> {
+			Identity::traits_of(identity_id)
+		}
+		fn identities_owned_by(owner: AccountId) -> Vec<u32> {
+			Identity::owned_identities(owner).into_inner()
+		}
+	}
+
+	impl pallet_keystore_runtime_api::KeystoreApi<Block, AccountId, BlockNumber> for Runtime {
+		fn keys(who: AccountId) -> Vec<(Vec<u8>, Vec<u8>)> {
+			Keystore::keys_of(&who)
+		}
+		fn key_owners(fingerprint: Vec<u8>) -> Vec<AccountId> {
+			Keystore::owners_of(&fingerprint)
+		}
+		fn encryption_key(who: AccountId) -> Option<pallet_keystore::EncryptionKeyOf<Runtime>> {
+			Keystore::encryption_key(who)
+		}
+	}
+
+	impl pallet_certificate_runtime_api::CertificateApi<Block, AccountId, BlockNumber> for Runtime {
+		fn issued_by(who: AccountId) -> Vec<(AccountId, pallet_certificate::CertificateInfoOf<Runtime>)> {
+			Certificate::certificates_issued_by(&who)
+		}
+		fn held_by(who: AccountId) -> Vec<(AccountId, pallet_certificate::CertificateInfoOf<Runtime>)> {
+			Certificate::certificates_held_by(&who)
+		}
+		fn is_valid(sender: AccountId, recipient: AccountId) -> bool {
+			Certificate::is_certificate_valid(&sender, &recipient, System::block_number())
+		}
+	}
+
+	impl pallet_signal_runtime_api::SignalApi<Block, AccountId, BlockNumber> for Runtime {
+		fn rating_aggregate(target: Vec<u8>) -> pallet_signal::RatingAggregate {
+			Signal::rating_aggregate_of(target)
+		}
+
+		fn service_signals(service_identifier: Vec<u8>) -> Vec<pallet_signal::ServiceSignal<AccountId, BlockNumber>> {
+			Signal::live_service_signals(service_identifier)
+		}
+
+		fn live_endpoints(service_identifier: Vec<u8>) -> Vec<pallet_signal::ServiceEndpoint<AccountId, BlockNumber>> {
+			Signal::live_service_endpoints(service_identifier)
+		}
+	}
+
 	#[cfg(feature = "runtime-benchmarks")]
 	impl frame_benchmarking::Benchmark<Block> for Runtime {
 		fn benchmark_metadata(extra: bool) -> (
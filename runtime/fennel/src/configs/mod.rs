@@ -67,6 +67,8 @@ parameter_types! {
 
 parameter_types! {
     pub const MaxTrustParameterSize: u32 = 64;
+    pub const MaxTrustLevel: u8 = 100;
+    pub const MaxTrustScopeSize: u32 = 64;
 }
 
 /// The default types are being injected by [`derive_impl`](`frame_support::derive_impl`) from
@@ -175,6 +177,8 @@ impl pallet_trust::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
     type WeightInfo = pallet_trust::weights::SubstrateWeight<Runtime>;
     type MaxTrustParameterSize = MaxTrustParameterSize;
+    type MaxTrustLevel = MaxTrustLevel;
+    type MaxTrustScopeSize = MaxTrustScopeSize;
 }
 
 // Removed duplicate implementation of pallet_template::Config for Runtime to avoid conflicts. The Config trait for pallet_template is now only implemented in lib.rs.
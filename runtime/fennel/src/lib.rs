@@ -74,10 +74,10 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
 	//   `spec_version`, and `authoring_version` are the same between Wasm and native.
 	// This value is set to 100 to notify Polkadot-JS App (https://polkadot.js.org/apps) to use
 	//   the compatible custom types.
-	spec_version: 102,
+	spec_version: 103,
 	impl_version: 1,
 	apis: apis::RUNTIME_API_VERSIONS,
-	transaction_version: 1,
+	transaction_version: 2,
 	system_version: 1,
 };
 
@@ -177,7 +177,20 @@ pub type SignedPayload = generic::SignedPayload<RuntimeCall, TxExtension>;
 ///
 /// This can be a tuple of types, each implementing `OnRuntimeUpgrade`.
 #[allow(unused_parens)]
-type Migrations = ();
+type Migrations = (
+	pallet_identity::migrations::v1::MigrateV0ToV1<Runtime>,
+	pallet_identity::migrations::v2::MigrateV1ToV2<Runtime>,
+	pallet_keystore::migrations::v1::MigrateV0ToV1<Runtime>,
+	pallet_keystore::migrations::v2::MigrateV1ToV2<Runtime>,
+	pallet_certificate::migrations::v1::MigrateV0ToV1<Runtime>,
+	pallet_certificate::migrations::v2::MigrateV1ToV2<Runtime>,
+	pallet_certificate::migrations::v3::MigrateV2ToV3<Runtime>,
+	pallet_signal::migrations::v1::MigrateV0ToV1<Runtime>,
+	pallet_signal::migrations::v2::MigrateV1ToV2<Runtime>,
+	pallet_signal::migrations::v3::MigrateV2ToV3<Runtime>,
+	pallet_signal::migrations::v4::MigrateV3ToV4<Runtime>,
+	pallet_trust::migrations::v1::MigrateV0ToV1<Runtime>,
+);
 
 /// Executive: handles dispatch to the various modules.
 pub type Executive = frame_executive::Executive<
@@ -241,6 +254,7 @@ impl pallet_validator_manager::Config for Runtime {
 parameter_types! {
     pub const CertificateLockId: [u8; 8] = *b"certlock";
     pub const CertificateLockPrice: Balance = 100 * UNIT;      // 100 FNL
+    pub const CertificateAcceptanceTimeout: BlockNumber = 7 * DAYS;
 }
 
 impl pallet_certificate::Config for Runtime {
@@ -249,6 +263,14 @@ impl pallet_certificate::Config for Runtime {
     type Currency = pallet_balances::Pallet<Runtime>;
     type LockId = CertificateLockId;
     type LockPrice = CertificateLockPrice;
+    type MaxPayloadSize = ConstU32<256>;
+    type MaxExpiriesPerBlock = ConstU32<64>;
+    type AcceptanceTimeout = CertificateAcceptanceTimeout;
+}
+
+parameter_types! {
+    pub const IdentityBaseDeposit: Balance = UNIT;             // 1 FNL
+    pub const IdentityByteDeposit: Balance = MILLIUNIT;        // 0.001 FNL per byte
 }
 
 // Implement the Config trait for the identity pallet in the runtime
@@ -256,6 +278,15 @@ impl pallet_identity::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
     type WeightInfo = pallet_identity::weights::SubstrateWeight<Runtime>;
     type MaxSize = ConstU32<1024>; // Updated to match original codebase
+    type MaxIdentitiesPerOwner = ConstU32<100>;
+    type RuntimeHoldReason = RuntimeHoldReason;
+    type Currency = pallet_balances::Pallet<Runtime>;
+    type BaseDeposit = IdentityBaseDeposit;
+    type ByteDeposit = IdentityByteDeposit;
+    type MaxTraitRemovals = ConstU32<100>;
+    type RegistrarOrigin = frame_system::EnsureRoot<AccountId>;
+    type MaxRegistrars = ConstU32<20>;
+    type MaxTraitUpdates = ConstU32<32>;
 }
 
 // Implement the Config trait for the keystore pallet in the runtime
@@ -263,6 +294,9 @@ impl pallet_keystore::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
     type WeightInfo = pallet_keystore::weights::SubstrateWeight<Runtime>;
     type MaxSize = ConstU32<1024>; // Updated to match original codebase
+    type MaxExpiriesPerBlock = ConstU32<64>;
+    type MaxRevokedKeys = ConstU32<256>;
+    type MaxCompromisedKeys = ConstU32<64>;
 }
 
 parameter_types! {
@@ -284,6 +318,29 @@ parameter_types! {
     pub const SignalLockId: [u8; 8] = *b"signallk";
     pub const SignalLockPrice: Balance = 10 * UNIT;            // 10 FNL
     pub const SignalMaxSize: u32 = 1024; // Updated to match original codebase
+    pub const SignalBaseDeposit: Balance = UNIT;               // 1 FNL
+    pub const SignalByteDeposit: Balance = MILLIUNIT;          // 0.001 FNL per byte
+    pub const SignalMaxTtl: BlockNumber = 30 * DAYS;
+    pub const SignalServiceLivenessPeriod: BlockNumber = DAYS;
+    pub const SignalMaxRating: u8 = 10;
+    /// The most weight a single rater's ratings can carry: the unscoped trust of ten issuers at
+    /// the highest trust level.
+    pub const MaxTrustRatingWeight: u32 = 1_000;
+}
+
+/// Weighs ratings by the unscoped trust other accounts have placed in the rater, up to
+/// `MaxTrustRatingWeight`. Raters nobody trusts do not count towards the weighted totals.
+///
+/// Issuing trust costs nothing, so accounts controlled by one party can trust each other to
+/// give their ratings weight. The cap keeps any one of them from outweighing ten fully trusted
+/// raters, but it does not stop a group of such accounts from adding up.
+pub struct TrustRatingWeight;
+
+impl pallet_signal::RatingWeight<AccountId> for TrustRatingWeight {
+    fn rating_weight(rater: &AccountId) -> Option<u32> {
+        let trust = pallet_trust::TrustReceived::<Runtime>::get(rater);
+        Some(trust.min(MaxTrustRatingWeight::get())).filter(|weight| *weight > 0)
+    }
 }
 
 impl pallet_signal::Config for Runtime {
@@ -293,6 +350,19 @@ impl pallet_signal::Config for Runtime {
     type LockId = SignalLockId;
     type LockPrice = SignalLockPrice;
     type MaxSize = SignalMaxSize;
+    type RatingWeight = TrustRatingWeight;
+    type SchemaOrigin = frame_system::EnsureRoot<AccountId>;
+    type MaxParameterListLength = ConstU32<64>;
+    type RuntimeHoldReason = RuntimeHoldReason;
+    type DepositCurrency = pallet_balances::Pallet<Runtime>;
+    type SignalBaseDeposit = SignalBaseDeposit;
+    type SignalByteDeposit = SignalByteDeposit;
+    type MaxSignalTtl = SignalMaxTtl;
+    type MaxSignalExpiriesPerBlock = ConstU32<64>;
+    type MaxServiceUrls = ConstU32<8>;
+    type ServiceLivenessPeriod = SignalServiceLivenessPeriod;
+    type MaxRating = SignalMaxRating;
+    type MaxReviewLength = ConstU32<256>;
 }
 
 // Create the runtime by composing the FRAME pallets that were previously configured.